@@ -1,49 +1,84 @@
+use chrono::DateTime;
 use uuid::Uuid;
 
 use crate::{
-    dates::get::get_now_as_string, events::models::EventName, files::append::append,
+    annotations::{
+        grammar::format_pairs,
+        models::{Annotation, AnnotationValue, CORE_KEYS},
+    },
+    dates::get::get_now_as_string,
+    errors::models::{NostError, NostResult},
+    events::models::EventName,
+    files::append::append,
     files::check_content::ends_with_line_break,
 };
 
+/**
+ * Write an annotation with the current version of the grammar. The keys of a parsed annotation
+ * keep their order, so that it is written back the same. The other keys follow: the core keys,
+ * then the fields sorted by key.
+ * i.e. "not:{v:2,date:'2025-09-29T00:00:43+02:00',event:'STOP_WORK',uid:'...',workday:'2025-09-28'}"
+ */
+pub fn format_annotation(annotation: &Annotation) -> String {
+    let date = AnnotationValue::Text(annotation.datetime_string());
+    let event = AnnotationValue::Text(annotation.event.to_string());
+    let uid = AnnotationValue::Text(annotation._uid.to_string());
+    let value_of = |key: &str| match key {
+        "date" => Some(&date),
+        "event" => Some(&event),
+        "uid" => Some(&uid),
+        _ => annotation.fields.get(key),
+    };
+
+    let mut keys: Vec<&str> = Vec::new();
+    let known_keys = annotation.keys.iter().map(String::as_str);
+    let new_keys = ["date", "event", "uid"].into_iter().chain(
+        annotation
+            .fields
+            .keys()
+            .map(String::as_str)
+            .filter(|key| !CORE_KEYS.contains(key)),
+    );
+    for key in known_keys.chain(new_keys) {
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    format_pairs(
+        keys.into_iter()
+            .filter_map(|key| value_of(key).map(|value| (key, value))),
+    )
+}
+
 pub fn annotate(
     date: Option<&str>,
     event: EventName,
     input_uid: Option<&Uuid>,
     not_path: &str,
-    workday: Option<&str>,
-) {
-    let now = get_now_as_string();
-    let date = match date {
-        Some(d) => d,
-        None => &now,
-    };
-
-    let new_uid = Uuid::new_v4().to_string();
-    let uid = match input_uid {
-        Some(u) => u.to_string(),
-        None => new_uid,
-    };
-
-    workday.unwrap_or_default();
+    fields: &[(&str, AnnotationValue)],
+) -> NostResult<()> {
+    let date = date.map(str::to_string).unwrap_or_else(get_now_as_string);
+    let datetime = DateTime::parse_from_rfc3339(&date)
+        .map_err(|_| NostError::Usage(format!("Invalid annotation date \"{}\"", date)))?;
 
-    let content = if Option::<&str>::is_some(&workday) {
-        format!(
-            "\"not:{{date:'{}',event:'{}',uid:'{}',workday:'{}'}}\"",
-            date,
-            event,
-            uid,
-            workday.unwrap()
-        )
-    } else {
-        format!(
-            "\"not:{{date:'{}',event:'{}',uid:'{}'}}\"",
-            date, event, uid
-        )
+    let annotation = Annotation {
+        _uid: input_uid.copied().unwrap_or_else(Uuid::new_v4),
+        event,
+        datetime,
+        datetime_text: Some(date),
+        // plugin fields, i.e. "workday"
+        fields: fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect(),
+        keys: Vec::new(),
     };
+    let content = format!("\"{}\"", format_annotation(&annotation));
 
     // Add a line break before the annotation if there is no previous empty line
     let ends_with_line_break = ends_with_line_break(not_path.into())
-        .expect("🛑 Failed to check if file ends with line break.");
+        .map_err(|e| NostError::io(format!("Failed to read {}", not_path), e))?;
 
     let annotation = if ends_with_line_break {
         format!("\n[//]: # {}", content)
@@ -51,7 +86,8 @@ pub fn annotate(
         format!("[//]: # {}\n", content)
     };
 
-    append(not_path.into(), &annotation).expect("🛑 Failed to annotate.");
+    append(not_path.into(), &annotation)
+        .map_err(|e| NostError::io(format!("Failed to annotate {}", not_path), e))
 }
 
 #[cfg(test)]
@@ -76,8 +112,9 @@ mod tests {
             crate::events::models::EventName::CreateNot,
             None,
             file_path.to_str().unwrap(),
-            None,
-        );
+            &[],
+        )
+        .unwrap();
 
         // Read back the content
         let mut file = fs::File::open(&file_path).unwrap();
@@ -86,7 +123,7 @@ mod tests {
 
         // The annotation should be wrapped as [//]: # "..."
         let annotation_regex =
-            regex::Regex::new(r#"\[//\]: # "not:\{date:'.*',event:'CREATE_NOT',uid:'.*'\}""#)
+            regex::Regex::new(r#"\[//\]: # "not:\{v:2,date:'.*',event:'CREATE_NOT',uid:'.*'\}""#)
                 .unwrap();
         assert!(
             file_content
@@ -1,77 +1,86 @@
+use crate::errors::models::{NostError, NostResult};
 use crate::files::find::find_all_not_files;
-use crate::{annotations::models::Annotation, annotations::parse::parse_annotation};
+use crate::{
+    annotations::front_matter::{parse_front_matter, split_front_matter},
+    annotations::grammar::parse_pairs,
+    annotations::models::Annotation,
+    annotations::parse::parse_annotation,
+};
 use regex::Regex;
-use std::io::Result;
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::LazyLock};
 
+/// An annotation line of a note, with the annotation without its "not:" prefix as first group.
+pub static ANNOTATION_LINE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\[//\]: # "not:(\{.*\})"\s*$"#).expect("valid annotation line regex")
+});
+
+// Get a field of an annotation as text, i.e. "3" for `pages:3`
 pub fn extract_field_from_annotation(annotation: &str, field: &str) -> Option<String> {
-    let re = Regex::new(&format!(r#"{}:'(?P<value>[^']+)'"#, field)).unwrap();
-    if let Some(caps) = re.captures(annotation) {
-        if let Some(value) = caps.name("value") {
-            return Some(value.as_str().to_string());
-        }
-    }
-    None
+    parse_pairs(annotation)
+        .ok()?
+        .into_iter()
+        .find(|(key, _)| key == field)
+        .map(|(_, value)| value.to_plain_string())
 }
 
-pub fn extract_annotations_from_path(path: PathBuf) -> Result<Vec<Annotation>> {
-    let paths = match find_all_not_files(path) {
+pub fn extract_annotations_from_path(path: PathBuf) -> NostResult<Vec<Annotation>> {
+    let paths = match find_all_not_files(path.clone()) {
         Ok(p) => p,
         Err(e) => {
-            return Err(e);
+            return Err(NostError::io(
+                format!("Cannot read the notes in {}", path.display()),
+                e,
+            ));
         }
     };
 
+    extract_annotations_from_files(paths)
+}
+
+pub fn extract_annotations_from_files(paths: Vec<PathBuf>) -> NostResult<Vec<Annotation>> {
     // get all annotations of the month
-    let mut raw_annotations = Vec::new();
+    let mut annotations = Vec::new();
     for path in paths {
-        let annotations_for_current_file = extract_annotations_from_one_file(&path).unwrap();
-        raw_annotations.extend(annotations_for_current_file);
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| NostError::io(format!("Cannot read {}", path.display()), e))?;
+        annotations.extend(extract_annotations_from_content(&content));
     }
 
-    // refine the annotation removing the not prefix
-    let re = Regex::new(r#"\[//\]: # "not:(\{.*\})""#).unwrap();
-
-    let filtered_annotations: Vec<String> = raw_annotations
-        .into_iter()
-        .filter_map(|annotation| {
-            re.captures(&annotation)
-                .and_then(|caps| caps.get(1).map(|m| m.as_str().to_string()))
-        })
-        .collect();
+    Ok(annotations)
+}
 
-    // convert text annotations into Annotation structs
+/**
+ * Get the annotations of a note, written in its front matter or as `[//]: # "not:{...}"`
+ * lines. Invalid metadata is reported and skipped.
+ */
+pub fn extract_annotations_from_content(content: &str) -> Vec<Annotation> {
     let mut annotations = Vec::new();
 
-    filtered_annotations
-        .into_iter()
-        .for_each(|annotation| match parse_annotation(&annotation) {
+    let (front_matter, body) = split_front_matter(content);
+    if let Some(yaml) = front_matter {
+        match parse_front_matter(yaml).and_then(|front_matter| front_matter.to_annotations()) {
+            Ok(front_matter_annotations) => annotations.extend(front_matter_annotations),
+            Err(e) => eprintln!("Failed to parse front matter: {}\n", e),
+        }
+    }
+
+    // convert text annotations into Annotation structs
+    body.lines()
+        .filter_map(|line| {
+            ANNOTATION_LINE_REGEX
+                .captures(line)
+                .and_then(|caps| caps.get(1))
+        })
+        .for_each(|annotation| match parse_annotation(annotation.as_str()) {
             Ok(annotation_struct) => annotations.push(annotation_struct),
             Err(e) => eprintln!(
                 "Failed to parse annotation: {}\n Annotation: {}\n",
-                e, annotation
+                e,
+                annotation.as_str()
             ),
         });
 
-    Ok(annotations)
-}
-
-pub fn extract_annotations_from_one_file(file_path: &PathBuf) -> Result<Vec<String>> {
-    let content = std::fs::read_to_string(file_path)?;
-    let re = Regex::new(r#"^\[//\]: # "not.*"\s*$"#).unwrap();
-
-    let extracted: Vec<String> = content
-        .lines()
-        .filter_map(|line| {
-            if re.is_match(line) {
-                Some(line.to_string())
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    Ok(extracted)
+    annotations
 }
 
 #[cfg(test)]
@@ -0,0 +1,618 @@
+/*!
+ * Note metadata as YAML front matter, read by tools like Obsidian:
+ *
+ * ---
+ * uid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff
+ * created_at: 2026-08-05T09:00:00+02:00
+ * type: work
+ * tags: [client, review]
+ * sessions:
+ *   - event: START_WORK
+ *     date: 2026-08-05T09:00:00+02:00
+ *     uid: d6330352-e55c-4944-9496-0f819a826905
+ * ---
+ *
+ * Only the nost keys are read, with a subset of YAML: `key: value` lines, flow lists (`[a, b]`)
+ * and block lists (`- item`) of values or of `key: value` maps. The keys of other tools, i.e.
+ * `aliases` or `title`, and the comments are kept as they are written, they are never moved
+ * into annotations.
+ */
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use uuid::Uuid;
+
+use crate::{
+    annotations::{
+        models::{Annotation, AnnotationValue, CORE_KEYS},
+        parse::annotation_from_fields,
+    },
+    events::models::EventName,
+};
+
+const DELIMITER: &str = "---";
+// the keys written by nost, which must be valid
+const NOST_KEYS: [&str; 5] = ["uid", "created_at", "type", "tags", "sessions"];
+const TAGS_SEPARATOR: &str = ", ";
+
+/// The metadata of a note, written between `---` lines at its top.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrontMatter {
+    pub uid: Option<String>,
+    pub created_at: Option<String>,
+    // written as "type"
+    pub note_type: Option<String>,
+    pub tags: Vec<String>,
+    // the other annotations of the note, i.e. START_WORK and STOP_WORK
+    pub sessions: Vec<BTreeMap<String, AnnotationValue>>,
+    // the other fields of the CREATE_NOT annotation, written as keys
+    pub fields: BTreeMap<String, AnnotationValue>,
+    // the lines of the other keys and the comments, written back as they are
+    pub raw_lines: Vec<String>,
+}
+
+enum YamlValue {
+    Scalar(AnnotationValue),
+    List(Vec<AnnotationValue>),
+    Maps(Vec<BTreeMap<String, AnnotationValue>>),
+}
+
+/**
+ * Split a note into its front matter, without the delimiters, and the rest of its content.
+ * i.e. "---\ntype: work\n---\n# Title\n" gives ("type: work\n", "# Title\n")
+ */
+pub fn split_front_matter(content: &str) -> (Option<&str>, &str) {
+    let Some(rest) = content.strip_prefix(DELIMITER).and_then(|rest| {
+        rest.strip_prefix('\n')
+            .or_else(|| rest.strip_prefix("\r\n"))
+    }) else {
+        return (None, content);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == DELIMITER {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+
+    (None, content)
+}
+
+fn is_key(text: &str) -> bool {
+    let mut characters = text.chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && characters.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+// Split "key: value" lines, None for other lines
+fn split_pair(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    if !is_key(key.trim()) || !(value.is_empty() || value.starts_with(' ')) {
+        return None;
+    }
+    Some((key.trim(), value.trim()))
+}
+
+fn parse_scalar(text: &str) -> Result<AnnotationValue, String> {
+    let text = text.trim();
+
+    if let Some(quoted) = text.strip_prefix('\'') {
+        let inner = quoted
+            .strip_suffix('\'')
+            .ok_or_else(|| format!("unterminated text {}", text))?;
+        return Ok(AnnotationValue::Text(inner.replace("''", "'")));
+    }
+    if let Some(quoted) = text.strip_prefix('"') {
+        let inner = quoted
+            .strip_suffix('"')
+            .ok_or_else(|| format!("unterminated text {}", text))?;
+        let mut unescaped = String::new();
+        let mut characters = inner.chars();
+        while let Some(c) = characters.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            match characters.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some('t') => unescaped.push('\t'),
+                Some(other) => unescaped.push(other),
+                None => return Err(format!("unterminated text {}", text)),
+            }
+        }
+        return Ok(AnnotationValue::Text(unescaped));
+    }
+
+    match text {
+        "true" => return Ok(AnnotationValue::Boolean(true)),
+        "false" => return Ok(AnnotationValue::Boolean(false)),
+        _ => {}
+    }
+    if !text.contains(['.', 'e', 'E']) {
+        if let Ok(integer) = text.parse::<i64>() {
+            return Ok(AnnotationValue::Integer(integer));
+        }
+    }
+    // i.e. not "inf" nor "nan", which are texts in YAML
+    if text.contains(|c: char| c.is_ascii_digit()) {
+        if let Ok(float) = text.parse::<f64>() {
+            return Ok(AnnotationValue::Float(float));
+        }
+    }
+
+    Ok(AnnotationValue::Text(text.to_string()))
+}
+
+// Split the items of a flow list, i.e. "[a, 'b, c']"
+fn parse_flow_list(text: &str) -> Result<Vec<AnnotationValue>, String> {
+    let inner = text
+        .strip_prefix('[')
+        .and_then(|text| text.strip_suffix(']'))
+        .ok_or_else(|| format!("unterminated list {}", text))?;
+
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut quote: Option<char> = None;
+    for c in inner.chars() {
+        match (c, quote) {
+            (',', None) => {
+                items.push(parse_scalar(&item)?);
+                item.clear();
+                continue;
+            }
+            ('\'' | '"', None) => quote = Some(c),
+            (c, Some(open)) if c == open => quote = None,
+            _ => {}
+        }
+        item.push(c);
+    }
+    if !item.trim().is_empty() || !items.is_empty() {
+        items.push(parse_scalar(&item)?);
+    }
+
+    Ok(items)
+}
+
+// Parse the indented lines of a block list, either values or maps
+fn parse_block_list(lines: &[&str]) -> Result<YamlValue, String> {
+    let mut values = Vec::new();
+    let mut maps: Vec<BTreeMap<String, AnnotationValue>> = Vec::new();
+
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        match trimmed
+            .strip_prefix("- ")
+            .or(trimmed.strip_prefix('-').filter(|rest| rest.is_empty()))
+        {
+            Some(item) => match split_pair(item) {
+                Some((key, value)) => {
+                    maps.push(BTreeMap::from([(key.to_string(), parse_scalar(value)?)]));
+                }
+                None => values.push(parse_scalar(item)?),
+            },
+            None => {
+                let (key, value) = split_pair(trimmed)
+                    .ok_or_else(|| format!("invalid list item \"{}\"", trimmed))?;
+                let map = maps
+                    .last_mut()
+                    .ok_or_else(|| format!("invalid list item \"{}\"", trimmed))?;
+                map.insert(key.to_string(), parse_scalar(value)?);
+            }
+        }
+    }
+
+    match (values.is_empty(), maps.is_empty()) {
+        (true, false) => Ok(YamlValue::Maps(maps)),
+        (_, true) => Ok(YamlValue::List(values)),
+        _ => Err("a list mixes values and maps".to_string()),
+    }
+}
+
+fn to_plain_strings(values: Vec<AnnotationValue>) -> Vec<String> {
+    values
+        .iter()
+        .map(AnnotationValue::to_plain_string)
+        .collect()
+}
+
+// Parse the value of a key, with the indented lines following it
+fn parse_value(value: &str, block: &[&str]) -> Result<YamlValue, String> {
+    let has_block = block.iter().any(|line| !line.trim().is_empty());
+
+    if value.is_empty() {
+        parse_block_list(block)
+    } else if has_block || value.starts_with(['|', '>']) {
+        Err(format!("unsupported multi-line value \"{}\"", value))
+    } else if value.starts_with('[') {
+        Ok(YamlValue::List(parse_flow_list(value)?))
+    } else {
+        Ok(YamlValue::Scalar(parse_scalar(value)?))
+    }
+}
+
+/**
+ * Parse a front matter, without its delimiters. Only the nost keys are read and must be
+ * valid: every other line, i.e. the keys of other tools and the comments, is kept in
+ * `raw_lines` as it is written.
+ */
+pub fn parse_front_matter(yaml: &str) -> Result<FrontMatter, String> {
+    let lines: Vec<&str> = yaml.lines().collect();
+    let mut front_matter = FrontMatter::default();
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // the value can continue on the indented lines, i.e. a block list
+        let start = index;
+        while index < lines.len()
+            && (lines[index].starts_with([' ', '\t', '-']) || lines[index].trim().is_empty())
+        {
+            index += 1;
+        }
+        let block = &lines[start..index];
+
+        let Some((key, value)) = split_pair(line).filter(|(key, _)| NOST_KEYS.contains(key)) else {
+            let end = block
+                .iter()
+                .rposition(|line| !line.trim().is_empty())
+                .map_or(0, |position| position + 1);
+            front_matter.raw_lines.extend(
+                std::iter::once(line)
+                    .chain(block[..end].iter().copied())
+                    .map(str::to_string),
+            );
+            continue;
+        };
+
+        match (key, parse_value(value, block)?) {
+            ("uid", YamlValue::Scalar(value)) => front_matter.uid = Some(value.to_plain_string()),
+            ("created_at", YamlValue::Scalar(value)) => {
+                front_matter.created_at = Some(value.to_plain_string())
+            }
+            ("type", YamlValue::Scalar(value)) => {
+                front_matter.note_type = Some(value.to_plain_string())
+            }
+            ("tags", YamlValue::Scalar(value)) => front_matter.tags = vec![value.to_plain_string()],
+            ("tags", YamlValue::List(values)) => front_matter.tags = to_plain_strings(values),
+            ("sessions", YamlValue::Maps(maps)) => front_matter.sessions = maps,
+            ("sessions", YamlValue::List(values)) if values.is_empty() => {}
+            (key, _) => return Err(format!("unsupported value for \"{}\"", key)),
+        }
+    }
+
+    Ok(front_matter)
+}
+
+// Write a value, quoted when it would be read back differently
+fn format_scalar(value: &AnnotationValue, in_flow_list: bool) -> String {
+    let AnnotationValue::Text(text) = value else {
+        return value.to_string();
+    };
+
+    if text.contains(['\n', '\r', '\t']) {
+        let escaped = text
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+            .replace('\t', "\\t");
+        return format!("\"{}\"", escaped);
+    }
+
+    let needs_quotes = text.trim() != text
+        || text.starts_with([
+            '-', '?', ':', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '%', '@', '`',
+        ])
+        || text.contains(": ")
+        || text.contains(" #")
+        || text.ends_with(':')
+        || (in_flow_list && text.contains([',', '[', ']']))
+        || parse_scalar(text).ok().as_ref() != Some(value);
+    if needs_quotes {
+        format!("'{}'", text.replace('\'', "''"))
+    } else {
+        text.clone()
+    }
+}
+
+/**
+ * Write a front matter with its delimiters. uid, created_at and type are omitted when
+ * missing, tags and sessions are always written so that they can be filled by hand.
+ */
+pub fn format_front_matter(front_matter: &FrontMatter) -> String {
+    let mut content = format!("{}\n", DELIMITER);
+    let text = |value: &str| format_scalar(&AnnotationValue::Text(value.to_string()), false);
+
+    if let Some(uid) = &front_matter.uid {
+        content.push_str(&format!("uid: {}\n", text(uid)));
+    }
+    if let Some(created_at) = &front_matter.created_at {
+        content.push_str(&format!("created_at: {}\n", text(created_at)));
+    }
+    if let Some(note_type) = &front_matter.note_type {
+        content.push_str(&format!("type: {}\n", text(note_type)));
+    }
+
+    let tags: Vec<String> = front_matter
+        .tags
+        .iter()
+        .map(|tag| format_scalar(&AnnotationValue::Text(tag.clone()), true))
+        .collect();
+    content.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+
+    if front_matter.sessions.is_empty() {
+        content.push_str("sessions: []\n");
+    } else {
+        content.push_str("sessions:\n");
+        for session in &front_matter.sessions {
+            // the core keys first, like in annotations
+            let mut keys: Vec<&String> = session
+                .keys()
+                .filter(|key| CORE_KEYS.contains(&key.as_str()))
+                .collect();
+            keys.sort_by_key(|key| CORE_KEYS.iter().position(|core| core == key));
+            keys.extend(
+                session
+                    .keys()
+                    .filter(|key| !CORE_KEYS.contains(&key.as_str())),
+            );
+
+            for (position, key) in keys.into_iter().enumerate() {
+                let prefix = if position == 0 { "  - " } else { "    " };
+                content.push_str(&format!(
+                    "{}{}: {}\n",
+                    prefix,
+                    key,
+                    format_scalar(&session[key], false)
+                ));
+            }
+        }
+    }
+
+    // a key of another tool is kept as written rather than overwritten by a field
+    let raw_keys: Vec<&str> = front_matter
+        .raw_lines
+        .iter()
+        .filter(|line| !line.starts_with([' ', '\t', '-']))
+        .filter_map(|line| split_pair(line))
+        .map(|(key, _)| key)
+        .collect();
+    for (key, value) in &front_matter.fields {
+        if !raw_keys.contains(&key.as_str()) {
+            content.push_str(&format!("{}: {}\n", key, format_scalar(value, false)));
+        }
+    }
+    for line in &front_matter.raw_lines {
+        content.push_str(&format!("{}\n", line));
+    }
+
+    content.push_str(DELIMITER);
+    content.push('\n');
+    content
+}
+
+// The pairs of an annotation, the core ones included
+pub fn annotation_to_fields(annotation: &Annotation) -> BTreeMap<String, AnnotationValue> {
+    let mut fields = annotation.fields.clone();
+    fields.insert(
+        "date".to_string(),
+        AnnotationValue::Text(annotation.datetime_string()),
+    );
+    fields.insert(
+        "event".to_string(),
+        AnnotationValue::Text(annotation.event.to_string()),
+    );
+    fields.insert(
+        "uid".to_string(),
+        AnnotationValue::Text(annotation._uid.to_string()),
+    );
+    fields
+}
+
+impl FrontMatter {
+    /**
+     * Build the front matter of the annotations of a note: the CREATE_NOT annotation gives
+     * the uid, the creation date, the type, the tags and the other fields, the other
+     * annotations are the sessions.
+     */
+    pub fn from_annotations(annotations: &[Annotation]) -> Self {
+        let mut front_matter = FrontMatter::default();
+
+        for annotation in annotations {
+            if annotation.event != EventName::CreateNot || front_matter.uid.is_some() {
+                front_matter.sessions.push(annotation_to_fields(annotation));
+                continue;
+            }
+
+            front_matter.uid = Some(annotation._uid.to_string());
+            front_matter.created_at = Some(annotation.datetime_string());
+            for (key, value) in &annotation.fields {
+                match key.as_str() {
+                    "type" => front_matter.note_type = Some(value.to_plain_string()),
+                    "tags" => {
+                        front_matter.tags = value
+                            .to_plain_string()
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|tag| !tag.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    }
+                    _ => {
+                        front_matter.fields.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        front_matter
+    }
+
+    /**
+     * Get the annotations of the front matter: a CREATE_NOT annotation when it has a creation
+     * date, then the sessions. Front matter without nost metadata gives no annotation.
+     */
+    pub fn to_annotations(&self) -> Result<Vec<Annotation>, String> {
+        let mut annotations = Vec::new();
+
+        if let Some(created_at) = &self.created_at {
+            let mut fields = self.fields.clone();
+            if let Some(note_type) = &self.note_type {
+                fields.insert("type".to_string(), AnnotationValue::Text(note_type.clone()));
+            }
+            if !self.tags.is_empty() {
+                fields.insert(
+                    "tags".to_string(),
+                    AnnotationValue::Text(self.tags.join(TAGS_SEPARATOR)),
+                );
+            }
+
+            annotations.push(Annotation {
+                _uid: match &self.uid {
+                    Some(uid) => {
+                        Uuid::from_str(uid).map_err(|_| format!("invalid uid \"{}\"", uid))?
+                    }
+                    None => return Err("missing uid".to_string()),
+                },
+                event: EventName::CreateNot,
+                datetime: DateTime::parse_from_rfc3339(created_at)
+                    .map_err(|_| format!("invalid created_at \"{}\"", created_at))?,
+                datetime_text: Some(created_at.clone()),
+                fields,
+                keys: Vec::new(),
+            });
+        }
+
+        for session in &self.sessions {
+            annotations.push(annotation_from_fields(session.clone())?);
+        }
+
+        Ok(annotations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str = "---
+uid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff
+created_at: '2026-08-05T09:00:00+02:00'
+type: work
+tags: [client, 'a, b', '2026']
+sessions:
+  - date: '2026-08-05T09:00:00+02:00'
+    event: START_WORK
+    uid: d6330352-e55c-4944-9496-0f819a826905
+    workday: 2026-08-05
+aliases:
+  - first
+  - second
+pages: 3
+---
+# Wednesday
+";
+
+    #[test]
+    fn front_matter_is_read_and_written_back() {
+        let (yaml, body) = split_front_matter(NOTE);
+        assert_eq!(body, "# Wednesday\n");
+
+        let front_matter = parse_front_matter(yaml.unwrap()).unwrap();
+        assert_eq!(front_matter.note_type, Some("work".to_string()));
+        assert_eq!(front_matter.tags, vec!["client", "a, b", "2026"]);
+        assert_eq!(front_matter.sessions.len(), 1);
+        assert!(front_matter.fields.is_empty());
+        assert_eq!(
+            front_matter.raw_lines,
+            vec!["aliases:", "  - first", "  - second", "pages: 3"]
+        );
+
+        let written = format_front_matter(&front_matter);
+        let (yaml, _) = split_front_matter(&written);
+        assert_eq!(parse_front_matter(yaml.unwrap()).unwrap(), front_matter);
+    }
+
+    #[test]
+    fn front_matter_and_annotations_convert_both_ways() {
+        let (yaml, _) = split_front_matter(NOTE);
+        let front_matter = parse_front_matter(yaml.unwrap()).unwrap();
+
+        let annotations = front_matter.to_annotations().unwrap();
+        assert_eq!(annotations[0].event, EventName::CreateNot);
+        assert_eq!(annotations[0].field("type"), Some("work"));
+        assert_eq!(annotations[1].event, EventName::StartWork);
+        assert_eq!(annotations[1].field("workday"), Some("2026-08-05"));
+        // the keys of other tools are not annotations
+        assert_eq!(annotations[0].field("aliases"), None);
+
+        let mut converted = FrontMatter::from_annotations(&annotations);
+        // tags are a text in annotations, split on commas
+        converted.tags = front_matter.tags.clone();
+        converted.raw_lines = front_matter.raw_lines.clone();
+        assert_eq!(converted, front_matter);
+    }
+
+    #[test]
+    fn front_matter_without_nost_metadata_has_no_annotations() {
+        let front_matter = parse_front_matter("tags:\n  - recipe\n").unwrap();
+
+        assert_eq!(front_matter.tags, vec!["recipe"]);
+        assert!(front_matter.to_annotations().unwrap().is_empty());
+        assert_eq!(split_front_matter("# Title\n---\n").0, None);
+    }
+
+    #[test]
+    fn keys_of_other_tools_are_kept_as_written() {
+        let yaml = "uid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff
+summary: |
+  First line.
+
+  Second line.
+cssclasses: >-
+  wide
+author:
+  name: Ada
+  links:
+    - https://example.com
+type: work
+";
+
+        let front_matter = parse_front_matter(yaml).unwrap();
+
+        assert_eq!(front_matter.note_type, Some("work".to_string()));
+        assert_eq!(
+            front_matter.uid.as_deref(),
+            Some("b86bc6ed-50a5-4ef2-bdd3-e17baef11eff")
+        );
+        assert!(front_matter.fields.is_empty());
+        assert_eq!(front_matter.raw_lines.len(), 10);
+
+        let written = format_front_matter(&front_matter);
+        assert!(written.contains("summary: |\n  First line.\n\n  Second line.\ncssclasses: >-\n"));
+        let (written_yaml, _) = split_front_matter(&written);
+        assert_eq!(
+            parse_front_matter(written_yaml.unwrap()).unwrap(),
+            front_matter
+        );
+
+        // the nost keys must still be valid
+        assert!(parse_front_matter("tags: ['unterminated]\n").is_err());
+    }
+}
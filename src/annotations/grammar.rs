@@ -0,0 +1,231 @@
+/*!
+ * Grammar of the annotations, i.e. `not:{v:2,date:'2026-08-05T09:00:00+02:00',event:'START_WORK',uid:'...',count:3}`.
+ *
+ * annotation := ["not:"] "{" pair ("," pair)* "}"
+ * pair       := key ":" value
+ * key        := [A-Za-z_][A-Za-z0-9_.-]*
+ * value      := text | integer | float | boolean
+ * text       := "'" characters "'", with \\ \' \" \n \r \t escaped
+ * integer    := i.e. -12
+ * float      := i.e. 2.5 or 1e-7, always with a dot or an exponent
+ * boolean    := true | false
+ *
+ * The version is given by a first `v` pair. Annotations without version were written before
+ * the grammar (version 1): their texts have no escaping and end at the next quote.
+ */
+
+use crate::annotations::models::AnnotationValue;
+
+/// Version of the grammar written by nost.
+pub const ANNOTATION_VERSION: i64 = 2;
+pub const VERSION_KEY: &str = "v";
+const PREFIX: &str = "not:";
+
+struct Scanner<'a> {
+    characters: std::iter::Peekable<std::str::Chars<'a>>,
+    // escaping is only known from version 2
+    escaping: bool,
+}
+
+impl Scanner<'_> {
+    fn skip_spaces(&mut self) {
+        while self.characters.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_spaces();
+        match self.characters.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("expected '{}', found '{}'", expected, c)),
+            None => Err(format!("expected '{}', found the end", expected)),
+        }
+    }
+
+    fn key(&mut self) -> Result<String, String> {
+        self.skip_spaces();
+        let mut key = String::new();
+        while let Some(c) = self
+            .characters
+            .next_if(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            key.push(c);
+        }
+
+        match key.chars().next() {
+            Some(first) if first.is_ascii_alphabetic() || first == '_' => Ok(key),
+            _ => Err(format!("invalid key \"{}\"", key)),
+        }
+    }
+
+    fn text(&mut self) -> Result<String, String> {
+        let mut text = String::new();
+        loop {
+            match self.characters.next() {
+                None => return Err("unterminated text".to_string()),
+                Some('\'') => return Ok(text),
+                Some('\\') if self.escaping => match self.characters.next() {
+                    Some('n') => text.push('\n'),
+                    Some('r') => text.push('\r'),
+                    Some('t') => text.push('\t'),
+                    Some(c @ ('\\' | '\'' | '"')) => text.push(c),
+                    Some(c) => return Err(format!("unknown escape \"\\{}\"", c)),
+                    None => return Err("unterminated text".to_string()),
+                },
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<AnnotationValue, String> {
+        self.skip_spaces();
+        if self.characters.next_if_eq(&'\'').is_some() {
+            return self.text().map(AnnotationValue::Text);
+        }
+
+        let mut token = String::new();
+        while let Some(c) = self
+            .characters
+            .next_if(|c| !matches!(c, ',' | '}') && !c.is_whitespace())
+        {
+            token.push(c);
+        }
+
+        match token.as_str() {
+            "true" => return Ok(AnnotationValue::Boolean(true)),
+            "false" => return Ok(AnnotationValue::Boolean(false)),
+            _ => {}
+        }
+        // a float always has a dot or an exponent
+        if !token.contains(['.', 'e', 'E']) {
+            if let Ok(integer) = token.parse::<i64>() {
+                return Ok(AnnotationValue::Integer(integer));
+            }
+        }
+        token
+            .parse::<f64>()
+            .map(AnnotationValue::Float)
+            .map_err(|_| format!("invalid value \"{}\"", token))
+    }
+}
+
+/**
+ * Parse the pairs of an annotation, in their order. The `v` pair is kept.
+ * i.e. "not:{v:2,title:'it\'s'}" gives [("v", 2), ("title", "it's")]
+ */
+pub fn parse_pairs(annotation: &str) -> Result<Vec<(String, AnnotationValue)>, String> {
+    let annotation = annotation.trim();
+    let annotation = annotation.strip_prefix(PREFIX).unwrap_or(annotation);
+    let mut scanner = Scanner {
+        characters: annotation.chars().peekable(),
+        escaping: false,
+    };
+
+    scanner.expect('{')?;
+    let mut pairs: Vec<(String, AnnotationValue)> = Vec::new();
+    loop {
+        let key = scanner.key()?;
+        scanner.expect(':')?;
+        let value = scanner.value()?;
+
+        if pairs.is_empty() && key == VERSION_KEY {
+            match value {
+                AnnotationValue::Integer(version)
+                    if (1..=ANNOTATION_VERSION).contains(&version) =>
+                {
+                    scanner.escaping = version >= 2;
+                }
+                _ => return Err(format!("unsupported annotation version {}", value)),
+            }
+        }
+        if pairs.iter().any(|(known, _)| *known == key) {
+            return Err(format!("duplicate key \"{}\"", key));
+        }
+        pairs.push((key, value));
+
+        scanner.skip_spaces();
+        match scanner.characters.next() {
+            Some(',') => continue,
+            Some('}') => break,
+            Some(c) => return Err(format!("expected ',' or '}}', found '{}'", c)),
+            None => return Err("expected '}', found the end".to_string()),
+        }
+    }
+
+    scanner.skip_spaces();
+    match scanner.characters.next() {
+        None => Ok(pairs),
+        Some(c) => Err(format!("unexpected '{}' after the annotation", c)),
+    }
+}
+
+/**
+ * Write pairs with the current version of the grammar, first given by a `v` pair.
+ * i.e. [("title", "it's")] gives "not:{v:2,title:'it\'s'}"
+ */
+pub fn format_pairs<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a AnnotationValue)>) -> String {
+    let mut content = format!("{}{{{}:{}", PREFIX, VERSION_KEY, ANNOTATION_VERSION);
+    for (key, value) in pairs {
+        if key != VERSION_KEY {
+            content.push_str(&format!(",{}:{}", key, value));
+        }
+    }
+    content.push('}');
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> AnnotationValue {
+        AnnotationValue::Text(value.to_string())
+    }
+
+    #[test]
+    fn pairs_round_trip_with_escaping_and_types() {
+        let pairs = [
+            ("title".to_string(), text("it's a \"quote\" \\ with\nlines")),
+            ("count".to_string(), AnnotationValue::Integer(-3)),
+            ("ratio".to_string(), AnnotationValue::Float(1.0)),
+            ("tiny".to_string(), AnnotationValue::Float(1e-7)),
+            ("billable".to_string(), AnnotationValue::Boolean(true)),
+            ("project.name".to_string(), text("")),
+        ];
+
+        let written = format_pairs(pairs.iter().map(|(key, value)| (key.as_str(), value)));
+        assert_eq!(
+            written,
+            r#"not:{v:2,title:'it\'s a \"quote\" \\ with\nlines',count:-3,ratio:1.0,tiny:1e-7,billable:true,project.name:''}"#
+        );
+
+        let parsed = parse_pairs(&written).unwrap();
+        assert_eq!(parsed[0], ("v".to_string(), AnnotationValue::Integer(2)));
+        assert_eq!(parsed[1..], pairs[..]);
+        // written again, the annotation is the same
+        assert_eq!(
+            format_pairs(parsed.iter().map(|(key, value)| (key.as_str(), value))),
+            written
+        );
+    }
+
+    #[test]
+    fn annotations_without_version_have_no_escaping() {
+        let pairs = parse_pairs(r"{date:'2025-09-29',path:'C:\notes\'}").unwrap();
+
+        assert_eq!(pairs[1], ("path".to_string(), text(r"C:\notes\")));
+    }
+
+    #[test]
+    fn invalid_annotations_are_errors() {
+        assert!(parse_pairs("not:{v:3,date:'2025-09-29'}")
+            .unwrap_err()
+            .contains("version 3"));
+        assert!(parse_pairs("not:{v:2,title:'open}").is_err());
+        assert!(parse_pairs("not:{v:2,title:'\\q'}").is_err());
+        assert!(parse_pairs("not:{v:2,1key:'value'}").is_err());
+        assert!(parse_pairs("not:{v:2,key:'a',key:'b'}").is_err());
+        assert!(parse_pairs("not:{v:2,key:maybe}").is_err());
+        assert!(parse_pairs("not:{v:2,key:'a'} trailing").is_err());
+    }
+}
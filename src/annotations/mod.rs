@@ -1,5 +1,7 @@
 pub mod annotate;
 pub mod extract;
 pub mod filter;
+pub mod front_matter;
+pub mod grammar;
 pub mod models;
 pub mod parse;
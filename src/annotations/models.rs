@@ -1,12 +1,91 @@
 use crate::events::models::EventName;
 use chrono::{DateTime, FixedOffset};
+use std::{collections::BTreeMap, fmt};
 use uuid::Uuid;
 
+// keys of every annotation, the other ones are in `Annotation::fields`
+pub const CORE_KEYS: [&str; 4] = ["v", "date", "event", "uid"];
+
 #[derive(Debug, Clone)]
 pub struct Annotation {
     pub _uid: Uuid,
     pub event: EventName,
     pub datetime: DateTime<FixedOffset>,
-    // todo: remove from core, should be plugin specific
-    pub workday: Option<String>,
+    // the datetime as written, i.e. "2026-08-05T07:00:00Z", None for a new annotation
+    pub datetime_text: Option<String>,
+    // any other key, i.e. "workday" declared by the gdarquie_work plugin
+    pub fields: BTreeMap<String, AnnotationValue>,
+    // the keys in the order they were written, the core ones included, empty for a new annotation
+    pub keys: Vec<String>,
+}
+
+impl Annotation {
+    /// The datetime as written when it still is the datetime of the annotation, so that
+    /// "2026-08-05T07:00:00Z" is not written back as "2026-08-05T07:00:00+00:00".
+    pub fn datetime_string(&self) -> String {
+        self.datetime_text
+            .as_ref()
+            .filter(|text| DateTime::parse_from_rfc3339(text).ok() == Some(self.datetime))
+            .cloned()
+            .unwrap_or_else(|| self.datetime.to_rfc3339())
+    }
+
+    // The text of a field, None when it is missing or is not a text
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).and_then(AnnotationValue::as_str)
+    }
+}
+
+/// A value of an annotation, see `annotations::grammar` for how each type is written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnnotationValue {
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+impl AnnotationValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AnnotationValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    // The value without quotes nor escaping, i.e. "it's" or "3"
+    pub fn to_plain_string(&self) -> String {
+        match self {
+            AnnotationValue::Text(text) => text.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+// Written as in an annotation, i.e. 'it\'s', 3, 2.5 or true
+impl fmt::Display for AnnotationValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnnotationValue::Text(text) => {
+                write!(f, "'")?;
+                for character in text.chars() {
+                    match character {
+                        '\\' => write!(f, "\\\\")?,
+                        '\'' => write!(f, "\\'")?,
+                        // the annotation is the title of a markdown link, between double quotes
+                        '"' => write!(f, "\\\"")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\r' => write!(f, "\\r")?,
+                        '\t' => write!(f, "\\t")?,
+                        other => write!(f, "{}", other)?,
+                    }
+                }
+                write!(f, "'")
+            }
+            AnnotationValue::Integer(integer) => write!(f, "{}", integer),
+            // the debug format keeps a dot or an exponent, i.e. "1.0" and not "1"
+            AnnotationValue::Float(float) => write!(f, "{:?}", float),
+            AnnotationValue::Boolean(boolean) => write!(f, "{}", boolean),
+        }
+    }
 }
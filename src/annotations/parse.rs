@@ -1,41 +1,75 @@
 use chrono::DateTime;
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 use uuid::Uuid;
 
 use crate::{
-    annotations::{extract::extract_field_from_annotation, models::Annotation},
+    annotations::{
+        grammar::{parse_pairs, VERSION_KEY},
+        models::{Annotation, AnnotationValue},
+    },
     events::models::EventName,
 };
 
-pub fn parse_annotation(annotation_in_text: &str) -> Result<Annotation, &str> {
+// Take a text field out of the pairs of an annotation
+fn take_text(fields: &mut BTreeMap<String, AnnotationValue>, key: &str) -> Option<String> {
+    match fields.remove(key)? {
+        AnnotationValue::Text(text) => Some(text),
+        _ => None,
+    }
+}
+
+/**
+ * Parse an annotation with or without its "not:" prefix, written with any version of the
+ * grammar. Every key besides v, date, event and uid goes to the fields.
+ */
+pub fn parse_annotation(annotation_in_text: &str) -> Result<Annotation, String> {
+    let pairs = parse_pairs(annotation_in_text)?;
+    let keys = pairs.iter().map(|(key, _)| key.clone()).collect();
+
+    Ok(Annotation {
+        keys,
+        ..annotation_from_fields(pairs.into_iter().collect())?
+    })
+}
+
+// Build an annotation from its pairs, i.e. a session of a front matter
+pub fn annotation_from_fields(
+    mut fields: BTreeMap<String, AnnotationValue>,
+) -> Result<Annotation, String> {
+    fields.remove(VERSION_KEY);
+
     // extract datetime
-    let datetime = extract_field_from_annotation(annotation_in_text, "date")
-        .and_then(|datetime_str| DateTime::parse_from_rfc3339(&datetime_str).ok())
+    let datetime_text = take_text(&mut fields, "date");
+    let datetime = datetime_text
+        .as_deref()
+        .and_then(|datetime_str| DateTime::parse_from_rfc3339(datetime_str).ok())
         .ok_or("Missing or invalid date")?;
 
     // extract event
-    let event = extract_field_from_annotation(annotation_in_text, "event")
+    let event = take_text(&mut fields, "event")
         .and_then(|event_str| EventName::from_str(&event_str).ok())
         .ok_or("Missing or invalid event")?;
 
     // extract uid
-    let uid = extract_field_from_annotation(annotation_in_text, "uid")
+    let uid = take_text(&mut fields, "uid")
         .and_then(|uid_str| Uuid::parse_str(&uid_str).ok())
         .ok_or("Missing or invalid uid")?;
 
-    let workday = extract_field_from_annotation(annotation_in_text, "workday");
-
     Ok(Annotation {
         _uid: uid,
         event,
         datetime,
-        workday,
+        datetime_text,
+        fields,
+        keys: Vec::new(),
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::annotations::parse::parse_annotation;
+    use crate::annotations::{
+        annotate::format_annotation, models::AnnotationValue, parse::parse_annotation,
+    };
 
     #[test]
     #[serial_test::serial]
@@ -55,4 +89,45 @@ mod tests {
             "b86bc6ed-50a5-4ef2-bdd3-e17baef11eff"
         );
     }
+
+    #[test]
+    fn test_parse_annotation_with_plugin_field() {
+        let raw_annotation = "not:{date:'2025-09-29T00:00:43+02:00',event:'STOP_WORK',uid:'b86bc6ed-50a5-4ef2-bdd3-e17baef11eff',workday:'2025-09-28'}";
+        let annotation = parse_annotation(raw_annotation).unwrap();
+        assert_eq!(annotation.field("workday"), Some("2025-09-28"));
+        assert_eq!(annotation.field("unknown"), None);
+    }
+
+    #[test]
+    fn test_parse_annotation_round_trips_any_field() {
+        let raw_annotation = r#"not:{v:2,date:'2025-09-29T00:00:43.245684903+02:00',event:'START_WORK',uid:'b86bc6ed-50a5-4ef2-bdd3-e17baef11eff',billable:true,client:'O\'Neil \"& sons\"',hours:1.5,pages:12}"#;
+        let annotation = parse_annotation(raw_annotation).unwrap();
+
+        assert_eq!(annotation.field("client"), Some(r#"O'Neil "& sons""#));
+        assert_eq!(
+            annotation.fields.get("pages"),
+            Some(&AnnotationValue::Integer(12))
+        );
+        assert_eq!(format_annotation(&annotation), raw_annotation);
+    }
+
+    #[test]
+    fn test_parse_annotation_keeps_the_order_of_the_keys() {
+        let raw_annotation = "not:{v:2,uid:'b86bc6ed-50a5-4ef2-bdd3-e17baef11eff',zone:'b',event:'START_WORK',area:'a',date:'2025-09-29T00:00:43+02:00'}";
+        let annotation = parse_annotation(raw_annotation).unwrap();
+
+        assert_eq!(format_annotation(&annotation), raw_annotation);
+    }
+
+    #[test]
+    fn test_parse_annotation_keeps_the_datetime_as_written() {
+        let raw_annotation = "not:{v:2,date:'2026-08-05T07:00:00Z',event:'START_WORK',uid:'b86bc6ed-50a5-4ef2-bdd3-e17baef11eff'}";
+        let mut annotation = parse_annotation(raw_annotation).unwrap();
+
+        assert_eq!(format_annotation(&annotation), raw_annotation);
+
+        // a changed datetime is written in full
+        annotation.datetime += chrono::Duration::hours(1);
+        assert!(format_annotation(&annotation).contains("date:'2026-08-05T08:00:00+00:00'"));
+    }
 }
@@ -0,0 +1,385 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashMap;
+
+use crate::{
+    errors::models::NostResult,
+    export::render::escape_html,
+    locales::{get::fill, models::Locale},
+    statistics::print::format_duration,
+    vaults::vault::Vault,
+};
+
+// Shades of the terminal heatmap, from no activity to the last threshold
+const SHADES: [char; 5] = ['·', '░', '▒', '▓', '█'];
+// Colours of the SVG heatmap, the ones of GitHub
+const COLOURS: [&str; 5] = ["#ebedf0", "#9be9a8", "#40c463", "#30a14e", "#216e39"];
+// Size of a day in the SVG, with the gap to the next one
+const SVG_CELL: usize = 11;
+const SVG_STEP: usize = 13;
+// Room for the weekday labels on the left and the month labels on top of the SVG
+const SVG_LEFT: usize = 30;
+const SVG_TOP: usize = 20;
+
+/// What a day of the heatmap counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapMetric {
+    WorkMinutes,
+    Notes,
+}
+
+impl HeatmapMetric {
+    /// The thresholds used without --thresholds: 30 minutes, 2, 4 and 6 hours, or 1 to 4 notes.
+    pub fn default_thresholds(&self) -> [i32; 4] {
+        match self {
+            HeatmapMetric::WorkMinutes => [30, 120, 240, 360],
+            HeatmapMetric::Notes => [1, 2, 3, 4],
+        }
+    }
+
+    /// Parse 4 increasing thresholds, in hours for the work, i.e. "0.5,2,4,6".
+    pub fn parse_thresholds(&self, text: &str) -> Result<[i32; 4], String> {
+        let error = || {
+            format!(
+                "Invalid thresholds '{}', expected 4 increasing values, i.e. {}",
+                text,
+                match self {
+                    HeatmapMetric::WorkMinutes => "0.5,2,4,6 (hours)",
+                    HeatmapMetric::Notes => "1,2,3,4 (notes)",
+                }
+            )
+        };
+
+        let values = text
+            .split(',')
+            .map(|value| {
+                let value = value.trim().parse::<f32>().map_err(|_| error())?;
+                Ok(match self {
+                    HeatmapMetric::WorkMinutes => (value * 60.0).round() as i32,
+                    HeatmapMetric::Notes => value.round() as i32,
+                })
+            })
+            .collect::<Result<Vec<i32>, String>>()?;
+
+        let thresholds: [i32; 4] = values.try_into().map_err(|_| error())?;
+        if thresholds[0] <= 0 || thresholds.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(error());
+        }
+
+        Ok(thresholds)
+    }
+
+    fn format_value(&self, value: i32) -> String {
+        match self {
+            HeatmapMetric::WorkMinutes => format_duration(value),
+            HeatmapMetric::Notes => value.to_string(),
+        }
+    }
+}
+
+/// The activity of each day of a year.
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    pub year: i32,
+    pub metric: HeatmapMetric,
+    // the days without activity are missing
+    pub values: HashMap<NaiveDate, i32>,
+    pub thresholds: [i32; 4],
+}
+
+impl Heatmap {
+    pub fn value(&self, date: NaiveDate) -> i32 {
+        self.values.get(&date).copied().unwrap_or(0)
+    }
+
+    /// The number of thresholds reached by a day, from 0 to 4.
+    pub fn level(&self, date: NaiveDate) -> usize {
+        let value = self.value(date);
+        self.thresholds
+            .iter()
+            .filter(|threshold| value >= **threshold)
+            .count()
+    }
+
+    // The Monday of the first week of the year and the number of weeks, the first and the last
+    // ones being partial
+    fn weeks(&self) -> (NaiveDate, usize) {
+        let first = NaiveDate::from_ymd_opt(self.year, 1, 1).unwrap();
+        let last = NaiveDate::from_ymd_opt(self.year, 12, 31).unwrap();
+        let start = first - Duration::days(first.weekday().num_days_from_monday() as i64);
+
+        (start, ((last - start).num_days() / 7 + 1) as usize)
+    }
+
+    // The day of a week column and a weekday row, None outside of the year
+    fn day(&self, start: NaiveDate, week: usize, weekday: usize) -> Option<NaiveDate> {
+        let day = start + Duration::days((week * 7 + weekday) as i64);
+        (day.year() == self.year).then_some(day)
+    }
+
+    // The week column of the first day of each month
+    fn month_columns(&self, start: NaiveDate) -> Vec<(u32, usize)> {
+        (1..=12)
+            .map(|month| {
+                let first = NaiveDate::from_ymd_opt(self.year, month, 1).unwrap();
+                (month, ((first - start).num_days() / 7) as usize)
+            })
+            .collect()
+    }
+
+    fn total(&self) -> String {
+        self.metric.format_value(self.values.values().sum())
+    }
+
+    fn active_days(&self) -> usize {
+        self.values.values().filter(|value| **value > 0).count()
+    }
+}
+
+/**
+ * Count the activity of each day of a year in some vaults: the worked minutes from their
+ * journals or the notes found in their folders.
+ */
+pub fn build_heatmap(
+    vaults: &[Vault],
+    year: i32,
+    metric: HeatmapMetric,
+    thresholds: [i32; 4],
+) -> NostResult<Heatmap> {
+    let mut values: HashMap<NaiveDate, i32> = HashMap::new();
+    for vault in vaults {
+        match metric {
+            HeatmapMetric::WorkMinutes => {
+                for (day, minutes) in vault.minutes_by_day(&year.to_string())? {
+                    if let Ok(date) = NaiveDate::parse_from_str(&day, "%Y-%m-%d") {
+                        *values.entry(date).or_default() += minutes;
+                    }
+                }
+            }
+            HeatmapMetric::Notes => {
+                for note in vault.dated_notes()? {
+                    if note.date.year() == year {
+                        *values.entry(note.date).or_default() += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Heatmap {
+        year,
+        metric,
+        values,
+        thresholds,
+    })
+}
+
+// The short name of a month, i.e. "Aug"
+fn short_month(locale: &Locale, month: u32) -> String {
+    locale.months[(month - 1) as usize]
+        .chars()
+        .take(3)
+        .collect()
+}
+
+/**
+ * Draw a heatmap in the terminal, a column per week and a row per weekday, shaded by the
+ * thresholds reached by each day.
+ * i.e.
+ *     Jan Feb
+ * Mon  ·░▒▓█·
+ */
+pub fn render_heatmap(heatmap: &Heatmap, locale: &Locale) -> String {
+    let (start, weeks) = heatmap.weeks();
+
+    let mut months = vec![' '; weeks];
+    for (month, column) in heatmap.month_columns(start) {
+        for (offset, c) in short_month(locale, month).chars().enumerate() {
+            if let Some(cell) = months.get_mut(column + offset) {
+                *cell = c;
+            }
+        }
+    }
+    let months: String = months.into_iter().collect();
+    let mut rendered = format!("{}\n    {}\n", heatmap.year, months.trim_end());
+
+    for (weekday, label) in locale.weekdays_short.iter().enumerate() {
+        let mut row = format!("{:<4}", label.chars().take(3).collect::<String>());
+        for week in 0..weeks {
+            row.push(match heatmap.day(start, week, weekday) {
+                Some(day) => SHADES[heatmap.level(day)],
+                None => ' ',
+            });
+        }
+        rendered.push_str(row.trim_end());
+        rendered.push('\n');
+    }
+
+    let legend: Vec<String> = heatmap
+        .thresholds
+        .iter()
+        .enumerate()
+        .map(|(level, threshold)| {
+            format!(
+                "{} {}",
+                SHADES[level + 1],
+                heatmap.metric.format_value(*threshold)
+            )
+        })
+        .collect();
+    rendered.push_str(&format!(
+        "\n{}: {} · {}\n{} 0  {}\n",
+        locale.stats.total,
+        heatmap.total(),
+        fill(
+            locale.messages.days_count,
+            &[&heatmap.active_days().to_string()]
+        ),
+        SHADES[0],
+        legend.join("  ")
+    ));
+
+    rendered
+}
+
+/**
+ * Draw a heatmap as an SVG image, GitHub style. Each day has a tooltip with its date and value.
+ */
+pub fn render_heatmap_svg(heatmap: &Heatmap, locale: &Locale) -> String {
+    let (start, weeks) = heatmap.weeks();
+    let width = SVG_LEFT + weeks * SVG_STEP;
+    let height = SVG_TOP + 7 * SVG_STEP + SVG_STEP * 2;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"10\" fill=\"#767676\">\n",
+        w = width,
+        h = height
+    );
+
+    for (month, column) in heatmap.month_columns(start) {
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\">{}</text>\n",
+            SVG_LEFT + column * SVG_STEP,
+            SVG_TOP - 8,
+            escape_html(&short_month(locale, month))
+        ));
+    }
+    // Mon, Wed and Fri, like GitHub
+    for weekday in [0, 2, 4] {
+        svg.push_str(&format!(
+            "<text x=\"0\" y=\"{}\">{}</text>\n",
+            SVG_TOP + weekday * SVG_STEP + SVG_CELL - 2,
+            escape_html(locale.weekdays_short[weekday])
+        ));
+    }
+
+    for week in 0..weeks {
+        for weekday in 0..7 {
+            let Some(day) = heatmap.day(start, week, weekday) else {
+                continue;
+            };
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" rx=\"2\" fill=\"{}\"><title>{}: {}</title></rect>\n",
+                SVG_LEFT + week * SVG_STEP,
+                SVG_TOP + weekday * SVG_STEP,
+                COLOURS[heatmap.level(day)],
+                day.format("%Y-%m-%d"),
+                heatmap.metric.format_value(heatmap.value(day)),
+                s = SVG_CELL
+            ));
+        }
+    }
+
+    // the legend, under the last weeks
+    let legend_y = SVG_TOP + 7 * SVG_STEP + SVG_STEP / 2;
+    let legend_x = width.saturating_sub(COLOURS.len() * SVG_STEP + SVG_LEFT);
+    svg.push_str(&format!(
+        "<text x=\"{}\" y=\"{}\">{}: {}</text>\n",
+        SVG_LEFT,
+        legend_y + SVG_CELL - 2,
+        escape_html(locale.stats.total),
+        heatmap.total()
+    ));
+    for (level, colour) in COLOURS.iter().enumerate() {
+        let threshold = match level {
+            0 => 0,
+            _ => heatmap.thresholds[level - 1],
+        };
+        svg.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" rx=\"2\" fill=\"{}\"><title>{}</title></rect>\n",
+            legend_x + level * SVG_STEP,
+            legend_y,
+            colour,
+            heatmap.metric.format_value(threshold),
+            s = SVG_CELL
+        ));
+    }
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locales::get::find_locale;
+    use crate::test_helpers::date;
+
+    fn heatmap() -> Heatmap {
+        Heatmap {
+            year: 2026,
+            metric: HeatmapMetric::WorkMinutes,
+            values: HashMap::from([
+                (date(2026, 1, 1), 20),
+                (date(2026, 1, 2), 30),
+                (date(2026, 1, 5), 133),
+                (date(2026, 1, 6), 480),
+            ]),
+            thresholds: HeatmapMetric::WorkMinutes.default_thresholds(),
+        }
+    }
+
+    #[test]
+    fn thresholds_are_parsed_in_hours_and_must_increase() {
+        assert_eq!(
+            HeatmapMetric::WorkMinutes.parse_thresholds("0.5,2,4,6"),
+            Ok([30, 120, 240, 360])
+        );
+        assert_eq!(
+            HeatmapMetric::Notes.parse_thresholds("1, 3, 5, 8"),
+            Ok([1, 3, 5, 8])
+        );
+        assert!(HeatmapMetric::Notes.parse_thresholds("1,2,3").is_err());
+        assert!(HeatmapMetric::Notes.parse_thresholds("1,3,2,4").is_err());
+        assert!(HeatmapMetric::Notes.parse_thresholds("0,1,2,3").is_err());
+    }
+
+    #[test]
+    fn days_are_shaded_by_the_thresholds_they_reach() {
+        let rendered = render_heatmap(&heatmap(), find_locale("en"));
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        // 2026 starts on a Thursday: the first column only has its last 4 days
+        assert_eq!(lines[0], "2026");
+        assert!(lines[1].starts_with("    Jan Feb"));
+        assert!(lines[2].starts_with("Mon  ▒"));
+        assert!(lines[3].starts_with("Tue  █"));
+        assert!(lines[5].starts_with("Thu ··"));
+        assert!(lines[6].starts_with("Fri ░·"));
+        assert_eq!(lines[10], "Total: 11h03 · 4 day(s)");
+        assert_eq!(lines[11], "· 0  ░ 0h30  ▒ 2h00  ▓ 4h00  █ 6h00");
+
+        let rendered = render_heatmap(&heatmap(), find_locale("de"));
+        assert!(rendered.contains("\nGesamt: 11h03 · 4 Tag(e)\n"));
+    }
+
+    #[test]
+    fn svg_has_a_day_per_rect_with_its_value() {
+        let svg = render_heatmap_svg(&heatmap(), find_locale("en"));
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("fill=\"#40c463\"><title>2026-01-05: 2h13</title>"));
+        // 365 days and the 5 squares of the legend
+        assert_eq!(svg.matches("<rect").count(), 365 + 5);
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
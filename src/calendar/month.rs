@@ -0,0 +1,210 @@
+use chrono::{Datelike, Local, NaiveDate};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    dates::get::{get_days_of_month, get_week_of_month_for_date},
+    errors::models::{NostError, NostResult},
+    files::models::DatedNote,
+    locales::models::Locale,
+    statistics::{models::MonthStats, print::format_duration},
+    vaults::vault::Vault,
+};
+
+// Width of a day column, i.e. " 5 dw   " or " 2h13   "
+const CELL_WIDTH: usize = 8;
+// Marker of the legacy notes, which have no type
+const UNTYPED_MARKER: char = '•';
+
+// The marker of a note type in the grid, i.e. 'w' for "work"
+fn type_marker(note_type: Option<&str>) -> char {
+    note_type
+        .and_then(|note_type| note_type.chars().next())
+        .map(|c| c.to_ascii_lowercase())
+        .unwrap_or(UNTYPED_MARKER)
+}
+
+// The worked minutes of each day of the stats, i.e. "2026-08-05" -> 133
+fn minutes_by_day(stats: &MonthStats) -> HashMap<&str, i32> {
+    stats
+        .work_stats_by_week
+        .values()
+        .flat_map(|week_stats| week_stats.work_stats.iter())
+        .map(|stat| (stat.day.as_str(), stat.length_in_minutes))
+        .collect()
+}
+
+/**
+ * Draw a month as a grid, a row per week numbered like the week folders of the layout.
+ * Each day shows the markers of its notes, i.e. "dw" for a default and a work note, and the
+ * hours worked that day.
+ * i.e.
+ *    Mon     Tue     Wed ...
+ *  2  3 w     4       5 dw
+ *     7h30            2h13
+ */
+pub fn render_month_calendar(
+    month: NaiveDate,
+    notes: &[DatedNote],
+    stats: &MonthStats,
+    locale: &Locale,
+) -> String {
+    let minutes = minutes_by_day(stats);
+    let mut markers_by_day: HashMap<NaiveDate, Vec<char>> = HashMap::new();
+    // the legend, sorted by marker
+    let mut legend: BTreeMap<char, Vec<&str>> = BTreeMap::new();
+    for note in notes
+        .iter()
+        .filter(|note| note.date.year() == month.year() && note.date.month() == month.month())
+    {
+        let marker = type_marker(note.note_type.as_deref());
+        let markers = markers_by_day.entry(note.date).or_default();
+        if !markers.contains(&marker) {
+            markers.push(marker);
+            markers.sort();
+        }
+        let note_types = legend.entry(marker).or_default();
+        let note_type = note.note_type.as_deref().unwrap_or("note");
+        if !note_types.contains(&note_type) {
+            note_types.push(note_type);
+        }
+    }
+
+    let mut header = "   ".to_string();
+    for weekday in locale.weekdays_short {
+        header.push_str(&format!("{:<width$}", weekday, width = CELL_WIDTH));
+    }
+    let mut calendar = format!(
+        "{} {}\n{}\n",
+        locale.months[month.month0() as usize],
+        month.year(),
+        header.trim_end()
+    );
+
+    let days = get_days_of_month(month);
+    let mut weeks: BTreeMap<u32, Vec<NaiveDate>> = BTreeMap::new();
+    for day in days {
+        weeks
+            .entry(get_week_of_month_for_date(day))
+            .or_default()
+            .push(day);
+    }
+
+    for (week, days) in weeks {
+        let mut notes_line = format!("{:>2} ", week);
+        let mut hours_line = "   ".to_string();
+        for weekday in 0..7 {
+            let day = days
+                .iter()
+                .find(|day| day.weekday().num_days_from_monday() == weekday);
+            let (notes_cell, hours_cell) = match day {
+                Some(day) => {
+                    let markers: String = markers_by_day
+                        .get(day)
+                        .map(|markers| markers.iter().collect())
+                        .unwrap_or_default();
+                    let hours = minutes
+                        .get(day.format("%Y-%m-%d").to_string().as_str())
+                        .map(|minutes| format_duration(*minutes))
+                        .unwrap_or_default();
+                    (format!("{:>2} {}", day.day(), markers), hours)
+                }
+                None => (String::new(), String::new()),
+            };
+            notes_line.push_str(&format!("{:<width$}", notes_cell, width = CELL_WIDTH));
+            hours_line.push_str(&format!(" {:<width$}", hours_cell, width = CELL_WIDTH - 1));
+        }
+        calendar.push_str(notes_line.trim_end());
+        calendar.push('\n');
+        if !hours_line.trim().is_empty() {
+            calendar.push_str(hours_line.trim_end());
+            calendar.push('\n');
+        }
+    }
+
+    calendar.push_str(&format!(
+        "\n{}: {} · {}: {}\n",
+        locale.stats.total,
+        format_duration(stats.total_duration_in_minutes),
+        locale.stats.work_days,
+        stats.total_work_days
+    ));
+    if !legend.is_empty() {
+        let legend: Vec<String> = legend
+            .iter()
+            .map(|(marker, note_types)| format!("{} {}", marker, note_types.join("/")))
+            .collect();
+        calendar.push_str(&format!("{}\n", legend.join(" · ")));
+    }
+
+    calendar
+}
+
+/**
+ * Draw the calendar of a month ("YYYY-MM") of the vault, the current month if None.
+ */
+pub fn month_calendar(vault: &Vault, month: Option<&str>) -> NostResult<String> {
+    let date = match month {
+        Some(month) => NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+            .map_err(|_| NostError::Usage(vault.locale().messages.invalid_month.to_string()))?,
+        None => Local::now().date_naive(),
+    };
+    let month = date.format("%Y-%m").to_string();
+
+    let notes = vault.dated_notes()?;
+    let stats = vault.month_stats(Some(&month))?;
+
+    Ok(render_month_calendar(date, &notes, &stats, vault.locale()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{date, event_at};
+    use crate::{
+        events::models::EventName, locales::get::find_locale,
+        statistics::compute::compute_stats_from_events,
+    };
+    use std::path::PathBuf;
+
+    fn note(date: NaiveDate, note_type: Option<&str>) -> DatedNote {
+        DatedNote {
+            path: PathBuf::from(format!("{}.md", date)),
+            date,
+            note_type: note_type.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn weeks_are_numbered_like_the_week_folders() {
+        let notes = vec![
+            note(date(2026, 8, 3), Some("work")),
+            note(date(2026, 8, 5), Some("work")),
+            note(date(2026, 8, 5), Some("default")),
+            note(date(2026, 8, 31), None),
+            note(date(2026, 9, 1), Some("work")),
+        ];
+        let stats = compute_stats_from_events(vec![
+            event_at(EventName::StartWork, "2026-08-05T09:00:00+00:00"),
+            event_at(EventName::StopWork, "2026-08-05T11:13:00+00:00"),
+        ]);
+
+        let calendar = render_month_calendar(date(2026, 8, 1), &notes, &stats, find_locale("en"));
+
+        assert_eq!(
+            calendar,
+            "August 2026
+   Mon     Tue     Wed     Thu     Fri     Sat     Sun
+ 1                                          1       2
+ 2  3 w     4       5 dw    6       7       8       9
+                    2h13
+ 3 10      11      12      13      14      15      16
+ 4 17      18      19      20      21      22      23
+ 5 24      25      26      27      28      29      30
+ 6 31 •
+
+Total: 2h13 · Work Days: 1
+d default · w work · • note
+"
+        );
+    }
+}
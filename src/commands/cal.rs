@@ -0,0 +1,26 @@
+use crate::calendar::month::month_calendar;
+use crate::dates::validate::is_valid_month_string;
+use crate::errors::models::{NostError, NostResult};
+use crate::vaults::vault::Vault;
+
+/**
+ * Print the calendar of a month with its notes and worked hours.
+ * usage: nost cal [YYYY-MM]
+ */
+pub fn cal(args: Vec<String>) -> NostResult<()> {
+    let vault = Vault::from_config()?;
+
+    // Optional first arg is month in format YYYY-MM
+    let month = args.get(2);
+    if let Some(month) = month {
+        if !is_valid_month_string(month) {
+            return Err(NostError::Usage(
+                vault.locale().messages.invalid_month.to_string(),
+            ));
+        }
+    }
+
+    print!("{}", month_calendar(&vault, month.map(String::as_str))?);
+
+    Ok(())
+}
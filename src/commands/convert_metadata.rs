@@ -0,0 +1,121 @@
+use std::{
+    fs::{read_to_string, write},
+    path::PathBuf,
+};
+
+use crate::{
+    configurations::models::MetadataStyle,
+    errors::models::{NostError, NostResult},
+    files::convert_metadata::convert_note_metadata,
+    git::commit::autocommit,
+    locales::get::fill,
+    vaults::vault::Vault,
+};
+
+const CONVERT_METADATA_USAGE: &str =
+    "Usage: nost convert-metadata --to annotations|front_matter [--apply]";
+
+/**
+ * Move the metadata of every note to annotations or to front matter.
+ * usage: nost convert-metadata --to annotations|front_matter [--apply]
+ * Without --apply, only the notes to convert are displayed.
+ */
+pub fn convert_metadata(args: Vec<String>) -> NostResult<()> {
+    let mut to: Option<MetadataStyle> = None;
+    let mut apply = false;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--apply" => apply = true,
+            "--to" => {
+                to = match options.next().map(String::as_str) {
+                    Some("annotations") => Some(MetadataStyle::Annotations),
+                    Some("front_matter") => Some(MetadataStyle::FrontMatter),
+                    value => {
+                        return Err(NostError::Usage(format!(
+                            "Unknown metadata style \"{}\". {}",
+                            value.unwrap_or_default(),
+                            CONVERT_METADATA_USAGE
+                        )))
+                    }
+                }
+            }
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". {}",
+                    option, CONVERT_METADATA_USAGE
+                )));
+            }
+        }
+    }
+    let to = to.ok_or_else(|| NostError::Usage(CONVERT_METADATA_USAGE.to_string()))?;
+
+    let vault = Vault::from_config()?;
+    let messages = &vault.locale().messages;
+    println!("{}\n", fill(messages.convert_start, &[to.as_str()]));
+
+    let mut conversions: Vec<(PathBuf, String)> = Vec::new();
+    let mut skipped = 0;
+    for path in vault.notes()? {
+        let content = read_to_string(&path)
+            .map_err(|e| NostError::io(format!("Cannot read {}", path.display()), e))?;
+        let relative = path
+            .strip_prefix(vault.path())
+            .unwrap_or(&path)
+            .display()
+            .to_string();
+
+        match convert_note_metadata(&content, to) {
+            Ok(Some(converted)) => {
+                println!("{}", fill(messages.convert_note, &[&relative]));
+                conversions.push((path.clone(), converted));
+            }
+            Ok(None) => {}
+            Err(reason) => {
+                println!("{}", fill(messages.convert_skipped, &[&relative, &reason]));
+                skipped += 1;
+            }
+        }
+    }
+
+    println!(
+        "\n{}",
+        fill(
+            messages.convert_summary,
+            &[&conversions.len().to_string(), &skipped.to_string()]
+        )
+    );
+
+    if !apply {
+        println!("{}", messages.convert_dry_run);
+        return Ok(());
+    }
+
+    if conversions.is_empty() {
+        println!("{}", messages.nothing_to_convert);
+        return Ok(());
+    }
+
+    for (path, converted) in &conversions {
+        write(path, converted)
+            .map_err(|e| NostError::io(format!("Cannot write {}", path.display()), e))?;
+    }
+    println!(
+        "{}",
+        fill(messages.notes_converted, &[&conversions.len().to_string()])
+    );
+
+    let paths: Vec<PathBuf> = conversions.into_iter().map(|(path, _)| path).collect();
+    autocommit(
+        &vault,
+        &format!(
+            "convert-metadata: {} note(s) to {}",
+            paths.len(),
+            to.as_str()
+        ),
+        &paths,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+
+use crate::{
+    dates::parse::parse_iso_date,
+    errors::models::{NostError, NostResult},
+    export::{
+        html::export_html,
+        markdown::{export_markdown, MarkdownExport},
+    },
+    vaults::vault::Vault,
+};
+
+const EXPORT_USAGE: &str = "Usage: nost export html <out_dir> | nost export md --from YYYY-MM-DD --to YYYY-MM-DD [--type work] [--strip-annotations] [--strip-private]";
+
+fn export_md(args: &[String]) -> NostResult<()> {
+    let mut from = None;
+    let mut to = None;
+    let mut note_type = None;
+    let mut strip_annotations = false;
+    let mut strip_private = false;
+
+    let mut options = args.iter().skip(3);
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--strip-annotations" => strip_annotations = true,
+            "--strip-private" => strip_private = true,
+            "--from" | "--to" | "--type" => {
+                let value = options.next().ok_or_else(|| {
+                    NostError::Usage(format!("Missing value for {}. {}", option, EXPORT_USAGE))
+                })?;
+                match option.as_str() {
+                    "--type" => note_type = Some(value.to_string()),
+                    "--from" => from = Some(parse_iso_date(value).map_err(NostError::Usage)?),
+                    _ => to = Some(parse_iso_date(value).map_err(NostError::Usage)?),
+                }
+            }
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". {}",
+                    option, EXPORT_USAGE
+                )));
+            }
+        }
+    }
+
+    let (Some(from), Some(to)) = (from, to) else {
+        return Err(NostError::Usage(EXPORT_USAGE.to_string()));
+    };
+    if from > to {
+        return Err(NostError::Usage(format!(
+            "--from {} is after --to {}",
+            from, to
+        )));
+    }
+
+    let vault = Vault::from_config()?;
+    print!(
+        "{}",
+        export_markdown(
+            &vault,
+            &MarkdownExport {
+                from,
+                to,
+                note_type,
+                strip_annotations,
+                strip_private,
+            },
+        )?
+    );
+
+    Ok(())
+}
+
+/**
+ * Export the notes of the vault to another format.
+ * usage: nost export html <out_dir>
+ *        nost export md --from YYYY-MM-DD --to YYYY-MM-DD [--type work] [--strip-annotations] [--strip-private]
+ * The markdown document is written on the standard output, i.e. `> report.md`.
+ */
+pub fn export(args: Vec<String>) -> NostResult<()> {
+    match (args.get(2).map(String::as_str), args.get(3), args.get(4)) {
+        (Some("html"), Some(out_dir), None) => {
+            let vault = Vault::from_config()?;
+            let out_dir = PathBuf::from(out_dir);
+            let pages = export_html(&vault, &out_dir)?;
+            println!(
+                "✅ {} page(s) written, open {}",
+                pages,
+                out_dir.join("index.html").display()
+            );
+            Ok(())
+        }
+        (Some("md"), _, _) => export_md(&args),
+        _ => Err(NostError::Usage(EXPORT_USAGE.to_string())),
+    }
+}
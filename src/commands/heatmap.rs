@@ -0,0 +1,105 @@
+use chrono::{Datelike, Local};
+use std::{fs::write, path::PathBuf};
+
+use crate::{
+    calendar::heatmap::{build_heatmap, render_heatmap, render_heatmap_svg, HeatmapMetric},
+    errors::models::{NostError, NostResult},
+    locales::get::fill,
+    vaults::vault::Vault,
+};
+
+const HEATMAP_USAGE: &str = "Usage: nost heatmap [YYYY] [--notes] [--thresholds 0.5,2,4,6] [--project <vault>]... [--all-vaults] [--svg <file>]";
+
+/**
+ * Draw the activity of a year, day by day: the worked hours, or the notes with --notes.
+ * usage: nost heatmap [YYYY] [--notes] [--thresholds 0.5,2,4,6] [--project <vault>]... [--all-vaults] [--svg <file>]
+ * A project is a vault of the configuration, --project can be repeated.
+ */
+pub fn heatmap(args: Vec<String>) -> NostResult<()> {
+    let mut year = Local::now().year();
+    let mut metric = HeatmapMetric::WorkMinutes;
+    let mut thresholds = None;
+    let mut projects: Vec<String> = Vec::new();
+    let mut all_vaults = false;
+    let mut svg = None;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--notes" => metric = HeatmapMetric::Notes,
+            "--all-vaults" => all_vaults = true,
+            "--thresholds" | "--project" | "--svg" => {
+                let value = options.next().ok_or_else(|| {
+                    NostError::Usage(format!("Missing value for {}. {}", option, HEATMAP_USAGE))
+                })?;
+                match option.as_str() {
+                    "--thresholds" => thresholds = Some(value.to_string()),
+                    "--project" => projects.push(value.to_string()),
+                    _ => svg = Some(PathBuf::from(value)),
+                }
+            }
+            // a year of four digits, "-999" is not one
+            value if value.len() == 4 && value.bytes().all(|byte| byte.is_ascii_digit()) => {
+                if let Ok(parsed) = value.parse() {
+                    year = parsed;
+                }
+            }
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". {}",
+                    option, HEATMAP_USAGE
+                )));
+            }
+        }
+    }
+
+    // the thresholds are parsed once the metric is known, they are hours or notes
+    let thresholds = match thresholds {
+        Some(text) => metric.parse_thresholds(&text).map_err(NostError::Usage)?,
+        None => metric.default_thresholds(),
+    };
+
+    let vaults = if projects.is_empty() && !all_vaults {
+        vec![Vault::from_config()?]
+    } else {
+        let vaults = Vault::all_from_config()?;
+        if let Some(unknown) = projects.iter().find(|project| {
+            !vaults
+                .iter()
+                .any(|vault| vault.name() == Some(project.as_str()))
+        }) {
+            return Err(NostError::Usage(format!(
+                "Unknown project \"{}\", expected the name of a vault of the configuration",
+                unknown
+            )));
+        }
+        vaults
+            .into_iter()
+            .filter(|vault| {
+                projects.is_empty()
+                    || projects
+                        .iter()
+                        .any(|project| vault.name() == Some(project.as_str()))
+            })
+            .collect()
+    };
+    let locale = vaults[0].locale();
+
+    let heatmap = build_heatmap(&vaults, year, metric, thresholds)?;
+    match svg {
+        Some(path) => {
+            write(&path, render_heatmap_svg(&heatmap, locale))
+                .map_err(|e| NostError::io(format!("Cannot write {}", path.display()), e))?;
+            println!(
+                "{}",
+                fill(
+                    locale.messages.heatmap_written,
+                    &[&year.to_string(), &path.display().to_string()]
+                )
+            );
+        }
+        None => print!("{}", render_heatmap(&heatmap, locale)),
+    }
+
+    Ok(())
+}
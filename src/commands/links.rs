@@ -0,0 +1,160 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    links::{
+        graph::{format_dot, resolve_target},
+        models::{display_note, LinkEdge, LinkGraph},
+    },
+    locales::get::fill,
+    vaults::vault::Vault,
+};
+
+const LINKS_USAGE: &str = "Usage: nost links <YYYY-MM-DD|note> | --broken | --export dot|json";
+
+// i.e. "[[acme#Contacts|the client]]"
+fn format_link(edge: &LinkEdge) -> String {
+    let mut link = edge.link.target.clone();
+    if let Some(heading) = &edge.link.heading {
+        link.push_str(&format!("#{}", heading));
+    }
+    if let Some(alias) = &edge.link.alias {
+        link.push_str(&format!("|{}", alias));
+    }
+
+    format!("[[{}]]", link)
+}
+
+// i.e. "2026/08/2/5/05.work.md:3"
+fn format_source(edge: &LinkEdge) -> String {
+    format!("{}:{}", display_note(&edge.source), edge.link.line)
+}
+
+// The notes of a date, a note name or a path to a note, relative to the vault
+fn find_subject_notes(vault: &Vault, graph: &LinkGraph, subject: &str) -> Vec<PathBuf> {
+    let path = Path::new(subject);
+    if path.is_file() {
+        if let Ok(path) = path.canonicalize() {
+            let vault_path = vault
+                .path()
+                .canonicalize()
+                .unwrap_or_else(|_| vault.path().to_path_buf());
+            if let Ok(relative) = path.strip_prefix(vault_path) {
+                return vec![relative.to_path_buf()];
+            }
+        }
+    }
+
+    resolve_target(
+        &graph.days,
+        &graph.notes,
+        subject.strip_suffix(".md").unwrap_or(subject),
+    )
+}
+
+/**
+ * Show the wiki links of a day or a note, i.e. `[[2026-08-05]]` or `[[acme]]`, and the notes
+ * linking to it.
+ * usage: nost links <YYYY-MM-DD|note>
+ *        nost links --broken
+ *        nost links --export dot|json
+ */
+pub fn links(args: Vec<String>) -> NostResult<()> {
+    let mut subject: Option<String> = None;
+    let mut broken = false;
+    let mut export: Option<String> = None;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--broken" => broken = true,
+            "--export" => {
+                export = match options.next().map(String::as_str) {
+                    Some(format @ ("dot" | "json")) => Some(format.to_string()),
+                    format => {
+                        return Err(NostError::Usage(format!(
+                            "Unknown export format \"{}\". {}",
+                            format.unwrap_or_default(),
+                            LINKS_USAGE
+                        )))
+                    }
+                }
+            }
+            value if !value.starts_with("--") && subject.is_none() => {
+                subject = Some(value.to_string())
+            }
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". {}",
+                    option, LINKS_USAGE
+                )));
+            }
+        }
+    }
+
+    let vault = Vault::from_config()?;
+    let graph = vault.links()?;
+    let messages = &vault.locale().messages;
+
+    match (export.as_deref(), broken, subject) {
+        (Some("dot"), false, None) => print!("{}", format_dot(&graph)),
+        (Some(_), false, None) => {
+            let content = serde_json::to_string_pretty(&graph)
+                .map_err(|e| NostError::io("Failed to serialize the links", e.into()))?;
+            println!("{}", content);
+        }
+        (None, true, None) => {
+            let broken = graph.broken();
+            for edge in &broken {
+                println!("  {}  {}", format_source(edge), format_link(edge));
+            }
+            println!(
+                "\n{}",
+                fill(messages.broken_links, &[&broken.len().to_string()])
+            );
+        }
+        (None, false, Some(subject)) => {
+            let notes = find_subject_notes(&vault, &graph, &subject);
+            if notes.is_empty() {
+                return Err(NostError::Usage(format!(
+                    "No note found for \"{}\". {}",
+                    subject, LINKS_USAGE
+                )));
+            }
+
+            for note in &notes {
+                println!("🔗 {}", display_note(note));
+            }
+
+            let outgoing = graph.outgoing(&notes);
+            println!(
+                "\n{}",
+                fill(messages.outgoing_links, &[&outgoing.len().to_string()])
+            );
+            for edge in outgoing {
+                let resolved = if edge.is_broken() {
+                    messages.broken.to_string()
+                } else {
+                    edge.resolved
+                        .iter()
+                        .map(|path| display_note(path))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                };
+                println!("  → {}  {}", format_link(edge), resolved);
+            }
+
+            let backlinks = graph.backlinks(&notes);
+            println!(
+                "\n{}",
+                fill(messages.backlinks, &[&backlinks.len().to_string()])
+            );
+            for edge in backlinks {
+                println!("  ← {}  {}", format_source(edge), format_link(edge));
+            }
+        }
+        _ => return Err(NostError::Usage(LINKS_USAGE.to_string())),
+    }
+
+    Ok(())
+}
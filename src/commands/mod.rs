@@ -1,3 +1,13 @@
+pub mod cal;
+pub mod convert_metadata;
+pub mod export;
+pub mod heatmap;
+pub mod links;
 pub mod new;
+pub mod plugins;
+pub mod prompt;
+pub mod reorganize;
 pub mod stats;
-pub mod work;
+pub mod status;
+pub mod sync_status;
+pub mod tui;
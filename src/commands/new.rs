@@ -1,19 +1,38 @@
+use chrono::Local;
+
 use crate::{
     dates::parse::parse_iso_date,
-    events::{find::find_last_work_event, models::EventName},
-    files::create::{create_file, create_note_file_with_folders},
+    errors::models::{NostError, NostResult},
+    events::models::EventName,
+    files::{create::create_file, models::NoteFile},
+    git::commit::{autocommit, commit_message},
+    locales::get::fill,
     projects::initialize::initialize_project,
+    vaults::vault::Vault,
 };
-pub fn new_legacy(args: Vec<String>) {
+
+// Create today's note of the vault and tell whether it already existed
+pub fn create_today_note(vault: &Vault, note_type: &str) -> NostResult<NoteFile> {
+    let messages = &vault.locale().messages;
+    let note = vault.create_note(Local::now().date_naive(), note_type)?;
+
+    if note.created {
+        println!(
+            "{}",
+            fill(messages.file_created, &[&note.path.to_string_lossy()])
+        );
+        println!("{}", messages.note_initiated);
+    } else {
+        println!("{}", messages.note_already_exists);
+    }
+
+    Ok(note)
+}
+
+pub fn new_legacy(args: Vec<String>) -> NostResult<()> {
     // Optional date argument in YYYY-MM-DD format
     let date = match args.get(2) {
-        Some(arg) => match parse_iso_date(arg) {
-            Ok(d) => Some(d),
-            Err(msg) => {
-                eprintln!("{}", msg);
-                std::process::exit(1);
-            }
-        },
+        Some(arg) => Some(parse_iso_date(arg).map_err(NostError::Usage)?),
         None => None,
     };
 
@@ -22,20 +41,48 @@ pub fn new_legacy(args: Vec<String>) {
         None => println!("Creating legacy note for today..."),
     }
 
-    create_file(date).unwrap();
+    let path = create_file(date)?;
+
+    autocommit(
+        &Vault::from_config()?,
+        &commit_message(
+            "new-legacy",
+            &EventName::CreateNot.to_string(),
+            &Local::now().to_rfc3339(),
+        ),
+        &[path.into()],
+    );
+
+    Ok(())
 }
 
-pub fn new() {
+pub fn new() -> NostResult<()> {
+    let vault = Vault::from_config()?;
+    let locale = vault.locale();
+
     // Warn if a work session is still open
-    if let Some(last) = find_last_work_event() {
+    if let Some(last) = vault.last_work_event()? {
         if last.event == EventName::StartWork.to_string() {
-            eprintln!("⚠️  Warning: you should first end the last work session before creating a new note.");
+            eprintln!("{}", locale.messages.open_session_warning);
         }
     }
 
-    println!("Creating new note for today...");
-    let _ = initialize_project();
-    let _ = create_note_file_with_folders("default".to_string());
+    println!("{}", locale.messages.creating_note_today);
+    initialize_project()?;
+    let note = create_today_note(&vault, "default")?;
+    if note.created {
+        autocommit(
+            &vault,
+            &commit_message(
+                "new",
+                &EventName::CreateNot.to_string(),
+                &Local::now().to_rfc3339(),
+            ),
+            &[note.path],
+        );
+    }
+
+    println!("{}", locale.messages.note_created);
 
-    println!("✅ Note has been created successfully!");
+    Ok(())
 }
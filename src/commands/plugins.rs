@@ -0,0 +1,36 @@
+use crate::{
+    errors::models::NostResult,
+    locales::get::get_current_locale,
+    plugins::{external::find_external_plugins, registry::PLUGINS},
+};
+
+/**
+ * List the built-in plugins with their commands, then the external plugins found on the PATH.
+ * usage: nost plugins
+ */
+pub fn plugins() -> NostResult<()> {
+    let messages = &get_current_locale().messages;
+    println!("{}", messages.builtin_plugins);
+    for plugin in PLUGINS.iter() {
+        println!("  {}", plugin.name());
+        for command in plugin.commands() {
+            let aliases = if command.aliases.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", command.aliases.join(", "))
+            };
+            println!("    {}{}: {}", command.name, aliases, command.description);
+        }
+    }
+
+    let external_plugins = find_external_plugins();
+    println!("\n{}", messages.external_plugins);
+    if external_plugins.is_empty() {
+        println!("  {}", messages.no_external_plugin);
+    }
+    for plugin in external_plugins {
+        println!("  {}: {}", plugin.name, plugin.path.display());
+    }
+
+    Ok(())
+}
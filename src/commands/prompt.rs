@@ -0,0 +1,116 @@
+use chrono::{DateTime, FixedOffset, Local};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    events::models::SessionState,
+    statistics::print::format_duration,
+    vaults::vault::Vault,
+};
+
+const DEFAULT_PROMPT_FORMAT: &str = "⏱ {elapsed}";
+
+/**
+ * Render the prompt segment of a running session, an empty string without session.
+ * Tokens: {elapsed} (i.e. "2h13"), {minutes} (i.e. "133"), {since} (i.e. "09:00"), {vault}
+ */
+pub fn render_prompt(
+    format: &str,
+    state: &SessionState,
+    now: DateTime<FixedOffset>,
+    vault_name: Option<&str>,
+) -> String {
+    let Some(start) = state
+        .session_start
+        .as_deref()
+        .and_then(|start| DateTime::parse_from_rfc3339(start).ok())
+    else {
+        return String::new();
+    };
+
+    let minutes = (now - start).num_minutes().max(0) as i32;
+
+    format
+        .replace("{elapsed}", &format_duration(minutes))
+        .replace("{minutes}", &minutes.to_string())
+        .replace(
+            "{since}",
+            &start.with_timezone(&Local).format("%H:%M").to_string(),
+        )
+        .replace("{vault}", vault_name.unwrap_or_default())
+}
+
+/**
+ * Print a short segment for the shell prompt while a work session is running.
+ * Only `.nost/state.json` is read, the journal is not.
+ * usage: nost prompt [--format "⏱ {elapsed}"]
+ */
+pub fn prompt(args: Vec<String>) -> NostResult<()> {
+    let mut format = DEFAULT_PROMPT_FORMAT.to_string();
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match (option.as_str(), options.next()) {
+            ("--format", Some(value)) => format = value.clone(),
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". Usage: nost prompt [--format \"{}\"]",
+                    option, DEFAULT_PROMPT_FORMAT
+                )));
+            }
+        }
+    }
+
+    let vault = Vault::from_config()?;
+    let state = vault.session_state()?;
+
+    // no line break, the output is embedded in the prompt
+    print!(
+        "{}",
+        render_prompt(&format, &state, Local::now().fixed_offset(), vault.name())
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::at;
+
+    #[test]
+    fn render_prompt_of_a_running_session() {
+        let state = SessionState {
+            session_start: Some("2026-08-05T09:00:00+02:00".to_string()),
+            ..SessionState::default()
+        };
+
+        let now = at("2026-08-05T11:13:00+02:00");
+
+        assert_eq!(
+            render_prompt(DEFAULT_PROMPT_FORMAT, &state, now, None),
+            "⏱ 2h13"
+        );
+        assert_eq!(
+            render_prompt("[{vault}] {minutes}min", &state, now, Some("acme")),
+            "[acme] 133min"
+        );
+    }
+
+    #[test]
+    fn render_prompt_without_session_is_empty() {
+        let state = SessionState {
+            last_event: Some("STOP_WORK".to_string()),
+            ..SessionState::default()
+        };
+
+        assert_eq!(
+            render_prompt(
+                DEFAULT_PROMPT_FORMAT,
+                &state,
+                at("2026-08-05T11:13:00+02:00"),
+                None
+            ),
+            ""
+        );
+    }
+}
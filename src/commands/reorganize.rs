@@ -0,0 +1,191 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    configurations::get::get_not_path,
+    errors::models::{NostError, NostResult},
+    files::{
+        find::find_all_not_files_with_layouts,
+        layout::{get_layout, validate_layout, DEFAULT_LAYOUT},
+        models::{NoteMove, ReorganizationPlan},
+        reorganize::{apply_reorganization, plan_reorganization, rollback_reorganization},
+    },
+    git::commit::autocommit,
+    locales::{get::fill, models::Messages},
+    projects::initialize::get_project_config_path,
+    vaults::vault::Vault,
+};
+
+fn display_relative(path: &Path, vault_path: &Path) -> String {
+    path.strip_prefix(vault_path)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+// The notes of the moves, at their old and new places, to commit them
+fn moved_paths(moves: &[NoteMove]) -> Vec<PathBuf> {
+    moves
+        .iter()
+        .flat_map(|note_move| [note_move.from.clone(), note_move.to.clone()])
+        .collect()
+}
+
+fn print_plan(plan: &ReorganizationPlan, vault_path: &Path, messages: &Messages) {
+    for note_move in &plan.moves {
+        println!(
+            "{}",
+            fill(
+                messages.reorganize_move,
+                &[
+                    &display_relative(&note_move.from, vault_path),
+                    &display_relative(&note_move.to, vault_path)
+                ]
+            )
+        );
+    }
+    for conflict in &plan.conflicts {
+        println!(
+            "{}",
+            fill(
+                messages.reorganize_conflict,
+                &[
+                    &display_relative(&conflict.from, vault_path),
+                    &display_relative(&conflict.to, vault_path),
+                    &conflict.reason
+                ]
+            )
+        );
+    }
+    for path in &plan.unresolved {
+        println!(
+            "{}",
+            fill(
+                messages.reorganize_skipped,
+                &[&display_relative(path, vault_path)]
+            )
+        );
+    }
+
+    println!(
+        "\n{}",
+        fill(
+            messages.reorganize_summary,
+            &[
+                &plan.moves.len().to_string(),
+                &plan.unchanged.to_string(),
+                &plan.conflicts.len().to_string(),
+                &plan.unresolved.len().to_string()
+            ]
+        )
+    );
+}
+
+/**
+ * Move every note to the place given by the configured layout.
+ * usage: nost reorganize [--from <layout>] [--apply]
+ *        nost reorganize --rollback <manifest>
+ * Without --apply, only the plan is displayed.
+ */
+pub fn reorganize(args: Vec<String>) -> NostResult<()> {
+    let mut from_layout: Option<String> = None;
+    let mut rollback: Option<PathBuf> = None;
+    let mut apply = false;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--apply" => apply = true,
+            "--from" => from_layout = options.next().cloned(),
+            "--rollback" => rollback = options.next().map(PathBuf::from),
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". Usage: nost reorganize [--from <layout>] [--apply] | --rollback <manifest>",
+                    option
+                )));
+            }
+        }
+    }
+
+    let vault_path = PathBuf::from(get_not_path()?);
+    let config_path = PathBuf::from(get_project_config_path()?);
+    let vault = Vault::from_config()?;
+    let messages = &vault.locale().messages;
+
+    if let Some(manifest_path) = rollback {
+        let restored = rollback_reorganization(&manifest_path, &vault_path, &config_path)?;
+        println!(
+            "{}",
+            fill(messages.notes_moved_back, &[&restored.len().to_string()])
+        );
+        autocommit(
+            &vault,
+            &format!("reorganize: {} note(s) moved back", restored.len()),
+            &moved_paths(&restored),
+        );
+        return Ok(());
+    }
+
+    let to_layout = get_layout();
+
+    // notes are searched with the source layout, then the current and the default ones
+    let mut from_layouts: Vec<String> = Vec::new();
+    if let Some(layout) = from_layout {
+        validate_layout(&layout).map_err(NostError::Usage)?;
+        from_layouts.push(layout);
+    }
+    for layout in [to_layout.clone(), DEFAULT_LAYOUT.to_string()] {
+        if !from_layouts.contains(&layout) {
+            from_layouts.push(layout);
+        }
+    }
+
+    let files =
+        find_all_not_files_with_layouts(vault_path.clone(), &from_layouts).map_err(|e| {
+            NostError::io(
+                format!("Cannot read the notes in {}", vault_path.display()),
+                e,
+            )
+        })?;
+
+    println!("{}\n", fill(messages.reorganize_start, &[&to_layout]));
+    let plan = plan_reorganization(&vault_path, files, &from_layouts, &to_layout);
+    print_plan(&plan, &vault_path, messages);
+
+    if !apply {
+        println!("{}", messages.reorganize_dry_run);
+        return Ok(());
+    }
+
+    if plan.moves.is_empty() {
+        println!("{}", messages.nothing_to_move);
+        return Ok(());
+    }
+
+    let manifest_path =
+        apply_reorganization(&plan, &vault_path, &config_path, &from_layouts, &to_layout)?;
+    println!(
+        "{}",
+        fill(messages.notes_moved, &[&plan.moves.len().to_string()])
+    );
+
+    let mut paths = moved_paths(&plan.moves);
+    paths.push(manifest_path.clone());
+    autocommit(
+        &vault,
+        &format!(
+            "reorganize: {} note(s) moved to \"{}\"",
+            plan.moves.len(),
+            to_layout
+        ),
+        &paths,
+    );
+    println!(
+        "{}",
+        fill(
+            messages.reorganize_undo,
+            &[&manifest_path.display().to_string()]
+        )
+    );
+
+    Ok(())
+}
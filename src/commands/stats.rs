@@ -1,43 +1,62 @@
 use crate::dates::validate::is_valid_month_string;
-use crate::statistics::compute::compute_month_stats;
-use crate::statistics::print::print_stats;
+use crate::errors::models::{NostError, NostResult};
+use crate::locales::get::get_current_locale;
+use crate::plugins::models::StatsContext;
+use crate::plugins::registry::PLUGINS;
+use crate::vaults::vault::Vault;
+
+/**
+ * Print the stats section of every plugin for a month.
+ * usage: nost stats [YYYY-MM] [--all-vaults]
+ */
+pub fn stats(args: Vec<String>) -> NostResult<()> {
+    let locale = get_current_locale();
+
+    // Optional --all-vaults flag to aggregate the stats of every vault
+    let all_vaults = args.iter().skip(2).any(|arg| arg == "--all-vaults");
+    let positional: Vec<&String> = args
+        .iter()
+        .skip(2)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect();
 
-pub fn stats(args: Vec<String>) {
     // Optional first arg is month in format YYYY-MM
-    let month = if args.len() > 2 {
-        let m = args[2].as_str();
-        if !is_valid_month_string(m) {
-            eprintln!("Invalid month format. Please use YYYY-MM.");
-            std::process::exit(1);
+    let month = match positional.first() {
+        Some(m) => {
+            if !is_valid_month_string(m) {
+                return Err(NostError::Usage(locale.messages.invalid_month.to_string()));
+            }
+            Some(m.to_string())
         }
-        Some(m.to_string())
+        None => None,
+    };
+
+    let vaults = if all_vaults {
+        Vault::all_from_config()?
     } else {
-        None
+        vec![Vault::from_config()?]
     };
 
-    let stats = match compute_month_stats(month.as_deref()) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("💥 Cannot compute stats: \"{}\".", e);
-            eprintln!("Is there a journal.json with work events for this month?");
-            std::process::exit(1);
-        }
+    let context = StatsContext {
+        vaults: &vaults,
+        month: month.as_deref(),
+        locale,
     };
 
-    log::debug!(
-        "Computed work stats for month {:?}: total_duration_in_minutes={:?}, stats={:?}",
-        month,
-        stats.total_duration_in_minutes,
-        stats
-    );
+    for plugin in PLUGINS.iter() {
+        if let Some(section) = plugin.stats(&context)? {
+            log::debug!("Stats section of plugin {}", plugin.name());
+            println!("{}", section);
+        }
+    }
 
-    let stats_content = print_stats(stats);
-    println!("{}", stats_content);
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::stats;
+    use crate::errors::models::NostError;
     use crate::events::models::Event;
     use std::{env, fs};
     use tempfile::tempdir;
@@ -56,6 +75,7 @@ mod tests {
             day: day.to_string(),
             not_type: "work".to_string(),
             uid: uid.to_string(),
+            marker: None,
         }
     }
 
@@ -75,12 +95,21 @@ mod tests {
         ];
         write_journal(dir.path().to_str().unwrap(), &events);
 
-        stats(vec![
+        let result = stats(vec![
             "nost".to_string(),
             "s".to_string(),
             "2026-08".to_string(),
         ]);
 
-        assert!(true); // smoke test: asserts no panic
+        assert!(result.is_ok(), "{:?}", result.err());
+        // a month out of range is refused
+        assert!(matches!(
+            stats(vec![
+                "nost".to_string(),
+                "s".to_string(),
+                "2026-13".to_string(),
+            ]),
+            Err(NostError::Usage(_))
+        ));
     }
 }
@@ -0,0 +1,129 @@
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    locales::get::fill,
+    statistics::{
+        compute::find_leave_days, print::format_duration, status::compute_work_status,
+        targets::compute_month_expectation,
+    },
+    vaults::vault::Vault,
+};
+
+/**
+ * Show whether a work session is running, the totals of today, this week and this month,
+ * and whether today's note exists.
+ * usage: nost status [--json]
+ */
+pub fn status(args: Vec<String>) -> NostResult<()> {
+    let mut json = false;
+    for option in args.iter().skip(2) {
+        match option.as_str() {
+            "--json" => json = true,
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". Usage: nost status [--json]",
+                    option
+                )));
+            }
+        }
+    }
+
+    let vault = Vault::from_config()?;
+    let now = Local::now();
+
+    // the target of the whole month, not only up to today
+    let events = vault.events()?;
+    let targets = vault.targets();
+    let month_target = (!targets.is_empty()).then(|| {
+        compute_month_expectation(
+            targets,
+            now.date_naive(),
+            NaiveDate::MAX,
+            &find_leave_days(&events),
+        )
+        .expected_minutes
+    });
+
+    let mut status = compute_work_status(&events, now.fixed_offset(), month_target);
+    status.today_notes = vault
+        .notes_of_day(now.date_naive())
+        .iter()
+        .map(|path| {
+            path.strip_prefix(vault.path())
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        })
+        .collect();
+
+    if json {
+        let content = serde_json::to_string_pretty(&status)
+            .map_err(|e| NostError::io("Failed to serialize the status", e.into()))?;
+        println!("{}", content);
+        return Ok(());
+    }
+
+    let locale = vault.locale();
+    match status.since.as_deref().map(DateTime::parse_from_rfc3339) {
+        Some(Ok(since)) => println!(
+            "{}",
+            fill(
+                locale.messages.status_running_since,
+                &[
+                    &since
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string(),
+                    &format_duration(status.elapsed_minutes),
+                ]
+            )
+        ),
+        _ => println!("{}", locale.tui.no_session_running),
+    }
+
+    let month = match status.month_target_minutes {
+        Some(target) if target > 0 => format!(
+            "{} / {} ({}%)",
+            format_duration(status.month_minutes),
+            format_duration(target),
+            status.month_minutes * 100 / target
+        ),
+        _ => format_duration(status.month_minutes),
+    };
+    let totals = [
+        (locale.tui.today, format_duration(status.today_minutes)),
+        (locale.tui.this_week, format_duration(status.week_minutes)),
+        (locale.tui.this_month, month),
+    ];
+    // the durations are aligned after the longest label
+    let label_width = totals
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or_default();
+    println!();
+    for (label, total) in &totals {
+        println!(
+            "{}:{} {}",
+            label,
+            " ".repeat(label_width - label.chars().count()),
+            total
+        );
+    }
+
+    println!();
+    if status.today_notes.is_empty() {
+        println!("{}", locale.tui.no_note_today);
+    } else {
+        println!(
+            "{}",
+            fill(
+                locale.messages.today_note,
+                &[&status.today_notes.join(", ")]
+            )
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+    errors::models::{NostError, NostResult},
+    git::{commit::is_git_repository, status::get_git_status},
+    locales::get::fill,
+    vaults::vault::Vault,
+};
+
+/**
+ * Show the changes of the notes folder which are not committed yet, and whether the branch
+ * is ahead or behind its upstream.
+ * usage: nost sync-status
+ */
+pub fn sync_status() -> NostResult<()> {
+    let vault = Vault::from_config()?;
+
+    if !is_git_repository(vault.path()) {
+        return Err(NostError::Usage(format!(
+            "The notes folder {} is not a git repository, run \"git init\" in it first",
+            vault.path().display()
+        )));
+    }
+
+    let status = get_git_status(vault.path())?;
+
+    let messages = &vault.locale().messages;
+    println!(
+        "{}",
+        fill(
+            messages.sync_notes_folder,
+            &[&vault.path().display().to_string()]
+        )
+    );
+    println!(
+        "{}",
+        fill(
+            messages.sync_autocommit,
+            &[if vault.autocommit() {
+                messages.on
+            } else {
+                messages.off
+            }]
+        )
+    );
+    match (&status.branch, &status.upstream) {
+        (Some(branch), Some(upstream)) => println!(
+            "{}",
+            fill(
+                messages.sync_branch,
+                &[
+                    branch,
+                    &status.ahead.to_string(),
+                    &status.behind.to_string(),
+                    upstream
+                ]
+            )
+        ),
+        (Some(branch), None) => println!("{}", fill(messages.sync_no_upstream, &[branch])),
+        (None, _) => println!("{}", messages.sync_detached),
+    }
+
+    if status.changes.is_empty() {
+        println!("\n{}", messages.sync_committed);
+        return Ok(());
+    }
+
+    println!(
+        "\n{}",
+        fill(messages.sync_changes, &[&status.changes.len().to_string()])
+    );
+    for change in &status.changes {
+        println!("  {} {}", change.status, change.path);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use crate::errors::models::NostResult;
+
+/**
+ * Open the dashboard: today's notes, the session timer, the month stats and the vault tree.
+ * usage: nost tui
+ */
+#[cfg(feature = "tui")]
+pub fn tui(_args: Vec<String>) -> NostResult<()> {
+    use crate::{tui::run::run_dashboard, vaults::vault::Vault};
+
+    run_dashboard(Vault::from_config()?)
+}
+
+#[cfg(not(feature = "tui"))]
+pub fn tui(_args: Vec<String>) -> NostResult<()> {
+    Err(crate::errors::models::NostError::Usage(
+        "nost was built without the tui feature, build it with `cargo build --features tui`"
+            .to_string(),
+    ))
+}
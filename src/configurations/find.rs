@@ -1,7 +1,172 @@
-use std::path::PathBuf;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
 
-use crate::files::find::get_project_root;
+use crate::{
+    errors::models::{NostError, NostResult},
+    files::find::get_project_root,
+};
 
-pub fn find_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    Ok(get_project_root()?.join("config.toml"))
+// Path given with the --config command line option
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_config_path_override(path: PathBuf) {
+    let _ = CONFIG_PATH_OVERRIDE.set(path);
+}
+
+// Read a non-empty environment variable
+fn get_env(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+/**
+ * Pick the configuration file, in order: the --config option, $NOST_CONFIG,
+ * $XDG_CONFIG_HOME/nost/config.toml, ~/.config/nost/config.toml and config.toml at the root
+ * of a source checkout. Explicit paths (--config and $NOST_CONFIG) must exist, the other
+ * locations are skipped when missing.
+ */
+pub fn resolve_config_path(
+    cli_path: Option<&Path>,
+    nost_config: Option<String>,
+    xdg_config_home: Option<String>,
+    home: Option<String>,
+    project_root: Option<PathBuf>,
+) -> NostResult<PathBuf> {
+    let explicit = [
+        cli_path.map(|path| (path.to_path_buf(), "--config")),
+        nost_config.map(|path| (PathBuf::from(path), "$NOST_CONFIG")),
+    ];
+    if let Some((path, origin)) = explicit.into_iter().flatten().next() {
+        if path.is_file() {
+            return Ok(path);
+        }
+        return Err(NostError::ConfigNotFound(format!(
+            "Configuration file '{}' given by {} does not exist",
+            path.display(),
+            origin
+        )));
+    }
+
+    let candidates = [
+        xdg_config_home.map(|dir| PathBuf::from(dir).join("nost").join("config.toml")),
+        home.map(|dir| PathBuf::from(dir).join(".config/nost/config.toml")),
+        project_root.map(|dir| dir.join("config.toml")),
+    ];
+    let candidates: Vec<PathBuf> = candidates.into_iter().flatten().collect();
+
+    match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(NostError::ConfigNotFound(format!(
+            "No configuration file found, looked for: {}",
+            candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))),
+    }
+}
+
+pub fn find_config_path() -> NostResult<PathBuf> {
+    let config_path = resolve_config_path(
+        CONFIG_PATH_OVERRIDE.get().map(PathBuf::as_path),
+        get_env("NOST_CONFIG"),
+        get_env("XDG_CONFIG_HOME"),
+        get_env("HOME"),
+        get_project_root().ok(),
+    )?;
+    log::debug!("Using configuration file: {}", config_path.display());
+
+    Ok(config_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn create_config(path: &Path) -> PathBuf {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "not_path=\"/notes\"\n").unwrap();
+        path.to_path_buf()
+    }
+
+    fn dir_string(path: &Path) -> Option<String> {
+        Some(path.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn cli_path_wins_over_environment() {
+        let dir = tempdir().unwrap();
+        let cli = create_config(&dir.path().join("cli.toml"));
+        let env_config = create_config(&dir.path().join("env.toml"));
+
+        let resolved = resolve_config_path(
+            Some(&cli),
+            dir_string(&env_config),
+            dir_string(dir.path()),
+            None,
+            None,
+        );
+
+        assert_eq!(resolved.unwrap(), cli);
+    }
+
+    #[test]
+    fn missing_explicit_path_is_an_error() {
+        let dir = tempdir().unwrap();
+        let xdg = create_config(&dir.path().join("nost/config.toml"));
+        assert!(xdg.is_file());
+
+        let resolved = resolve_config_path(
+            None,
+            dir_string(&dir.path().join("missing.toml")),
+            dir_string(dir.path()),
+            None,
+            None,
+        );
+
+        assert!(resolved.unwrap_err().to_string().contains("$NOST_CONFIG"));
+    }
+
+    #[test]
+    fn xdg_config_home_wins_over_home() {
+        let xdg_dir = tempdir().unwrap();
+        let home_dir = tempdir().unwrap();
+        let xdg = create_config(&xdg_dir.path().join("nost/config.toml"));
+        create_config(&home_dir.path().join(".config/nost/config.toml"));
+
+        let resolved = resolve_config_path(
+            None,
+            None,
+            dir_string(xdg_dir.path()),
+            dir_string(home_dir.path()),
+            None,
+        );
+
+        assert_eq!(resolved.unwrap(), xdg);
+    }
+
+    #[test]
+    fn missing_locations_are_skipped() {
+        let xdg_dir = tempdir().unwrap();
+        let home_dir = tempdir().unwrap();
+        let root_dir = tempdir().unwrap();
+        let legacy = create_config(&root_dir.path().join("config.toml"));
+
+        let resolved = resolve_config_path(
+            None,
+            None,
+            dir_string(xdg_dir.path()),
+            dir_string(home_dir.path()),
+            Some(root_dir.path().to_path_buf()),
+        );
+        assert_eq!(resolved.unwrap(), legacy);
+
+        let resolved = resolve_config_path(None, None, dir_string(xdg_dir.path()), None, None);
+        assert!(resolved.is_err());
+    }
 }
@@ -1,35 +1,176 @@
-use std::fs;
+use std::{env, fs, sync::OnceLock};
 
 use crate::configurations::find::find_config_path;
-use crate::configurations::models::Config;
+use crate::configurations::models::{Config, EndOfDay, MetadataStyle, WorkTargets};
+use crate::errors::models::{NostError, NostResult};
+use crate::events::models::EventName;
+use crate::hooks::models::Hooks;
+use crate::statistics::models::Pay;
 
-// Load and parse the config file into a Config struct
-pub fn get_config() -> Result<Config, Box<dyn std::error::Error>> {
+// Vault given with the --vault command line option
+static VAULT_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+pub fn set_vault_override(name: String) {
+    let _ = VAULT_OVERRIDE.set(name);
+}
+
+pub fn get_vault_override() -> Option<&'static str> {
+    VAULT_OVERRIDE.get().map(String::as_str)
+}
+
+// Load and parse the config file into a Config struct, without selecting a vault
+pub fn get_raw_config() -> NostResult<Config> {
     let config_path = find_config_path()?;
-    let content = fs::read_to_string(&config_path)?;
-    let config: Config = toml::from_str(&content)?;
+    let content = fs::read_to_string(&config_path).map_err(|e| {
+        NostError::io(
+            format!("Cannot read configuration file {}", config_path.display()),
+            e,
+        )
+    })?;
+    let config: Config = toml::from_str(&content).map_err(|e| {
+        NostError::InvalidConfig(format!(
+            "Invalid configuration file {}: {}",
+            config_path.display(),
+            e
+        ))
+    })?;
 
     Ok(config)
 }
 
+// Load the configuration of the selected vault (--vault option or default vault)
+pub fn get_config() -> NostResult<Config> {
+    let config = get_raw_config()?;
+
+    config.for_vault(get_vault_override())
+}
+
 /**
  * Get a specific value from the configuration file based on the provided key.
  * i.e. get_value_from_config("not_path") will return the value of the "not_path" key in the configuration file.
  */
-pub fn get_value_from_config(key: &str) -> Result<String, Box<dyn std::error::Error>> {
+pub fn get_value_from_config(key: &str) -> NostResult<String> {
     if key.is_empty() {
-        return Err("Config key cannot be empty".into());
+        return Err(NostError::InvalidConfig(
+            "Config key cannot be empty".to_string(),
+        ));
     }
 
     let configurations_keys = Config::keys();
     if !configurations_keys.contains(&key) {
-        return Err(format!("Key '{}' not found in configuration", key).into());
+        return Err(NostError::InvalidConfig(format!(
+            "Key '{}' not found in configuration",
+            key
+        )));
     }
 
     let configuration = get_config()?;
 
     match configuration.get_value(key) {
         Some(value) => Ok(value),
-        None => Err(format!("Key '{}' not found in configuration", key).into()),
+        None => Err(NostError::InvalidConfig(format!(
+            "Key '{}' not found in configuration",
+            key
+        ))),
+    }
+}
+
+/**
+ * Get the path of the notes folder, in order of precedence: the vault selected with --vault,
+ * the NOT_PATH environment variable, then the default vault or the "not_path" key of the
+ * configuration file.
+ */
+pub fn get_not_path() -> NostResult<String> {
+    if get_vault_override().is_none() {
+        if let Ok(not_path) = env::var("NOT_PATH") {
+            if !not_path.is_empty() {
+                return Ok(not_path);
+            }
+        }
+    }
+
+    let not_path = get_value_from_config("not_path")?;
+    if not_path.is_empty() {
+        return Err(NostError::MissingNotesPath);
+    }
+
+    Ok(not_path)
+}
+
+// Get the hooks of the selected vault, none when nost is not configured
+pub fn get_hooks() -> Hooks {
+    get_config().map(|config| config.hooks).unwrap_or_default()
+}
+
+// Get where the metadata of new notes is written, none when nost is not configured
+pub fn get_metadata_style() -> Option<MetadataStyle> {
+    get_config().ok().and_then(|config| config.metadata)
+}
+
+// Get the hours expected to be worked of a configuration, none when it declares no targets
+pub fn get_work_targets_of(config: Option<&Config>) -> WorkTargets {
+    config
+        .and_then(|config| config.work.targets.clone())
+        .unwrap_or_default()
+}
+
+// Get the hours expected to be worked of the selected vault, none when nost is not configured
+pub fn get_work_targets() -> WorkTargets {
+    get_work_targets_of(get_config().ok().as_ref())
+}
+
+// Get the end-of-day policy of the selected vault, only warning when nost is not configured
+pub fn get_work_end_of_day() -> EndOfDay {
+    get_config()
+        .ok()
+        .and_then(|config| config.work.end_of_day)
+        .unwrap_or_default()
+}
+
+// Get how the work days of a configuration are paid
+pub fn get_work_pay_of(config: Option<&Config>) -> Pay {
+    let work = config.map(|config| &config.work);
+
+    Pay {
+        salary: work.and_then(|work| work.salary),
+        currency: work.and_then(|work| work.currency.clone()),
+        paid_leave: work
+            .and_then(|work| work.paid_leave.clone())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|kind| EventName::from_leave_kind(kind))
+            .collect(),
     }
 }
+
+// Get how the work days of the selected vault are paid
+pub fn get_work_pay() -> Pay {
+    get_work_pay_of(get_config().ok().as_ref())
+}
+
+// Get the daily rate of a pay, or the NOST_WORK_SALARY environment variable
+pub fn get_work_salary(pay: &Pay) -> f32 {
+    if let Some(salary) = pay.salary {
+        return salary;
+    }
+
+    env::var("NOST_WORK_SALARY")
+        .unwrap_or_else(|_| {
+            eprintln!("NOST_WORK_SALARY environment variable not set.");
+            "0".to_string()
+        })
+        .parse()
+        .unwrap_or(0.0)
+}
+
+// Get the currency of a pay, or the NOST_WORK_CURRENCY environment variable
+pub fn get_work_currency(pay: &Pay) -> String {
+    if let Some(currency) = &pay.currency {
+        return currency.clone();
+    }
+
+    env::var("NOST_WORK_CURRENCY").unwrap_or_else(|_| {
+        eprintln!("NOST_WORK_CURRENCY environment variable not set.");
+        "EUR".to_string()
+    })
+}
@@ -1,22 +1,366 @@
+use chrono::Weekday;
 use serde::Deserialize;
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
+
+use crate::{errors::models::NostError, files::layout::DEFAULT_LAYOUT, hooks::models::Hooks};
+
+fn default_language() -> String {
+    "en".to_string()
+}
+
+/// Hours expected to be worked, declared as `[work.targets]`.
+/// See `statistics::targets` for how they are combined.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct WorkTargets {
+    pub monday: Option<f32>,
+    pub tuesday: Option<f32>,
+    pub wednesday: Option<f32>,
+    pub thursday: Option<f32>,
+    pub friday: Option<f32>,
+    pub saturday: Option<f32>,
+    pub sunday: Option<f32>,
+    pub week: Option<f32>,
+    pub month: Option<f32>,
+}
+
+impl WorkTargets {
+    // The target of a weekday, Monday first
+    pub fn weekday(&self, weekday: Weekday) -> Option<f32> {
+        match weekday {
+            Weekday::Mon => self.monday,
+            Weekday::Tue => self.tuesday,
+            Weekday::Wed => self.wednesday,
+            Weekday::Thu => self.thursday,
+            Weekday::Fri => self.friday,
+            Weekday::Sat => self.saturday,
+            Weekday::Sun => self.sunday,
+        }
+    }
+
+    pub fn has_weekdays(&self) -> bool {
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]
+        .into_iter()
+        .any(|weekday| self.weekday(weekday).is_some())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.has_weekdays() && self.week.is_none() && self.month.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorkConfig {
+    // daily rate
+    pub salary: Option<f32>,
+    pub currency: Option<String>,
+    pub targets: Option<WorkTargets>,
+    // kinds of leave counted in the salary, i.e. ["holiday", "sick"]
+    pub paid_leave: Option<Vec<String>>,
+    pub end_of_day: Option<EndOfDay>,
+}
+
+/// What to do with a work session left open after its day, declared as `[work.end_of_day]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndOfDayPolicy {
+    // only warn, the session is closed by the next `nost work`
+    #[default]
+    Warn,
+    // close the session at `time` of the day it started
+    Fixed,
+    // close the session at the last event recorded the day it started
+    LastActivity,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EndOfDay {
+    #[serde(default)]
+    pub policy: EndOfDayPolicy,
+    // closing time of the fixed policy, i.e. "18:00"
+    pub time: Option<String>,
+    // `nost work` asks before closing a session older than this, 12 hours by default
+    pub ask_after_hours: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GitConfig {
+    // commit the touched files after each command changing the notes
+    #[serde(default)]
+    pub autocommit: bool,
+}
+
+/// Where the metadata of a new note is written, see `annotations::front_matter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataStyle {
+    // a `[//]: # "not:{...}"` line at the end of the note
+    Annotations,
+    // YAML between `---` lines at the top of the note, read by tools like Obsidian
+    FrontMatter,
+}
+
+impl MetadataStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetadataStyle::Annotations => "annotations",
+            MetadataStyle::FrontMatter => "front_matter",
+        }
+    }
+}
+
+/// A named notes folder, declared as `[vaults.<name>]`.
+/// Missing settings are inherited from the top-level configuration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VaultConfig {
+    pub path: PathBuf,
+    pub language: Option<String>,
+    pub layout: Option<String>,
+    pub metadata: Option<MetadataStyle>,
+    pub work: Option<WorkConfig>,
+    pub hooks: Option<Hooks>,
+    pub git: Option<GitConfig>,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
+    // can be omitted when the NOT_PATH environment variable is set
+    #[serde(default)]
     pub not_path: PathBuf,
+    #[serde(default = "default_language")]
     pub language: String,
+    // folder pattern of the notes, i.e. "{year}/{month}/{week}/{day}"
+    #[serde(default)]
+    pub layout: Option<String>,
+    // metadata written in new notes, none by default
+    #[serde(default)]
+    pub metadata: Option<MetadataStyle>,
+    #[serde(default)]
+    pub work: WorkConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    pub default_vault: Option<String>,
+    #[serde(default)]
+    pub vaults: BTreeMap<String, VaultConfig>,
+    // shell commands run on events, i.e. post_new = "make site"
+    #[serde(default)]
+    pub hooks: Hooks,
 }
 
 impl Config {
     pub fn keys() -> &'static [&'static str] {
-        &["not_path", "language"]
+        &[
+            "not_path",
+            "language",
+            "layout",
+            "metadata",
+            "work.salary",
+            "work.currency",
+            "work.targets.month",
+            "git.autocommit",
+        ]
     }
 
     pub fn get_value(&self, key: &str) -> Option<String> {
         match key {
             "not_path" => Some(self.not_path.to_string_lossy().into_owned()),
             "language" => Some(self.language.clone()),
+            "layout" => Some(
+                self.layout
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_LAYOUT.to_string()),
+            ),
+            "metadata" => self.metadata.map(|metadata| metadata.as_str().to_string()),
+            "work.salary" => self.work.salary.map(|salary| salary.to_string()),
+            "work.currency" => self.work.currency.clone(),
+            "work.targets.month" => self
+                .work
+                .targets
+                .as_ref()
+                .and_then(|targets| targets.month)
+                .map(|month| month.to_string()),
+            "git.autocommit" => Some(self.git.autocommit.to_string()),
             _ => None,
         }
     }
+
+    /**
+     * Get the configuration of a vault, merged with the top-level settings.
+     * Without name, the default vault is used, or the only vault if the top-level
+     * configuration has no not_path. Otherwise the top-level configuration is returned as is.
+     */
+    pub fn for_vault(&self, name: Option<&str>) -> Result<Config, NostError> {
+        let name = match name.or(self.default_vault.as_deref()) {
+            Some(name) => name.to_string(),
+            None if self.vaults.len() == 1 && self.not_path.as_os_str().is_empty() => {
+                self.vaults.keys().next().cloned().unwrap_or_default()
+            }
+            None => return Ok(self.clone()),
+        };
+
+        let vault = self.vaults.get(&name).ok_or_else(|| {
+            NostError::InvalidConfig(format!(
+                "Unknown vault '{}', known vaults: {}",
+                name,
+                self.vaults
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))
+        })?;
+
+        let vault_work = vault.work.clone().unwrap_or_default();
+
+        Ok(Config {
+            not_path: vault.path.clone(),
+            language: vault
+                .language
+                .clone()
+                .unwrap_or_else(|| self.language.clone()),
+            layout: vault.layout.clone().or_else(|| self.layout.clone()),
+            metadata: vault.metadata.or(self.metadata),
+            work: WorkConfig {
+                salary: vault_work.salary.or(self.work.salary),
+                currency: vault_work.currency.or_else(|| self.work.currency.clone()),
+                targets: vault_work.targets.or_else(|| self.work.targets.clone()),
+                paid_leave: vault_work
+                    .paid_leave
+                    .or_else(|| self.work.paid_leave.clone()),
+                end_of_day: vault_work
+                    .end_of_day
+                    .or_else(|| self.work.end_of_day.clone()),
+            },
+            git: vault.git.clone().unwrap_or_else(|| self.git.clone()),
+            default_vault: Some(name),
+            vaults: self.vaults.clone(),
+            hooks: match &vault.hooks {
+                Some(hooks) => self.hooks.merge(hooks),
+                None => self.hooks.clone(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::models::HookStage;
+
+    fn parse(content: &str) -> Config {
+        toml::from_str(content).unwrap()
+    }
+
+    const VAULTS: &str = r#"
+language = "fr"
+default_vault = "personal"
+
+[work]
+salary = 100
+currency = "EUR"
+
+[work.targets]
+month = 140
+
+[work.end_of_day]
+policy = "fixed"
+time = "18:00"
+
+[vaults.personal]
+path = "/notes/personal"
+
+[vaults.acme]
+path = "/notes/acme"
+language = "en"
+layout = "{year}/{month}"
+metadata = "front_matter"
+
+[vaults.acme.work]
+salary = 500
+
+[git]
+autocommit = true
+
+[vaults.acme.git]
+autocommit = false
+
+[hooks]
+post_new = "echo new"
+pre_work_start = "echo start"
+
+[vaults.acme.hooks]
+post_new = "make site"
+"#;
+
+    #[test]
+    fn default_vault_is_used_without_name() {
+        let config = parse(VAULTS).for_vault(None).unwrap();
+
+        assert_eq!(config.not_path, PathBuf::from("/notes/personal"));
+        assert_eq!(config.language, "fr");
+        assert_eq!(config.work.salary, Some(100.0));
+        assert_eq!(config.get_value("git.autocommit"), Some("true".to_string()));
+        assert_eq!(
+            config.get_value("work.targets.month"),
+            Some("140".to_string())
+        );
+        let end_of_day = config.work.end_of_day.unwrap();
+        assert_eq!(end_of_day.policy, EndOfDayPolicy::Fixed);
+        assert_eq!(end_of_day.time, Some("18:00".to_string()));
+    }
+
+    #[test]
+    fn vault_settings_override_top_level_settings() {
+        let config = parse(VAULTS).for_vault(Some("acme")).unwrap();
+
+        assert_eq!(config.not_path, PathBuf::from("/notes/acme"));
+        assert_eq!(config.language, "en");
+        assert_eq!(
+            config.get_value("layout"),
+            Some("{year}/{month}".to_string())
+        );
+        assert_eq!(config.metadata, Some(MetadataStyle::FrontMatter));
+        assert_eq!(config.work.salary, Some(500.0));
+        assert_eq!(config.work.currency, Some("EUR".to_string()));
+        assert!(!config.git.autocommit);
+        assert_eq!(
+            config.hooks.command(HookStage::Post, "CREATE_NOT"),
+            Some("make site")
+        );
+        assert_eq!(
+            config.hooks.command(HookStage::Pre, "START_WORK"),
+            Some("echo start")
+        );
+    }
+
+    #[test]
+    fn unknown_vault_is_an_error() {
+        let error = parse(VAULTS).for_vault(Some("other")).unwrap_err();
+
+        assert!(error.to_string().contains("acme, personal"));
+    }
+
+    #[test]
+    fn configuration_without_vaults_is_unchanged() {
+        let config = parse("not_path = \"/notes\"").for_vault(None).unwrap();
+
+        assert_eq!(config.not_path, PathBuf::from("/notes"));
+        assert_eq!(config.language, "en");
+    }
+
+    #[test]
+    fn single_vault_is_the_default() {
+        let config = parse("[vaults.only]\npath = \"/notes/only\"")
+            .for_vault(None)
+            .unwrap();
+
+        assert_eq!(config.not_path, PathBuf::from("/notes/only"));
+    }
 }
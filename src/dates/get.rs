@@ -1,5 +1,7 @@
 use chrono::{DateTime, Datelike, Local};
 
+use crate::locales::models::Locale;
+
 pub fn get_now_as_string() -> String {
     let now: DateTime<Local> = Local::now();
     format!("{}{}", now.format("%Y-%m-%dT%H:%M:%S"), now.format("%:z"))
@@ -9,11 +11,6 @@ pub fn get_day_as_string(datetime: DateTime<Local>) -> String {
     format!("{}", datetime.format("%d"))
 }
 
-pub fn get_week_of_month() -> u32 {
-    let today = chrono::Local::now().date_naive();
-    get_week_of_month_for_date(today)
-}
-
 pub fn get_week_of_month_for_date(date: chrono::NaiveDate) -> u32 {
     // Get the first day of the month
     let first_of_month = chrono::NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap();
@@ -27,7 +24,17 @@ pub fn get_week_of_month_for_date(date: chrono::NaiveDate) -> u32 {
     (days_since_first_monday / 7) + 1
 }
 
-fn get_day_suffix(day: u32) -> &'static str {
+// Get every day of the month of a date, i.e. 31 days for 2026-08-12
+pub fn get_days_of_month(date: chrono::NaiveDate) -> Vec<chrono::NaiveDate> {
+    let first_of_month = chrono::NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap();
+
+    first_of_month
+        .iter_days()
+        .take_while(|day| day.month() == date.month())
+        .collect()
+}
+
+pub fn get_day_suffix(day: u32) -> &'static str {
     match day {
         11..=13 => "th",
         _ => match day % 10 {
@@ -39,57 +46,27 @@ fn get_day_suffix(day: u32) -> &'static str {
     }
 }
 
-pub fn get_date_as_text_en(datetime: DateTime<Local>) -> String {
-    let weekday = datetime.format("%A").to_string(); // e.g., "Thursday"
-    let day = datetime.day(); // e.g., 7
-    let month = datetime.format("%B").to_string(); // e.g., "August"
-    let year = datetime.year(); // e.g., 2025
+pub fn get_date_as_text(datetime: impl Datelike, locale: &Locale) -> String {
+    let weekday = locale.weekdays[datetime.weekday().num_days_from_monday() as usize]; // e.g., "Thursday"
+    let day = (locale.ordinal)(datetime.day()); // e.g., "7th"
+    let month = locale.months[(datetime.month() - 1) as usize]; // e.g., "August"
+    let year = datetime.year().to_string(); // e.g., "2025"
 
-    let suffix = get_day_suffix(day); // e.g., "th"
-    let formatted_date = format!("{}, {} {}{}, {}", weekday, month, day, suffix, year);
-    let date_line = format!("# {}\n", formatted_date);
-
-    date_line
-}
+    let formatted_date = locale
+        .date_header
+        .replace("{weekday}", weekday)
+        .replace("{day}", &day)
+        .replace("{month}", month)
+        .replace("{year}", &year);
 
-pub fn get_date_as_text_fr(datetime: DateTime<Local>) -> String {
-    let weekdays = [
-        "Dimanche", "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi",
-    ];
-    let months = [
-        "janvier",
-        "février",
-        "mars",
-        "avril",
-        "mai",
-        "juin",
-        "juillet",
-        "août",
-        "septembre",
-        "octobre",
-        "novembre",
-        "décembre",
-    ];
-
-    let weekday = weekdays[datetime.weekday().num_days_from_sunday() as usize];
-    let day = datetime.day();
-    let month = months[(datetime.month() - 1) as usize];
-    let year = datetime.year();
-
-    let formatted_date = format!("{} {} {} {}", weekday, day, month, year);
-    let date_line = format!("# {}\n", formatted_date);
-
-    date_line
+    format!("# {}\n", formatted_date)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::NaiveDate;
-
-    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
-        NaiveDate::from_ymd_opt(y, m, d).unwrap()
-    }
+    use crate::locales::get::find_locale;
+    use crate::test_helpers::date;
 
     // July 2026: the 1st is a Wednesday. Weeks start on Monday, so the first
     // (partial) week is Wed 1 -> Sun 5, and week 2 starts Mon 6.
@@ -163,13 +140,13 @@ mod tests {
         assert_eq!(get_day_suffix(13), "th");
     }
 
-    // --- get_date_as_text_en ---
+    // --- get_date_as_text (en) ---
 
     #[test]
     fn date_as_text_en_formats_full_line() {
         // Friday July 31st, 2026.
         assert_eq!(
-            get_date_as_text_en(datetime(2026, 7, 31)),
+            get_date_as_text(datetime(2026, 7, 31), find_locale("en")),
             "# Friday, July 31st, 2026\n"
         );
     }
@@ -178,22 +155,22 @@ mod tests {
     fn date_as_text_en_uses_correct_ordinal_suffix() {
         // 1st (not 1th) and 13th (teen exception).
         assert_eq!(
-            get_date_as_text_en(datetime(2026, 7, 1)),
+            get_date_as_text(datetime(2026, 7, 1), find_locale("en")),
             "# Wednesday, July 1st, 2026\n"
         );
         assert_eq!(
-            get_date_as_text_en(datetime(2026, 7, 13)),
+            get_date_as_text(datetime(2026, 7, 13), find_locale("en")),
             "# Monday, July 13th, 2026\n"
         );
     }
 
-    // --- get_date_as_text_fr ---
+    // --- get_date_as_text (fr) ---
 
     #[test]
     fn date_as_text_fr_formats_full_line() {
         // Vendredi 31 juillet 2026 (no ordinal suffix in French).
         assert_eq!(
-            get_date_as_text_fr(datetime(2026, 7, 31)),
+            get_date_as_text(datetime(2026, 7, 31), find_locale("fr")),
             "# Vendredi 31 juillet 2026\n"
         );
     }
@@ -202,12 +179,54 @@ mod tests {
     fn date_as_text_fr_uses_french_month_names() {
         // Check an accented month name (août) and January boundary.
         assert_eq!(
-            get_date_as_text_fr(datetime(2026, 8, 1)),
+            get_date_as_text(datetime(2026, 8, 1), find_locale("fr")),
             "# Samedi 1 août 2026\n"
         );
         assert_eq!(
-            get_date_as_text_fr(datetime(2026, 1, 1)),
+            get_date_as_text(datetime(2026, 1, 1), find_locale("fr")),
             "# Jeudi 1 janvier 2026\n"
         );
     }
+
+    // --- get_date_as_text (other locales) ---
+
+    #[test]
+    fn date_as_text_uses_locale_patterns() {
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 31), find_locale("de")),
+            "# Freitag, 31. Juli 2026\n"
+        );
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 31), find_locale("es")),
+            "# Viernes, 31 de julio de 2026\n"
+        );
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 31), find_locale("pt")),
+            "# Sexta-feira, 31 de julho de 2026\n"
+        );
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 31), find_locale("nl")),
+            "# Vrijdag 31 juli 2026\n"
+        );
+    }
+
+    #[test]
+    fn date_as_text_it_uses_ordinal_for_first_day() {
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 1), find_locale("it")),
+            "# Mercoledì 1º luglio 2026\n"
+        );
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 2), find_locale("it")),
+            "# Giovedì 2 luglio 2026\n"
+        );
+    }
+
+    #[test]
+    fn date_as_text_unknown_language_falls_back_to_english() {
+        assert_eq!(
+            get_date_as_text(datetime(2026, 7, 31), find_locale("ja")),
+            "# Friday, July 31st, 2026\n"
+        );
+    }
 }
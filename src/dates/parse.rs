@@ -11,16 +11,12 @@ static ISO_DATE_REGEX: LazyLock<Regex> =
 pub fn parse_iso_date(date_str: &str) -> Result<NaiveDate, String> {
     if !ISO_DATE_REGEX.is_match(date_str) {
         return Err(format!(
-            "🛑 Invalid date format: '{}'. Expected: YYYY-MM-DD",
+            "Invalid date format: '{}'. Expected: YYYY-MM-DD",
             date_str
         ));
     }
-    NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
-        format!(
-            "🛑 Invalid date format: '{}'. Expected: YYYY-MM-DD",
-            date_str
-        )
-    })
+    NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date format: '{}'. Expected: YYYY-MM-DD", date_str))
 }
 
 #[cfg(test)]
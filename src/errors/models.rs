@@ -0,0 +1,199 @@
+use std::{fmt, io};
+
+/**
+ * Every failure of nost, passed up to the binary which prints the message and the hint,
+ * then exits with the code of the kind of error:
+ *
+ * | Code | Error            | Meaning                                                |
+ * |------|------------------|--------------------------------------------------------|
+ * | 1    | Io               | a file or folder cannot be read or written             |
+ * | 2    | Usage            | unknown command, missing or invalid argument           |
+ * | 3    | ConfigNotFound   | no configuration file                                  |
+ * | 4    | InvalidConfig    | the configuration file cannot be parsed or is invalid  |
+ * | 5    | MissingNotesPath | no notes folder configured                             |
+ * | 6    | CorruptJournal   | a JSON file of `.nost/` (journal, project, manifest) is invalid |
+ * | 7    | Conflict         | the action would overwrite or lose existing data       |
+ * | 8    | ExternalPlugin   | a `nost-<name>` executable failed                      |
+ * | 9    | HookFailed       | a `pre_` hook of the configuration cancelled the action |
+ */
+#[derive(Debug)]
+pub enum NostError {
+    Io { context: String, source: io::Error },
+    Usage(String),
+    ConfigNotFound(String),
+    InvalidConfig(String),
+    MissingNotesPath,
+    CorruptJournal { path: String, reason: String },
+    Conflict(String),
+    // code is None when the plugin was killed by a signal
+    ExternalPlugin { name: String, code: Option<i32> },
+    // i.e. hook "pre_work_start", code is None when the hook was killed by a signal
+    HookFailed { hook: String, code: Option<i32> },
+}
+
+impl NostError {
+    // Wrap an I/O error with what nost was doing, i.e. "Cannot create folder /notes/2026"
+    pub fn io(context: impl Into<String>, source: io::Error) -> Self {
+        NostError::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            NostError::Io { .. } => 1,
+            NostError::Usage(_) => 2,
+            NostError::ConfigNotFound(_) => 3,
+            NostError::InvalidConfig(_) => 4,
+            NostError::MissingNotesPath => 5,
+            NostError::CorruptJournal { .. } => 6,
+            NostError::Conflict(_) => 7,
+            NostError::ExternalPlugin { .. } => 8,
+            NostError::HookFailed { .. } => 9,
+        }
+    }
+
+    // What the user can do to fix the error
+    pub fn hint(&self) -> String {
+        match self {
+            NostError::Io { .. } => {
+                "Check that the notes folder exists and that you can write to it.".to_string()
+            }
+            NostError::Usage(_) => {
+                "See the README for the list of commands and their arguments.".to_string()
+            }
+            NostError::ConfigNotFound(_) => {
+                "Copy config.toml.dist to ~/.config/nost/config.toml, or pass --config <path>."
+                    .to_string()
+            }
+            NostError::InvalidConfig(_) => {
+                "Fix the configuration file, config.toml.dist lists the expected settings."
+                    .to_string()
+            }
+            NostError::MissingNotesPath => {
+                "Set \"not_path\" in config.toml or the NOT_PATH environment variable.".to_string()
+            }
+            NostError::CorruptJournal { path, .. } => format!(
+                "Fix the JSON of {} by hand, or move it away to start a new one.",
+                path
+            ),
+            NostError::Conflict(_) => {
+                "Move or rename the conflicting files, then run the command again.".to_string()
+            }
+            NostError::ExternalPlugin { name, .. } => format!(
+                "The error comes from the nost-{} executable, see its output above.",
+                name
+            ),
+            NostError::HookFailed { hook, .. } => format!(
+                "Nothing was recorded. Fix or remove \"{}\" in the [hooks] section of the configuration file.",
+                hook
+            ),
+        }
+    }
+}
+
+impl fmt::Display for NostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NostError::Io { context, source } if context.is_empty() => write!(f, "{}", source),
+            NostError::Io { context, source } => write!(f, "{}: {}", context, source),
+            NostError::Usage(message)
+            | NostError::ConfigNotFound(message)
+            | NostError::InvalidConfig(message)
+            | NostError::Conflict(message) => write!(f, "{}", message),
+            NostError::MissingNotesPath => write!(f, "No notes folder configured"),
+            NostError::CorruptJournal { path, reason } => {
+                write!(f, "Invalid JSON in {}: {}", path, reason)
+            }
+            NostError::ExternalPlugin {
+                name,
+                code: Some(code),
+            } => {
+                write!(f, "Plugin nost-{} failed with exit code {}", name, code)
+            }
+            NostError::ExternalPlugin { name, code: None } => {
+                write!(f, "Plugin nost-{} was interrupted", name)
+            }
+            NostError::HookFailed {
+                hook,
+                code: Some(code),
+            } => write!(f, "Hook {} failed with exit code {}", hook, code),
+            NostError::HookFailed { hook, code: None } => {
+                write!(f, "Hook {} was interrupted", hook)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NostError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NostError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NostError {
+    fn from(error: io::Error) -> Self {
+        NostError::io("", error)
+    }
+}
+
+pub type NostResult<T> = Result<T, NostError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_kind_has_its_own_exit_code() {
+        let errors = [
+            NostError::io("", io::Error::other("disk full")),
+            NostError::Usage(String::new()),
+            NostError::ConfigNotFound(String::new()),
+            NostError::InvalidConfig(String::new()),
+            NostError::MissingNotesPath,
+            NostError::CorruptJournal {
+                path: String::new(),
+                reason: String::new(),
+            },
+            NostError::Conflict(String::new()),
+            NostError::ExternalPlugin {
+                name: String::new(),
+                code: Some(1),
+            },
+            NostError::HookFailed {
+                hook: String::new(),
+                code: Some(1),
+            },
+        ];
+
+        let codes: Vec<i32> = errors.iter().map(NostError::exit_code).collect();
+        assert_eq!(codes, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn io_error_message_includes_context() {
+        let error = NostError::io(
+            "Cannot create folder /notes/2026",
+            io::Error::other("permission denied"),
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "Cannot create folder /notes/2026: permission denied"
+        );
+    }
+
+    #[test]
+    fn corrupt_journal_hint_names_the_file() {
+        let error = NostError::CorruptJournal {
+            path: "/notes/.nost/journal.json".to_string(),
+            reason: "expected value at line 1".to_string(),
+        };
+
+        assert!(error.hint().contains("/notes/.nost/journal.json"));
+    }
+}
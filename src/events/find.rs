@@ -1,26 +1,53 @@
-use std::{fs::read_to_string, path::Path};
+use std::{fs::File, io::BufReader, path::Path};
 
 use crate::{
+    errors::models::{NostError, NostResult},
     events::models::{Event, EventName},
     projects::initialize::get_project_config_path,
 };
 
-/// Returns the most recent START_WORK or STOP_WORK event from journal.json,
-/// or None if the journal does not exist or contains no work events.
-pub fn find_last_work_event() -> Option<Event> {
-    let config_path = get_project_config_path();
-    let journal_file_path = format!("{}/journal.json", config_path);
+// Get the journal of a configuration folder, i.e. "<not_path>/.nost/journal.json"
+pub fn get_journal_file_path(config_path: &str) -> String {
+    Path::new(config_path)
+        .join("journal.json")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns every event of the journal of the given configuration folder,
+/// or an empty list if the journal does not exist yet.
+pub fn find_all_events(config_path: &str) -> NostResult<Vec<Event>> {
+    let journal_file_path = get_journal_file_path(config_path);
 
+    // if there is no journal file yet, return an empty vec
     if !Path::new(&journal_file_path).exists() {
-        return None;
+        return Ok(Vec::new());
     }
 
-    let content = read_to_string(&journal_file_path).ok()?;
-    let events: Vec<Event> = serde_json::from_str(&content).ok()?;
+    let file = File::open(&journal_file_path)
+        .map_err(|e| NostError::io(format!("Cannot read {}", journal_file_path), e))?;
+    let reader = BufReader::new(file);
+    let events: Vec<Event> =
+        serde_json::from_reader(reader).map_err(|e| NostError::CorruptJournal {
+            path: journal_file_path.clone(),
+            reason: e.to_string(),
+        })?;
 
-    events.into_iter().rev().find(|e| {
+    Ok(events)
+}
+
+/// Returns the most recent START_WORK or STOP_WORK event from journal.json,
+/// or None if the journal does not exist or contains no work events.
+pub fn find_last_work_event() -> NostResult<Option<Event>> {
+    find_last_work_event_in(&get_project_config_path()?)
+}
+
+pub fn find_last_work_event_in(config_path: &str) -> NostResult<Option<Event>> {
+    let events = find_all_events(config_path)?;
+
+    Ok(events.into_iter().rev().find(|e| {
         e.event == EventName::StartWork.to_string() || e.event == EventName::StopWork.to_string()
-    })
+    }))
 }
 
 #[cfg(test)]
@@ -47,7 +74,7 @@ mod tests {
         // Point NOT_PATH at an empty temp dir — no journal.json exists yet.
         env::set_var("NOT_PATH", dir.path().to_str().unwrap());
 
-        let result = find_last_work_event();
+        let result = find_last_work_event().unwrap();
         assert!(result.is_none(), "Expected None when no journal exists");
     }
 
@@ -58,7 +85,7 @@ mod tests {
         env::set_var("NOT_PATH", dir.path().to_str().unwrap());
         write_journal(dir.path().to_str().unwrap(), &[]);
 
-        let result = find_last_work_event();
+        let result = find_last_work_event().unwrap();
         assert!(result.is_none(), "Expected None for an empty journal");
     }
 
@@ -70,7 +97,7 @@ mod tests {
         let events = vec![Event::now(EventName::StartWork, "work".to_string())];
         write_journal(dir.path().to_str().unwrap(), &events);
 
-        let result = find_last_work_event();
+        let result = find_last_work_event().unwrap();
         assert!(result.is_some());
         assert_eq!(
             result.unwrap().event,
@@ -90,7 +117,7 @@ mod tests {
         ];
         write_journal(dir.path().to_str().unwrap(), &events);
 
-        let result = find_last_work_event();
+        let result = find_last_work_event().unwrap();
         assert!(result.is_some());
         assert_eq!(
             result.unwrap().event,
@@ -111,7 +138,7 @@ mod tests {
         ];
         write_journal(dir.path().to_str().unwrap(), &events);
 
-        let result = find_last_work_event();
+        let result = find_last_work_event().unwrap();
         assert!(result.is_some());
         assert_eq!(
             result.unwrap().event,
@@ -135,7 +162,7 @@ mod tests {
         ];
         write_journal(dir.path().to_str().unwrap(), &events);
 
-        let result = find_last_work_event();
+        let result = find_last_work_event().unwrap();
         assert!(result.is_some());
         assert_eq!(
             result.unwrap().event,
@@ -143,4 +170,19 @@ mod tests {
             "Should return the last (most recent) work event"
         );
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_find_last_work_event_corrupt_journal() {
+        let dir = tempdir().unwrap();
+        env::set_var("NOT_PATH", dir.path().to_str().unwrap());
+        fs::create_dir_all(dir.path().join(".nost")).unwrap();
+        fs::write(dir.path().join(".nost/journal.json"), "[{").unwrap();
+
+        let result = find_last_work_event();
+        assert!(
+            matches!(result, Err(NostError::CorruptJournal { .. })),
+            "A corrupt journal must not be mistaken for an empty one"
+        );
+    }
 }
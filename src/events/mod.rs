@@ -1,3 +1,4 @@
 pub mod find;
 pub mod models;
 pub mod record;
+pub mod state;
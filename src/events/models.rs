@@ -1,4 +1,6 @@
-use chrono::Local;
+use chrono::{DateTime, FixedOffset, Local, NaiveDate};
+
+use crate::plugins::registry::is_plugin_event;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use uuid::Uuid;
@@ -8,8 +10,20 @@ pub enum EventName {
     StartWork,
     StopWork,
     CreateNot,
+    // leave, recorded for the day it applies to
+    DayOff,
+    Holiday,
+    Sick,
+    HalfDay,
+    // an event declared by a plugin, i.e. "FOCUS_START"
+    Plugin(String),
 }
 
+// Marker of the STOP_WORK events recorded by `nost work close-stale` instead of the user
+pub const AUTO_CLOSED: &str = "AUTO_CLOSED";
+// Marker of the STOP_WORK events of a paused session, resumed by the next START_WORK
+pub const PAUSED: &str = "PAUSED";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
     pub datetime: String,
@@ -17,6 +31,67 @@ pub struct Event {
     pub day: String,
     pub not_type: String,
     pub uid: String,
+    // i.e. AUTO_CLOSED or PAUSED, absent from most events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+}
+
+/**
+ * What the journal tells at the moment, kept in `.nost/state.json` so that frequent commands
+ * like `nost prompt` do not read the whole journal.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionState {
+    // datetime of the START_WORK event of the running session, None when no session is running
+    pub session_start: Option<String>,
+    pub last_event: Option<String>,
+    pub last_event_datetime: Option<String>,
+    // the journal the state was computed from, the state is rebuilt when it changes
+    #[serde(default)]
+    pub journal: Option<JournalStamp>,
+}
+
+/// The length and the modification time of a journal file, to tell when it was changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalStamp {
+    pub length: u64,
+    pub modified_nanos: u128,
+}
+
+impl SessionState {
+    // The state after an event, which only changes the session for START_WORK and STOP_WORK
+    pub fn apply(&mut self, event: &Event) {
+        if event.event == EventName::StartWork.to_string() {
+            self.session_start = Some(event.datetime.clone());
+        } else if event.event == EventName::StopWork.to_string() {
+            self.session_start = None;
+        }
+        self.last_event = Some(event.event.clone());
+        self.last_event_datetime = Some(event.datetime.clone());
+    }
+}
+
+impl EventName {
+    /// The part of a day off given by a leave event, i.e. 0.5 for HALF_DAY, None for other events.
+    pub fn leave_fraction(&self) -> Option<f32> {
+        match self {
+            EventName::DayOff | EventName::Holiday | EventName::Sick => Some(1.0),
+            EventName::HalfDay => Some(0.5),
+            _ => None,
+        }
+    }
+
+    /// The leave event of a kind given on the command line or in the configuration,
+    /// i.e. "holiday" or "half-day".
+    pub fn from_leave_kind(kind: &str) -> Option<EventName> {
+        match kind.to_lowercase().replace('-', "_").as_str() {
+            "day_off" => Some(EventName::DayOff),
+            "holiday" => Some(EventName::Holiday),
+            "sick" => Some(EventName::Sick),
+            "half_day" => Some(EventName::HalfDay),
+            _ => None,
+        }
+    }
 }
 
 impl Event {
@@ -28,6 +103,24 @@ impl Event {
             day: now.format("%Y-%m-%d").to_string(),
             not_type,
             uid: Uuid::new_v4().to_string(),
+            marker: None,
+        }
+    }
+
+    // An event recorded at another time than now, i.e. the end of a forgotten session
+    pub fn at(event_name: EventName, not_type: String, datetime: DateTime<FixedOffset>) -> Self {
+        Self {
+            datetime: datetime.to_rfc3339(),
+            day: datetime.format("%Y-%m-%d").to_string(),
+            ..Self::now(event_name, not_type)
+        }
+    }
+
+    // An event about another day than today, i.e. a holiday recorded in advance
+    pub fn for_day(event_name: EventName, not_type: String, day: NaiveDate) -> Self {
+        Self {
+            day: day.format("%Y-%m-%d").to_string(),
+            ..Self::now(event_name, not_type)
         }
     }
 }
@@ -38,6 +131,11 @@ impl fmt::Display for EventName {
             EventName::StartWork => write!(f, "START_WORK"),
             EventName::StopWork => write!(f, "STOP_WORK"),
             EventName::CreateNot => write!(f, "CREATE_NOT"),
+            EventName::DayOff => write!(f, "DAY_OFF"),
+            EventName::Holiday => write!(f, "HOLIDAY"),
+            EventName::Sick => write!(f, "SICK"),
+            EventName::HalfDay => write!(f, "HALF_DAY"),
+            EventName::Plugin(name) => write!(f, "{}", name),
         }
     }
 }
@@ -50,6 +148,11 @@ impl std::str::FromStr for EventName {
             "START_WORK" => Ok(EventName::StartWork),
             "STOP_WORK" => Ok(EventName::StopWork),
             "CREATE_NOT" => Ok(EventName::CreateNot),
+            "DAY_OFF" => Ok(EventName::DayOff),
+            "HOLIDAY" => Ok(EventName::Holiday),
+            "SICK" => Ok(EventName::Sick),
+            "HALF_DAY" => Ok(EventName::HalfDay),
+            name if is_plugin_event(name) => Ok(EventName::Plugin(name.to_string())),
             _ => Err(()),
         }
     }
@@ -1,86 +1,123 @@
 use std::{
-    fs::{create_dir_all, read_to_string, write, File},
-    io::Error,
+    fs::{create_dir_all, read_to_string, write},
     path::Path,
 };
 
 use log::debug;
 
-use crate::{events::models::Event, projects::initialize::get_project_config_path};
+use crate::{
+    configurations::get::get_hooks,
+    errors::models::{NostError, NostResult},
+    events::{
+        find::get_journal_file_path,
+        models::Event,
+        state::{get_journal_stamp, update_state_in},
+    },
+    hooks::{
+        models::{HookStage, Hooks},
+        run::run_hook,
+    },
+    projects::initialize::get_project_config_path,
+};
+
+pub fn record_event(event: Event) -> NostResult<String> {
+    record_event_in(&get_project_config_path()?, event, &get_hooks())
+}
+
+// Append an event to the journal of the given configuration folder, running its hooks
+pub fn record_event_in(config_path: &str, event: Event, hooks: &Hooks) -> NostResult<String> {
+    record_event_after(config_path, event, hooks, || Ok(()))?;
+
+    Ok("Record has been added.".to_string())
+}
+
+/**
+ * Run the pre_ hook of an event, then the action, then append the event to the journal and
+ * run the post_ hook. A failing pre_ hook cancels the action, a failing post_ hook is only
+ * reported since the action is already done.
+ * i.e. a note file is written by the action of a CREATE_NOT event
+ */
+pub fn record_event_after<T>(
+    config_path: &str,
+    event: Event,
+    hooks: &Hooks,
+    action: impl FnOnce() -> NostResult<T>,
+) -> NostResult<T> {
+    // the notes folder, parent of "<not_path>/.nost/"
+    let not_path = Path::new(config_path)
+        .parent()
+        .unwrap_or(Path::new(config_path));
+
+    run_hook(hooks, HookStage::Pre, &event, not_path)?;
+    let result = action()?;
+    let journal_before = get_journal_stamp(config_path);
+    append_event_in(config_path, &event)?;
+    update_state_in(config_path, &event, journal_before)?;
+
+    if let Err(e) = run_hook(hooks, HookStage::Post, &event, not_path) {
+        eprintln!("⚠️  {}", e);
+    }
+
+    Ok(result)
+}
 
-pub fn record_event(event: Event) -> std::io::Result<String> {
-    let config_path = get_project_config_path();
+// Append an event to the journal of the given configuration folder, without hooks
+fn append_event_in(config_path: &str, event: &Event) -> NostResult<()> {
     debug!("Project config path: {:?}", config_path);
 
     // create journal folder if not exists
-    if let Err(e) = create_dir_all(&config_path) {
-        return Err(Error::other(format!(
-            "🛑 Failed to create directory: {}",
-            e
-        )));
-    }
+    create_dir_all(config_path)
+        .map_err(|e| NostError::io(format!("Failed to create directory {}", config_path), e))?;
 
-    // create journal file if not exists
-    let journal_file_path = format!("{}/journal.json", config_path);
+    // create journal file if not exists, initialized with an empty array
+    let journal_file_path = get_journal_file_path(config_path);
     if !Path::new(&journal_file_path).exists() {
-        if let Err(e) = File::create(&journal_file_path) {
-            return Err(Error::other(format!(
-                "🛑 Failed to create journal file: {}",
-                e
-            )));
-        }
-
-        // initialize the journal file with an empty array
-        if let Err(e) = write(&journal_file_path, "[]") {
-            return Err(Error::other(format!(
-                "🛑 Failed to initialize journal file: {}",
-                e
-            )));
-        }
+        write(&journal_file_path, "[]").map_err(|e| {
+            NostError::io(
+                format!("Failed to create journal file {}", journal_file_path),
+                e,
+            )
+        })?;
     }
 
     // create the record
-    let record = serde_json::to_value(&event)
-        .map_err(|e| Error::other(format!("🛑 Failed to serialize event record: {}", e)))?;
+    let record = serde_json::to_value(event)
+        .map_err(|e| NostError::io("Failed to serialize event record", std::io::Error::other(e)))?;
 
     // append the record in the array (at the end)
     let journal_content = read_to_string(&journal_file_path).map_err(|e| {
-        Error::other(format!(
-            "🛑 Failed to read journal file '{}': {}",
-            journal_file_path, e
-        ))
+        NostError::io(
+            format!("Failed to read journal file {}", journal_file_path),
+            e,
+        )
     })?;
 
     let mut journal_json: serde_json::Value =
-        serde_json::from_str(&journal_content).map_err(|e| {
-            Error::other(format!(
-                "🛑 Invalid JSON in journal file '{}': {}",
-                journal_file_path, e
-            ))
+        serde_json::from_str(&journal_content).map_err(|e| NostError::CorruptJournal {
+            path: journal_file_path.clone(),
+            reason: e.to_string(),
         })?;
 
-    let journal_array = journal_json.as_array_mut().ok_or_else(|| {
-        Error::other(format!(
-            "🛑 Journal file '{}' must contain a JSON array",
-            journal_file_path
-        ))
-    })?;
+    let journal_array = journal_json
+        .as_array_mut()
+        .ok_or_else(|| NostError::CorruptJournal {
+            path: journal_file_path.clone(),
+            reason: "the journal must contain a JSON array".to_string(),
+        })?;
 
     journal_array.push(record);
 
     let updated_content = serde_json::to_string_pretty(&journal_json).map_err(|e| {
-        Error::other(format!(
-            "🛑 Failed to serialize updated journal '{}': {}",
-            journal_file_path, e
-        ))
+        NostError::io(
+            format!("Failed to serialize updated journal {}", journal_file_path),
+            std::io::Error::other(e),
+        )
     })?;
 
     write(&journal_file_path, format!("{}\n", updated_content)).map_err(|e| {
-        Error::other(format!(
-            "🛑 Failed to write updated journal '{}': {}",
-            journal_file_path, e
-        ))
-    })?;
-
-    Ok("Record has been added.".to_string())
+        NostError::io(
+            format!("Failed to write updated journal {}", journal_file_path),
+            e,
+        )
+    })
 }
@@ -0,0 +1,163 @@
+use std::{
+    fs::{metadata, read_to_string, write},
+    path::Path,
+    time::UNIX_EPOCH,
+};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    events::{
+        find::{find_all_events, get_journal_file_path},
+        models::{Event, JournalStamp, SessionState},
+    },
+};
+
+// Get the state file of a configuration folder, i.e. "<not_path>/.nost/state.json"
+pub fn get_state_file_path(config_path: &str) -> String {
+    Path::new(config_path)
+        .join("state.json")
+        .to_string_lossy()
+        .into_owned()
+}
+
+// Get the length and the modification time of the journal, None when it does not exist
+pub fn get_journal_stamp(config_path: &str) -> Option<JournalStamp> {
+    let metadata = metadata(get_journal_file_path(config_path)).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+    Some(JournalStamp {
+        length: metadata.len(),
+        modified_nanos: modified.as_nanos(),
+    })
+}
+
+fn read_cached_state_in(config_path: &str) -> Option<SessionState> {
+    let content = read_to_string(get_state_file_path(config_path)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_state_in(config_path: &str, state: &SessionState) -> NostResult<()> {
+    let state_file_path = get_state_file_path(config_path);
+    let content = serde_json::to_string_pretty(state)
+        .map_err(|e| NostError::io("Failed to serialize the state", e.into()))?;
+
+    write(&state_file_path, format!("{}\n", content))
+        .map_err(|e| NostError::io(format!("Cannot write {}", state_file_path), e))
+}
+
+// Rebuild the state from the whole journal, and write it when the configuration folder exists
+fn build_state_in(config_path: &str) -> NostResult<SessionState> {
+    let mut state = SessionState {
+        journal: get_journal_stamp(config_path),
+        ..SessionState::default()
+    };
+    for event in find_all_events(config_path)? {
+        state.apply(&event);
+    }
+
+    if Path::new(config_path).is_dir() {
+        write_state_in(config_path, &state)?;
+    }
+    Ok(state)
+}
+
+/**
+ * Read the state of a configuration folder. The state file is only a cache of the journal:
+ * when it is missing, unreadable, or computed from another version of the journal, i.e. one
+ * edited by hand or synced from another computer, it is rebuilt from the journal.
+ */
+pub fn read_state_in(config_path: &str) -> NostResult<SessionState> {
+    match read_cached_state_in(config_path) {
+        Some(state) if state.journal == get_journal_stamp(config_path) => Ok(state),
+        _ => build_state_in(config_path),
+    }
+}
+
+/**
+ * Update the state with an event just appended to the journal. `journal_before` is the stamp
+ * of the journal before the event: when the state was not computed from it, the state is
+ * rebuilt.
+ */
+pub fn update_state_in(
+    config_path: &str,
+    event: &Event,
+    journal_before: Option<JournalStamp>,
+) -> NostResult<()> {
+    match read_cached_state_in(config_path) {
+        Some(mut state) if state.journal == journal_before => {
+            state.apply(event);
+            state.journal = get_journal_stamp(config_path);
+            write_state_in(config_path, &state)
+        }
+        _ => build_state_in(config_path).map(|_| ()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        events::{models::EventName, record::record_event_in},
+        hooks::models::Hooks,
+    };
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn state_follows_the_recorded_events() {
+        let dir = tempdir().unwrap();
+        let config_path = format!("{}/.nost/", dir.path().display());
+
+        let start = Event::now(EventName::StartWork, "work".to_string());
+        record_event_in(&config_path, start.clone(), &Hooks::default()).unwrap();
+        let state = read_state_in(&config_path).unwrap();
+        assert_eq!(state.session_start, Some(start.datetime));
+
+        record_event_in(
+            &config_path,
+            Event::now(EventName::StopWork, "work".to_string()),
+            &Hooks::default(),
+        )
+        .unwrap();
+        let state = read_state_in(&config_path).unwrap();
+        assert_eq!(state.session_start, None);
+        assert_eq!(state.last_event.as_deref(), Some("STOP_WORK"));
+    }
+
+    #[test]
+    fn missing_state_is_rebuilt_from_the_journal() {
+        let dir = tempdir().unwrap();
+        let config_path = format!("{}/.nost/", dir.path().display());
+        let start = Event::now(EventName::StartWork, "work".to_string());
+        record_event_in(&config_path, start.clone(), &Hooks::default()).unwrap();
+
+        // i.e. a journal written by an older version of nost
+        fs::remove_file(get_state_file_path(&config_path)).unwrap();
+
+        let state = read_state_in(&config_path).unwrap();
+        assert_eq!(state.session_start, Some(start.datetime));
+        assert!(Path::new(&get_state_file_path(&config_path)).is_file());
+    }
+
+    #[test]
+    fn state_is_rebuilt_when_the_journal_is_changed_elsewhere() {
+        let dir = tempdir().unwrap();
+        let config_path = format!("{}/.nost/", dir.path().display());
+        let start = Event::now(EventName::StartWork, "work".to_string());
+        record_event_in(&config_path, start.clone(), &Hooks::default()).unwrap();
+        assert!(read_state_in(&config_path).unwrap().session_start.is_some());
+
+        // i.e. the session was stopped on another computer and the journal synced
+        let stop = Event::now(EventName::StopWork, "work".to_string());
+        fs::write(
+            get_journal_file_path(&config_path),
+            serde_json::to_string_pretty(&[start, stop]).unwrap(),
+        )
+        .unwrap();
+
+        let state = read_state_in(&config_path).unwrap();
+        assert_eq!(state.session_start, None);
+        assert_eq!(state.last_event.as_deref(), Some("STOP_WORK"));
+        assert_eq!(state.journal, get_journal_stamp(&config_path));
+    }
+}
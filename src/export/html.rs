@@ -0,0 +1,596 @@
+use chrono::{Datelike, NaiveDate};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{create_dir_all, read_to_string, write},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{
+    dates::get::{get_date_as_text, get_week_of_month_for_date},
+    errors::models::{NostError, NostResult},
+    export::render::{escape_html, render_markdown, slugify, strip_metadata},
+    files::{find::find_all_markdown_files, models::DatedNote},
+    links::{
+        graph::{build_day_index, resolve_target},
+        models::display_note,
+    },
+    locales::{get::fill, models::Locale},
+    plugins::{models::StatsContext, registry::PLUGINS},
+    vaults::vault::Vault,
+};
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:50rem;margin:2rem auto;padding:0 1rem;line-height:1.5;color:#222}
+nav.breadcrumb{font-size:.9rem;margin-bottom:1rem}
+nav.pager{display:flex;justify-content:space-between;margin:2rem 0;font-size:.9rem}
+a{color:#0645ad}
+a.wiki{text-decoration:none;border-bottom:1px dotted}
+.broken{color:#b00;border-bottom:1px dashed}
+article{border-top:1px solid #ddd;padding-top:1rem;margin-top:1rem}
+.note-type{font-size:.8rem;text-transform:uppercase;color:#777;margin:0}
+.types{color:#777;font-size:.9rem}
+pre{background:#f5f5f5;padding:.5rem;overflow:auto}
+code{background:#f5f5f5}
+table{border-collapse:collapse;margin:1rem 0}
+th,td{border:1px solid #ccc;padding:.2rem .6rem}
+blockquote{border-left:3px solid #ccc;margin-left:0;padding-left:1rem;color:#555}";
+
+// i.e. "2026-08-05.html"
+fn day_page(date: NaiveDate) -> String {
+    format!("{}.html", date.format("%Y-%m-%d"))
+}
+
+// i.e. "2026-08-w2.html", with the week of the month of the default layout
+fn week_page(date: NaiveDate) -> String {
+    format!(
+        "{}-w{}.html",
+        date.format("%Y-%m"),
+        get_week_of_month_for_date(date)
+    )
+}
+
+// i.e. "2026-08.html"
+fn month_page(date: NaiveDate) -> String {
+    format!("{}.html", date.format("%Y-%m"))
+}
+
+fn year_page(year: i32) -> String {
+    format!("{}.html", year)
+}
+
+// i.e. "note-topics-acme.html" for "topics/acme.md"
+fn note_page(note: &Path) -> String {
+    format!("note-{}.html", slugify(&note_title(note)))
+}
+
+// i.e. "topics/acme" for "topics/acme.md"
+fn note_title(note: &Path) -> String {
+    let path = display_note(note);
+    path.strip_suffix(".md").unwrap_or(&path).to_string()
+}
+
+// i.e. "Wednesday, August 5th, 2026"
+fn day_title(date: NaiveDate, locale: &Locale) -> String {
+    get_date_as_text(date, locale)
+        .trim_start_matches('#')
+        .trim()
+        .to_string()
+}
+
+// i.e. "August 2026"
+fn month_title(date: NaiveDate, locale: &Locale) -> String {
+    format!("{} {}", locale.months[date.month0() as usize], date.year())
+}
+
+// i.e. "Week 2"
+fn week_label(week: u32, locale: &Locale) -> String {
+    fill(locale.messages.week_title, &[&week.to_string()])
+}
+
+// i.e. "Week 2, August 2026"
+fn week_title(date: NaiveDate, locale: &Locale) -> String {
+    format!(
+        "{}, {}",
+        week_label(get_week_of_month_for_date(date), locale),
+        month_title(date, locale)
+    )
+}
+
+fn link(href: &str, text: &str) -> String {
+    format!(
+        "<a href=\"{}\">{}</a>",
+        escape_html(href),
+        escape_html(text)
+    )
+}
+
+// The links to the previous and the next pages of the same level
+fn pager<T: PartialEq>(keys: &[T], current: &T, page: impl Fn(&T) -> (String, String)) -> String {
+    let Some(position) = keys.iter().position(|key| key == current) else {
+        return String::new();
+    };
+    let previous = position
+        .checked_sub(1)
+        .and_then(|index| keys.get(index))
+        .map(|key| {
+            let (href, title) = page(key);
+            link(&href, &format!("← {}", title))
+        })
+        .unwrap_or_default();
+    let next = keys
+        .get(position + 1)
+        .map(|key| {
+            let (href, title) = page(key);
+            link(&href, &format!("{} →", title))
+        })
+        .unwrap_or_default();
+
+    format!(
+        "<nav class=\"pager\"><span>{}</span><span>{}</span></nav>\n",
+        previous, next
+    )
+}
+
+// A whole page, with its styles inlined so that it needs no other file
+fn page(title: &str, breadcrumb: &[(String, String)], body: &str) -> String {
+    let mut crumbs: Vec<String> = breadcrumb
+        .iter()
+        .map(|(href, text)| link(href, text))
+        .collect();
+    crumbs.push(escape_html(title));
+
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">
+<title>{}</title>
+<style>{}</style>
+</head>
+<body>
+<nav class=\"breadcrumb\">{}</nav>
+<h1>{}</h1>
+{}</body>
+</html>
+",
+        escape_html(title),
+        STYLE,
+        crumbs.join(" › "),
+        escape_html(title),
+        body
+    )
+}
+
+// Resolve "." and ".." in a relative path, i.e. "2026/08/2/5/../4/04.md" gives "2026/08/2/4/04.md"
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+// The types of the notes of a day, i.e. "default, work"
+fn note_types(notes: &[&DatedNote]) -> String {
+    notes
+        .iter()
+        .map(|note| note.note_type.as_deref().unwrap_or("note"))
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+// The stats sections of the plugins for a month, rendered as HTML
+fn month_stats(vault: &Vault, month: NaiveDate) -> NostResult<String> {
+    let month = month.format("%Y-%m").to_string();
+    let context = StatsContext {
+        vaults: std::slice::from_ref(vault),
+        month: Some(&month),
+        locale: vault.locale(),
+    };
+
+    let mut html = String::new();
+    for plugin in PLUGINS.iter() {
+        if let Some(section) = plugin.stats(&context)? {
+            html.push_str(&render_markdown(&section, &|_| None));
+        }
+    }
+
+    Ok(html)
+}
+
+/**
+ * Write the notes of a vault as a static HTML site in `out_dir`: an index of the years, then a
+ * page per year, month, week and day, and a page per other note, i.e. "topics/acme.md". Wiki
+ * links and links to other notes point to the page of the note, the page of its day for a day
+ * note. Annotations and front matter are left out, and each month page shows the stats of the
+ * month. Styles are inlined, the site needs no other file.
+ * Returns the number of pages written.
+ */
+pub fn export_html(vault: &Vault, out_dir: &Path) -> NostResult<usize> {
+    let locale = vault.locale();
+    let notes = vault.dated_notes()?;
+
+    let mut days: BTreeMap<NaiveDate, Vec<&DatedNote>> = BTreeMap::new();
+    for note in &notes {
+        days.entry(note.date).or_default().push(note);
+    }
+    let day_keys: Vec<NaiveDate> = days.keys().copied().collect();
+    let mut week_keys: Vec<(i32, u32, u32)> = day_keys
+        .iter()
+        .map(|date| (date.year(), date.month(), get_week_of_month_for_date(*date)))
+        .collect();
+    week_keys.dedup();
+    let mut month_keys: Vec<NaiveDate> = day_keys
+        .iter()
+        .filter_map(|date| date.with_day(1))
+        .collect();
+    month_keys.dedup();
+    let mut year_keys: Vec<i32> = day_keys.iter().map(|date| date.year()).collect();
+    year_keys.dedup();
+
+    // links are resolved to the page of the note they point to, the page of its day for a day
+    // note
+    let relative = |path: &Path| {
+        path.strip_prefix(vault.path())
+            .unwrap_or(path)
+            .to_path_buf()
+    };
+    let markdown_files: Vec<PathBuf> = find_all_markdown_files(vault.path().to_path_buf())
+        .map_err(|e| {
+            NostError::io(
+                format!("Cannot read the notes in {}", vault.path().display()),
+                e,
+            )
+        })?
+        .iter()
+        .map(|path| relative(path))
+        .collect();
+    let mut pages_of_notes: HashMap<PathBuf, String> = notes
+        .iter()
+        .map(|note| (relative(&note.path), day_page(note.date)))
+        .collect();
+    let other_notes: Vec<PathBuf> = markdown_files
+        .iter()
+        .filter(|path| !pages_of_notes.contains_key(*path))
+        .cloned()
+        .collect();
+    for note in &other_notes {
+        pages_of_notes.insert(note.clone(), note_page(note));
+    }
+    let day_index = build_day_index(vault.path(), &notes);
+    // `folder` is the folder of the note the link is written in, relative to the vault
+    let resolve_in = |folder: &Path, target: &str| -> Option<String> {
+        if target.ends_with(".md") {
+            return pages_of_notes
+                .get(&normalize(&folder.join(target)))
+                .cloned();
+        }
+        resolve_target(&day_index, &markdown_files, target)
+            .iter()
+            .find_map(|path| pages_of_notes.get(path))
+            .cloned()
+    };
+
+    create_dir_all(out_dir)
+        .map_err(|e| NostError::io(format!("Cannot create {}", out_dir.display()), e))?;
+    let mut pages = 0;
+    let mut write_page = |name: &str, content: String| -> NostResult<()> {
+        let path = out_dir.join(name);
+        pages += 1;
+        write(&path, content)
+            .map_err(|e| NostError::io(format!("Cannot write {}", path.display()), e))
+    };
+    let week_of = |date: NaiveDate| (date.year(), date.month(), get_week_of_month_for_date(date));
+    let first_day_of_week = |key: &(i32, u32, u32)| {
+        day_keys
+            .iter()
+            .copied()
+            .find(|date| week_of(*date) == *key)
+            .unwrap_or_default()
+    };
+
+    // days
+    for (date, day_notes) in &days {
+        let mut body = String::new();
+        for note in day_notes {
+            let content = read_to_string(&note.path)
+                .map_err(|e| NostError::io(format!("Cannot read {}", note.path.display()), e))?;
+            let folder = relative(&note.path)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+
+            let resolve = |target: &str| resolve_in(&folder, target);
+
+            body.push_str(&format!(
+                "<article>\n<p class=\"note-type\">{}</p>\n{}</article>\n",
+                escape_html(note.note_type.as_deref().unwrap_or("note")),
+                render_markdown(&strip_metadata(&content), &resolve)
+            ));
+        }
+        body.push_str(&pager(&day_keys, date, |date| {
+            (day_page(*date), day_title(*date, locale))
+        }));
+
+        write_page(
+            &day_page(*date),
+            page(
+                &day_title(*date, locale),
+                &[
+                    (
+                        "index.html".to_string(),
+                        locale.messages.notes_title.to_string(),
+                    ),
+                    (year_page(date.year()), date.year().to_string()),
+                    (month_page(*date), month_title(*date, locale)),
+                    (week_page(*date), week_label(week_of(*date).2, locale)),
+                ],
+                &body,
+            ),
+        )?;
+    }
+
+    // weeks
+    for key in &week_keys {
+        let first_day = first_day_of_week(key);
+        let mut body = String::from("<ul>\n");
+        for (date, day_notes) in days.iter().filter(|(date, _)| week_of(**date) == *key) {
+            body.push_str(&format!(
+                "<li>{} <span class=\"types\">{}</span></li>\n",
+                link(&day_page(*date), &day_title(*date, locale)),
+                escape_html(&note_types(day_notes))
+            ));
+        }
+        body.push_str("</ul>\n");
+        body.push_str(&pager(&week_keys, key, |key| {
+            let date = first_day_of_week(key);
+            (week_page(date), week_title(date, locale))
+        }));
+
+        write_page(
+            &week_page(first_day),
+            page(
+                &week_title(first_day, locale),
+                &[
+                    (
+                        "index.html".to_string(),
+                        locale.messages.notes_title.to_string(),
+                    ),
+                    (year_page(first_day.year()), first_day.year().to_string()),
+                    (month_page(first_day), month_title(first_day, locale)),
+                ],
+                &body,
+            ),
+        )?;
+    }
+
+    // months, with their stats
+    for month in &month_keys {
+        let mut body = String::from("<ul>\n");
+        for key in week_keys.iter().filter(|(year, month_number, _)| {
+            *year == month.year() && *month_number == month.month()
+        }) {
+            let first_day = first_day_of_week(key);
+            let count = days.keys().filter(|date| week_of(**date) == *key).count();
+            body.push_str(&format!(
+                "<li>{} <span class=\"types\">{}</span></li>\n",
+                link(&week_page(first_day), &week_label(key.2, locale)),
+                fill(locale.messages.days_count, &[&count.to_string()])
+            ));
+        }
+        body.push_str(&format!(
+            "</ul>\n<section class=\"stats\">\n<h2>{}</h2>\n",
+            locale.messages.stats_title
+        ));
+        body.push_str(&month_stats(vault, *month)?);
+        body.push_str("</section>\n");
+        body.push_str(&pager(&month_keys, month, |month| {
+            (month_page(*month), month_title(*month, locale))
+        }));
+
+        write_page(
+            &month_page(*month),
+            page(
+                &month_title(*month, locale),
+                &[
+                    (
+                        "index.html".to_string(),
+                        locale.messages.notes_title.to_string(),
+                    ),
+                    (year_page(month.year()), month.year().to_string()),
+                ],
+                &body,
+            ),
+        )?;
+    }
+
+    // years
+    for year in &year_keys {
+        let mut body = String::from("<ul>\n");
+        for month in month_keys.iter().filter(|month| month.year() == *year) {
+            let count = days
+                .keys()
+                .filter(|date| date.year() == *year && date.month() == month.month())
+                .count();
+            body.push_str(&format!(
+                "<li>{} <span class=\"types\">{}</span></li>\n",
+                link(&month_page(*month), &month_title(*month, locale)),
+                fill(locale.messages.days_count, &[&count.to_string()])
+            ));
+        }
+        body.push_str("</ul>\n");
+        body.push_str(&pager(&year_keys, year, |year| {
+            (year_page(*year), year.to_string())
+        }));
+
+        write_page(
+            &year_page(*year),
+            page(
+                &year.to_string(),
+                &[(
+                    "index.html".to_string(),
+                    locale.messages.notes_title.to_string(),
+                )],
+                &body,
+            ),
+        )?;
+    }
+
+    // other notes, i.e. "topics/acme.md"
+    for note in &other_notes {
+        let path = vault.path().join(note);
+        let content = read_to_string(&path)
+            .map_err(|e| NostError::io(format!("Cannot read {}", path.display()), e))?;
+        let folder = note.parent().map(Path::to_path_buf).unwrap_or_default();
+        let resolve = |target: &str| resolve_in(&folder, target);
+
+        write_page(
+            &note_page(note),
+            page(
+                &note_title(note),
+                &[(
+                    "index.html".to_string(),
+                    locale.messages.notes_title.to_string(),
+                )],
+                &render_markdown(&strip_metadata(&content), &resolve),
+            ),
+        )?;
+    }
+
+    // index
+    let mut body = String::from("<ul>\n");
+    for year in year_keys.iter().rev() {
+        let count = notes
+            .iter()
+            .filter(|note| note.date.year() == *year)
+            .count();
+        body.push_str(&format!(
+            "<li>{} <span class=\"types\">{}</span></li>\n",
+            link(&year_page(*year), &year.to_string()),
+            fill(locale.messages.notes_count, &[&count.to_string()])
+        ));
+    }
+    body.push_str("</ul>\n");
+    if !other_notes.is_empty() {
+        body.push_str("<ul>\n");
+        for note in &other_notes {
+            body.push_str(&format!(
+                "<li>{}</li>\n",
+                link(&note_page(note), &note_title(note))
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+    write_page(
+        "index.html",
+        page(
+            vault.name().unwrap_or(locale.messages.notes_title),
+            &[],
+            &body,
+        ),
+    )?;
+
+    Ok(pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::date;
+    use std::fs::{read_dir, write};
+    use tempfile::tempdir;
+
+    #[test]
+    fn site_has_a_page_per_year_month_week_and_day() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path().join("notes"));
+        let tuesday = vault.create_note(date(2026, 8, 4), "work").unwrap().path;
+        let wednesday = vault.create_note(date(2026, 8, 5), "default").unwrap().path;
+        vault.create_note(date(2026, 9, 1), "work").unwrap();
+        write(
+            &tuesday,
+            "# Tuesday\n\nSee [[2026-08-05|tomorrow]], [[acme]] and [[missing]].\n\n[//]: # \"not:{v:2,date:'2026-08-04T09:00:00+02:00',event:'START_WORK',uid:'d6330352-e55c-4944-9496-0f819a826905'}\"\n",
+        )
+        .unwrap();
+        write(
+            &wednesday,
+            "# Wednesday\n\nBack to [monday](../4/04.work.md).\n",
+        )
+        .unwrap();
+        std::fs::create_dir(vault.path().join("topics")).unwrap();
+        write(
+            vault.path().join("topics/acme.md"),
+            "# Acme\n\nMet on [[2026-08-04]].\n",
+        )
+        .unwrap();
+
+        let out_dir = dir.path().join("site");
+        let pages = export_html(&vault, &out_dir).unwrap();
+
+        let mut names: Vec<String> = read_dir(&out_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "2026-08-04.html",
+                "2026-08-05.html",
+                "2026-08-w2.html",
+                "2026-08.html",
+                "2026-09-01.html",
+                "2026-09-w1.html",
+                "2026-09.html",
+                "2026.html",
+                "index.html",
+                "note-topics-acme.html"
+            ]
+        );
+        assert_eq!(pages, names.len());
+
+        let tuesday = read_to_string(out_dir.join("2026-08-04.html")).unwrap();
+        assert!(tuesday.contains("<a class=\"wiki\" href=\"2026-08-05.html\">tomorrow</a>"));
+        assert!(tuesday.contains("<a class=\"wiki\" href=\"note-topics-acme.html\">acme</a>"));
+        assert!(tuesday.contains("<span class=\"broken\" title=\"missing\">missing</span>"));
+        assert!(!tuesday.contains("START_WORK"));
+        assert!(tuesday.contains("<a href=\"2026-08-05.html\">Wednesday, August 5th, 2026 →</a>"));
+        assert!(!tuesday.contains("<link") && !tuesday.contains("<script"));
+
+        let wednesday = read_to_string(out_dir.join("2026-08-05.html")).unwrap();
+        assert!(wednesday.contains("<a href=\"2026-08-04.html\">monday</a>"));
+
+        let month = read_to_string(out_dir.join("2026-08.html")).unwrap();
+        assert!(month.contains("<a href=\"2026-08-w2.html\">Week 2</a>"));
+        assert!(month.contains("<section class=\"stats\">"));
+
+        let acme = read_to_string(out_dir.join("note-topics-acme.html")).unwrap();
+        assert!(acme.contains("<a class=\"wiki\" href=\"2026-08-04.html\">2026-08-04</a>"));
+        let index = read_to_string(out_dir.join("index.html")).unwrap();
+        assert!(index.contains("<a href=\"note-topics-acme.html\">topics/acme</a>"));
+    }
+
+    #[test]
+    fn labels_follow_the_language_of_the_vault() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path().join("notes")).with_language("fr");
+        vault.create_note(date(2026, 8, 4), "work").unwrap();
+
+        let out_dir = dir.path().join("site");
+        export_html(&vault, &out_dir).unwrap();
+
+        let month = read_to_string(out_dir.join("2026-08.html")).unwrap();
+        assert!(month.contains("<a href=\"index.html\">Notes</a>"));
+        assert!(month.contains("<a href=\"2026-08-w2.html\">Semaine 2</a>"));
+        assert!(month.contains("<span class=\"types\">1 jour(s)</span>"));
+        assert!(month.contains("<h2>Statistiques</h2>"));
+        let week = read_to_string(out_dir.join("2026-08-w2.html")).unwrap();
+        assert!(week.contains("<title>Semaine 2, août 2026</title>"));
+    }
+}
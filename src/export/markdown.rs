@@ -0,0 +1,256 @@
+use chrono::NaiveDate;
+use std::fs::read_to_string;
+
+use crate::{
+    annotations::front_matter::split_front_matter,
+    configurations::models::MetadataStyle,
+    dates::get::get_date_as_text,
+    errors::models::{NostError, NostResult},
+    export::render::strip_metadata,
+    files::convert_metadata::convert_note_metadata,
+    vaults::vault::Vault,
+};
+
+const FENCE: &str = "```";
+const PRIVATE_TAG: &str = "#private";
+
+/// What `nost export md` writes.
+#[derive(Debug, Clone)]
+pub struct MarkdownExport {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    // only the notes of this type, i.e. "work"
+    pub note_type: Option<String>,
+    pub strip_annotations: bool,
+    pub strip_private: bool,
+}
+
+// The level of a heading line, i.e. 2 for "## Contacts"
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    ((1..=6).contains(&level) && line[level..].starts_with(' ')).then_some(level)
+}
+
+// Apply a function to the lines outside fenced code
+fn map_lines_outside_code(content: &str, mut map: impl FnMut(&str) -> Option<String>) -> String {
+    let mut in_fence = false;
+    let mut lines = Vec::new();
+    for line in content.lines() {
+        if line.trim_start().starts_with(FENCE) {
+            in_fence = !in_fence;
+            lines.push(line.to_string());
+        } else if in_fence {
+            lines.push(line.to_string());
+        } else if let Some(line) = map(line) {
+            lines.push(line);
+        }
+    }
+
+    let mut mapped = lines.join("\n");
+    mapped.push('\n');
+    mapped
+}
+
+/**
+ * Remove the private sections of a note: a heading titled "Private" or tagged `#private`,
+ * with its content up to the next heading of the same or a higher level. Annotation lines are
+ * kept, they are the data of the note and often end it, under its last section.
+ * i.e. "## Salary #private" is removed, with its lines and its subsections
+ */
+pub fn strip_private_sections(content: &str) -> String {
+    let mut private_level: Option<usize> = None;
+
+    map_lines_outside_code(content, |line| {
+        if let Some(level) = heading_level(line) {
+            if private_level.is_some_and(|private| level <= private) {
+                private_level = None;
+            }
+            let title = line[level..].trim().to_lowercase();
+            if private_level.is_none()
+                && (title == "private" || title.split_whitespace().any(|word| word == PRIVATE_TAG))
+            {
+                private_level = Some(level);
+            }
+        }
+
+        (private_level.is_none() || line.starts_with("[//]: #")).then(|| line.to_string())
+    })
+}
+
+// Move the headings down, i.e. "# Title" becomes "### Title" with 2 levels, up to level 6
+fn demote_headings(content: &str, levels: usize) -> String {
+    map_lines_outside_code(content, |line| match heading_level(line) {
+        Some(level) => Some(format!(
+            "{}{}",
+            "#".repeat((level + levels).min(6)),
+            &line[level..]
+        )),
+        None => Some(line.to_string()),
+    })
+}
+
+// Prepare a note to be part of the document, under the heading of its day
+fn prepare_note(content: &str, day_title: &str, export: &MarkdownExport) -> String {
+    let content = if export.strip_annotations {
+        strip_metadata(content)
+    } else {
+        // front matter is only valid at the top of a file, nost metadata is kept as annotations
+        let converted = convert_note_metadata(content, MetadataStyle::Annotations)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| content.to_string());
+        split_front_matter(&converted).1.to_string()
+    };
+    let content = if export.strip_private {
+        strip_private_sections(&content)
+    } else {
+        content
+    };
+
+    // the date header of the note is replaced by the heading of the day,
+    // it can follow the annotations of the legacy notes
+    let mut header_found = false;
+    let content = map_lines_outside_code(&content, |line| {
+        if !header_found && line.trim() == day_title.trim() {
+            header_found = true;
+            return None;
+        }
+        Some(line.to_string())
+    });
+
+    demote_headings(content.trim(), 2).trim().to_string()
+}
+
+/**
+ * Concatenate the notes of a period in a single markdown document, by date, under a heading
+ * per day and a heading per note type when a day has several notes.
+ */
+pub fn export_markdown(vault: &Vault, export: &MarkdownExport) -> NostResult<String> {
+    let locale = vault.locale();
+    let notes: Vec<_> = vault
+        .dated_notes()?
+        .into_iter()
+        .filter(|note| note.date >= export.from && note.date <= export.to)
+        .filter(|note| match &export.note_type {
+            Some(note_type) => note.note_type.as_deref() == Some(note_type.as_str()),
+            None => true,
+        })
+        .collect();
+
+    let mut document = format!(
+        "# {} – {}\n",
+        export.from.format("%Y-%m-%d"),
+        export.to.format("%Y-%m-%d")
+    );
+
+    for (index, note) in notes.iter().enumerate() {
+        let day_title = get_date_as_text(note.date, locale);
+        let is_first_of_day = index == 0 || notes[index - 1].date != note.date;
+        let day_has_several_notes =
+            notes.iter().filter(|other| other.date == note.date).count() > 1;
+
+        if is_first_of_day {
+            document.push_str(&format!("\n#{}", day_title));
+        }
+        if day_has_several_notes {
+            document.push_str(&format!(
+                "\n### {}\n",
+                note.note_type.as_deref().unwrap_or("note")
+            ));
+        }
+
+        let content = read_to_string(&note.path)
+            .map_err(|e| NostError::io(format!("Cannot read {}", note.path.display()), e))?;
+        let content = prepare_note(&content, &day_title, export);
+        if !content.is_empty() {
+            document.push_str(&format!("\n{}\n", content));
+        }
+    }
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::date;
+    use std::fs::write;
+    use tempfile::tempdir;
+
+    #[test]
+    fn private_sections_are_removed_up_to_the_next_heading() {
+        let content = "# Day
+Public.
+## Salary #private
+Secret.
+### Details
+More secret.
+## Tasks
+- one
+```
+# Private
+kept in code
+```
+";
+
+        assert_eq!(
+            strip_private_sections(content),
+            "# Day\nPublic.\n## Tasks\n- one\n```\n# Private\nkept in code\n```\n"
+        );
+    }
+
+    #[test]
+    fn notes_of_the_period_are_concatenated_by_date() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path());
+        let work = vault.create_note(date(2026, 8, 5), "work").unwrap().path;
+        vault.create_note(date(2026, 8, 5), "default").unwrap();
+        let monday = vault.create_note(date(2026, 8, 3), "work").unwrap().path;
+        vault.create_note(date(2026, 8, 10), "work").unwrap();
+        write(
+            &work,
+            "# Wednesday, August 5th, 2026\n\n## Review\nDone.\n\n## Private\nSecret.\n\n[//]: # \"not:{v:2,date:'2026-08-05T09:00:00+02:00',event:'START_WORK',uid:'d6330352-e55c-4944-9496-0f819a826905'}\"\n",
+        )
+        .unwrap();
+        write(&monday, "# Monday, August 3rd, 2026\n\nKickoff.\n").unwrap();
+
+        let mut export = MarkdownExport {
+            from: date(2026, 8, 1),
+            to: date(2026, 8, 7),
+            note_type: Some("work".to_string()),
+            strip_annotations: true,
+            strip_private: true,
+        };
+        let document = export_markdown(&vault, &export).unwrap();
+
+        assert_eq!(
+            document,
+            "# 2026-08-01 – 2026-08-07
+
+## Monday, August 3rd, 2026
+
+Kickoff.
+
+## Wednesday, August 5th, 2026
+
+#### Review
+Done.
+"
+        );
+
+        export.note_type = None;
+        export.strip_annotations = false;
+        export.strip_private = false;
+        let document = export_markdown(&vault, &export).unwrap();
+        assert!(document.contains("\n### default\n"));
+        assert!(document.contains(
+            "\n### work\n\n#### Review\nDone.\n\n#### Private\nSecret.\n\n[//]: # \"not:"
+        ));
+        assert!(!document.contains("2026-08-10"));
+
+        export.strip_private = true;
+        let document = export_markdown(&vault, &export).unwrap();
+        assert!(!document.contains("Secret."));
+        assert!(document.contains("\n#### Review\nDone.\n\n[//]: # \"not:"));
+    }
+}
@@ -0,0 +1,476 @@
+/*!
+ * A small markdown to HTML renderer, enough for notes: headings, paragraphs, lists, task
+ * lists, quotes, code, tables, rules, emphasis, links and wiki links. Raw HTML is escaped.
+ */
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+use crate::{annotations::front_matter::split_front_matter, links::parse::parse_wiki_link};
+
+static ORDERED_ITEM_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d+[.)]\s+(.*)$").unwrap());
+static TABLE_SEPARATOR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?$").unwrap());
+
+/// Gives the address of a link target, i.e. "2026-08-05.html" for `[[2026-08-05]]`, None
+/// when the target is not exported. Markdown links to `.md` files are given as written.
+pub type LinkResolver<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The id of a heading, i.e. "next-steps" for "Next steps!"
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.trim().to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+/**
+ * Remove the metadata of a note: its front matter and its annotation lines, like any
+ * `[//]: # "..."` comment.
+ */
+pub fn strip_metadata(content: &str) -> String {
+    let (_, body) = split_front_matter(content);
+    let lines: Vec<&str> = body
+        .lines()
+        .filter(|line| !line.starts_with("[//]: #"))
+        .collect();
+
+    format!("{}\n", lines.join("\n").trim())
+}
+
+fn render_wiki_link(inner: &str, resolve: LinkResolver) -> String {
+    let Some(link) = parse_wiki_link(inner, 0) else {
+        return escape_html(&format!("[[{}]]", inner));
+    };
+    let text = link.alias.clone().unwrap_or_else(|| match &link.heading {
+        Some(heading) => format!("{} › {}", link.target, heading),
+        None => link.target.clone(),
+    });
+
+    match resolve(&link.target) {
+        Some(mut href) => {
+            if let Some(heading) = &link.heading {
+                href.push_str(&format!("#{}", slugify(heading)));
+            }
+            format!(
+                "<a class=\"wiki\" href=\"{}\">{}</a>",
+                escape_html(&href),
+                escape_html(&text)
+            )
+        }
+        None => format!(
+            "<span class=\"broken\" title=\"{}\">{}</span>",
+            escape_html(&link.target),
+            escape_html(&text)
+        ),
+    }
+}
+
+// Only web and mail addresses and relative paths are linked, i.e. not "javascript:alert(1)".
+// Browsers ignore the whitespace and control characters of a scheme, so they are too.
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_lowercase();
+    let scheme_end = url.find([':', '/', '?', '#']);
+    match scheme_end {
+        Some(end) if url[end..].starts_with(':') => {
+            matches!(&url[..end], "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+// Render `[text](url)` and `![alt](url)`, internal `.md` links are resolved and links with
+// another scheme than http, https or mailto are rendered as text
+fn render_link(text: &str, url: &str, is_image: bool, resolve: LinkResolver) -> String {
+    // images are linked, not loaded: the site needs no other file
+    let content = if is_image {
+        escape_html(if text.is_empty() { url } else { text })
+    } else {
+        render_inline(text, resolve)
+    };
+    if !is_safe_url(url) {
+        return content;
+    }
+
+    let (path, anchor) = match url.split_once('#') {
+        Some((path, anchor)) => (path, Some(anchor)),
+        None => (url, None),
+    };
+    let is_internal = !url.contains("://") && path.ends_with(".md");
+
+    let href = if is_internal {
+        resolve(path).map(|href| match anchor {
+            Some(anchor) => format!("{}#{}", href, anchor),
+            None => href,
+        })
+    } else {
+        Some(url.to_string())
+    };
+
+    match href {
+        Some(href) => format!("<a href=\"{}\">{}</a>", escape_html(&href), content),
+        None => format!(
+            "<span class=\"broken\" title=\"{}\">{}</span>",
+            escape_html(url),
+            content
+        ),
+    }
+}
+
+/**
+ * Render the inline markdown of a line: code, wiki links, links, bold, italic and
+ * strikethrough.
+ */
+pub fn render_inline(text: &str, resolve: LinkResolver) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                html.push_str(&format!("<code>{}</code>", escape_html(&after[..end])));
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix("[[") {
+            if let Some(end) = after.find("]]") {
+                html.push_str(&render_wiki_link(&after[..end], resolve));
+                rest = &after[end + 2..];
+                continue;
+            }
+        }
+        let (is_image, link) = match rest.strip_prefix("![") {
+            Some(after) => (true, Some(after)),
+            None => (false, rest.strip_prefix('[')),
+        };
+        if let Some(after) = link {
+            if let Some((label, rest_after)) = after.split_once("](") {
+                if let Some(end) = rest_after.find(')') {
+                    if !label.contains(']') {
+                        html.push_str(&render_link(label, &rest_after[..end], is_image, resolve));
+                        rest = &rest_after[end + 1..];
+                        continue;
+                    }
+                }
+            }
+        }
+        let mut emphasis = false;
+        for (marker, tag) in [("**", "strong"), ("~~", "del"), ("*", "em")] {
+            if let Some(after) = rest.strip_prefix(marker) {
+                if let Some(end) = after.find(marker).filter(|end| *end > 0) {
+                    html.push_str(&format!(
+                        "<{}>{}</{}>",
+                        tag,
+                        render_inline(&after[..end], resolve),
+                        tag
+                    ));
+                    rest = &after[end + marker.len()..];
+                    emphasis = true;
+                    break;
+                }
+            }
+        }
+        if emphasis {
+            continue;
+        }
+
+        html.push_str(&escape_html(&c.to_string()));
+        rest = &rest[c.len_utf8()..];
+    }
+
+    html
+}
+
+fn split_table_row(line: &str) -> Vec<&str> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+
+    line.split('|').map(str::trim).collect()
+}
+
+enum Block {
+    Paragraph(Vec<String>),
+    List { ordered: bool, items: Vec<String> },
+    Quote(Vec<String>),
+}
+
+fn close_block(html: &mut String, block: Option<Block>, resolve: LinkResolver) {
+    match block {
+        Some(Block::Paragraph(lines)) => {
+            html.push_str(&format!(
+                "<p>{}</p>\n",
+                render_inline(&lines.join(" "), resolve)
+            ));
+        }
+        Some(Block::List { ordered, items }) => {
+            let tag = if ordered { "ol" } else { "ul" };
+            html.push_str(&format!("<{}>\n", tag));
+            for item in items {
+                let item = match (item.strip_prefix("[ ] "), item.strip_prefix("[x] ")) {
+                    (Some(task), _) => format!(
+                        "<input type=\"checkbox\" disabled> {}",
+                        render_inline(task, resolve)
+                    ),
+                    (_, Some(task)) => format!(
+                        "<input type=\"checkbox\" checked disabled> {}",
+                        render_inline(task, resolve)
+                    ),
+                    _ => render_inline(&item, resolve),
+                };
+                html.push_str(&format!("<li>{}</li>\n", item));
+            }
+            html.push_str(&format!("</{}>\n", tag));
+        }
+        Some(Block::Quote(lines)) => {
+            html.push_str(&format!(
+                "<blockquote>\n{}</blockquote>\n",
+                render_markdown(&lines.join("\n"), resolve)
+            ));
+        }
+        None => {}
+    }
+}
+
+/**
+ * Render a note body to HTML. Headings get an id made from their text, so that
+ * `[[2026-08-05#Next steps]]` can point to them.
+ */
+pub fn render_markdown(content: &str, resolve: LinkResolver) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut html = String::new();
+    let mut block: Option<Block> = None;
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+        index += 1;
+
+        // fenced code
+        if trimmed.starts_with("```") {
+            close_block(&mut html, block.take(), resolve);
+            let mut code = Vec::new();
+            while index < lines.len() && !lines[index].trim().starts_with("```") {
+                code.push(lines[index]);
+                index += 1;
+            }
+            index += 1;
+            html.push_str(&format!(
+                "<pre><code>{}</code></pre>\n",
+                escape_html(&code.join("\n"))
+            ));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            close_block(&mut html, block.take(), resolve);
+            continue;
+        }
+
+        // headings
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            close_block(&mut html, block.take(), resolve);
+            let text = trimmed[level..].trim();
+            html.push_str(&format!(
+                "<h{} id=\"{}\">{}</h{}>\n",
+                level,
+                slugify(text),
+                render_inline(text, resolve),
+                level
+            ));
+            continue;
+        }
+
+        // rules
+        if ["---", "***", "___"].contains(&trimmed.replace(' ', "").as_str()) {
+            close_block(&mut html, block.take(), resolve);
+            html.push_str("<hr>\n");
+            continue;
+        }
+
+        // tables, with a header when the first row is followed by a separator row, rows
+        // without header are kept too, i.e. the summary of the stats
+        if trimmed.starts_with('|') && trimmed.ends_with('|') && trimmed.len() > 1 {
+            close_block(&mut html, block.take(), resolve);
+            html.push_str("<table>\n");
+            if index < lines.len() && TABLE_SEPARATOR_REGEX.is_match(lines[index].trim()) {
+                html.push_str("<thead><tr>");
+                for cell in split_table_row(trimmed) {
+                    html.push_str(&format!("<th>{}</th>", render_inline(cell, resolve)));
+                }
+                html.push_str("</tr></thead>\n");
+                index += 1;
+            } else {
+                index -= 1;
+            }
+            html.push_str("<tbody>\n");
+            while index < lines.len() && lines[index].trim().starts_with('|') {
+                html.push_str("<tr>");
+                for cell in split_table_row(lines[index]) {
+                    html.push_str(&format!("<td>{}</td>", render_inline(cell, resolve)));
+                }
+                html.push_str("</tr>\n");
+                index += 1;
+            }
+            html.push_str("</tbody>\n</table>\n");
+            continue;
+        }
+
+        // quotes
+        if let Some(quoted) = trimmed.strip_prefix('>') {
+            if !matches!(block, Some(Block::Quote(_))) {
+                close_block(&mut html, block.take(), resolve);
+                block = Some(Block::Quote(Vec::new()));
+            }
+            if let Some(Block::Quote(quote_lines)) = &mut block {
+                quote_lines.push(quoted.trim_start().to_string());
+            }
+            continue;
+        }
+
+        // lists, nested items are flattened
+        let unordered = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker));
+        let ordered = ORDERED_ITEM_REGEX
+            .captures(trimmed)
+            .and_then(|caps| caps.get(1))
+            .map(|item| item.as_str());
+        if let Some((is_ordered, item)) = unordered
+            .map(|item| (false, item))
+            .or(ordered.map(|item| (true, item)))
+        {
+            if !matches!(&block, Some(Block::List { ordered, .. }) if *ordered == is_ordered) {
+                close_block(&mut html, block.take(), resolve);
+                block = Some(Block::List {
+                    ordered: is_ordered,
+                    items: Vec::new(),
+                });
+            }
+            if let Some(Block::List { items, .. }) = &mut block {
+                items.push(item.to_string());
+            }
+            continue;
+        }
+
+        // a line continues the current list item or paragraph
+        match &mut block {
+            Some(Block::List { items, .. }) if line.starts_with([' ', '\t']) => {
+                if let Some(last) = items.last_mut() {
+                    last.push(' ');
+                    last.push_str(trimmed);
+                }
+            }
+            Some(Block::Paragraph(paragraph)) => paragraph.push(trimmed.to_string()),
+            _ => {
+                close_block(&mut html, block.take(), resolve);
+                block = Some(Block::Paragraph(vec![trimmed.to_string()]));
+            }
+        }
+    }
+    close_block(&mut html, block.take(), resolve);
+
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(target: &str) -> Option<String> {
+        match target {
+            "2026-08-04" => Some("2026-08-04.html".to_string()),
+            "../4/04.work.md" => Some("2026-08-04.html".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn markdown_is_rendered_with_resolved_links() {
+        let content = "# Wednesday
+
+Met **Acme** about <b>[[2026-08-04#Next steps|yesterday]]</b> and [[bob]].
+See [the notes](../4/04.work.md) and `[[code]]`.
+
+- [x] done
+- [ ] todo
+
+| Day | Hours |
+|-----|-------|
+| Wed | 7.50 |
+
+```
+let a = 1 < 2;
+```
+";
+
+        let html = render_markdown(content, &resolve);
+
+        assert!(html.starts_with("<h1 id=\"wednesday\">Wednesday</h1>\n"));
+        assert!(html.contains("<strong>Acme</strong> about &lt;b&gt;<a class=\"wiki\" href=\"2026-08-04.html#next-steps\">yesterday</a>&lt;/b&gt;"));
+        assert!(html.contains("<span class=\"broken\" title=\"bob\">bob</span>"));
+        assert!(
+            html.contains("<a href=\"2026-08-04.html\">the notes</a> and <code>[[code]]</code>")
+        );
+        assert!(html.contains("<li><input type=\"checkbox\" checked disabled> done</li>"));
+        assert!(html.contains("<thead><tr><th>Day</th><th>Hours</th></tr></thead>"));
+        assert!(render_markdown("| Total | 8.00 |\n", &resolve)
+            .contains("<tbody>\n<tr><td>Total</td><td>8.00</td></tr>\n</tbody>"));
+        assert!(html.contains("<tr><td>Wed</td><td>7.50</td></tr>"));
+        assert!(html.contains("<pre><code>let a = 1 &lt; 2;</code></pre>"));
+    }
+
+    #[test]
+    fn only_web_mail_and_relative_links_are_rendered() {
+        let html = render_inline(
+            "[site](https://acme.com) [mail](mailto:bob@acme.com) [doc](files/plan.pdf) \
+             [x](javascript:void) [y](JavaScript:void) [z](data:text/html,hi) \
+             ![img](vbscript:run)",
+            &resolve,
+        );
+
+        assert_eq!(
+            html,
+            "<a href=\"https://acme.com\">site</a> <a href=\"mailto:bob@acme.com\">mail</a> \
+             <a href=\"files/plan.pdf\">doc</a> x y z img"
+        );
+        assert!(!is_safe_url("java\tscript:alert(1)"));
+        assert!(is_safe_url("notes/2026:08.html"));
+    }
+
+    #[test]
+    fn metadata_is_stripped() {
+        let content = "---
+uid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff
+---
+# Wednesday
+
+Notes.
+
+[//]: # \"not:{v:2,date:'2026-08-05T09:00:00+02:00',event:'START_WORK',uid:'d6330352-e55c-4944-9496-0f819a826905'}\"
+";
+
+        assert_eq!(strip_metadata(content), "# Wednesday\n\nNotes.\n");
+    }
+}
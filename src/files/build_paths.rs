@@ -1,12 +1,24 @@
-use chrono::{Datelike, Local};
-
-use crate::dates::get::{get_week_of_month, get_week_of_month_for_date};
+use chrono::Local;
+
+use crate::files::layout::{
+    get_layout, get_legacy_layout, render_layout, render_layout_month_prefix,
+};
+
+// Join a base path and rendered layout folders into a folder path ending with a slash
+fn join_folders(base_path: &str, folders: &str) -> String {
+    if folders.is_empty() {
+        format!("{}/", base_path)
+    } else {
+        format!("{}/{}/", base_path, folders)
+    }
+}
 
+/**
+ * Build the deepest folder containing every note of the month for the configured layout.
+ * i.e. "<base>/2026/07/" for the default layout, or "<base>/" for an ISO week layout.
+ */
 pub fn build_file_path_for_month(base_path: &str, date: chrono::NaiveDate) -> String {
-    let year = date.year();
-    let month = format!("{:02}", date.month());
-
-    format!("{}/{}/{}/", base_path, year, month)
+    join_folders(base_path, &render_layout_month_prefix(&get_layout(), date))
 }
 
 pub fn build_file_path_for_now(base_path: &str) -> String {
@@ -14,30 +26,27 @@ pub fn build_file_path_for_now(base_path: &str) -> String {
     build_file_path_for_date(base_path, today)
 }
 
+/**
+ * Build the folder of legacy notes, see `get_legacy_layout`.
+ * i.e. "<base>/2026/07/5/" for the default layout.
+ */
 pub fn build_file_path_for_date(base_path: &str, date: chrono::NaiveDate) -> String {
-    let year = date.year();
-    let month = format!("{:02}", date.month());
-
-    format!(
-        "{}/{}/{}/{}/",
-        base_path,
-        year,
-        month,
-        get_week_of_month_for_date(date)
-    )
+    let layout = get_layout();
+    join_folders(base_path, &render_layout(&get_legacy_layout(&layout), date))
 }
 
 pub fn build_folder_path_for_now(base_path: &str) -> String {
-    let today = Local::now().date_naive();
-    let year = today.year();
-    let month = format!("{:02}", today.month());
-
-    format!(
-        "{}/{}/{}/{}/{}/",
-        base_path,
-        year,
-        month,
-        get_week_of_month(),
-        today.day()
-    )
+    build_folder_path_for_date(base_path, Local::now().date_naive())
+}
+
+pub fn build_folder_path_for_date(base_path: &str, date: chrono::NaiveDate) -> String {
+    build_folder_path_with_layout(base_path, &get_layout(), date)
+}
+
+pub fn build_folder_path_with_layout(
+    base_path: &str,
+    layout: &str,
+    date: chrono::NaiveDate,
+) -> String {
+    join_folders(base_path, &render_layout(layout, date))
 }
@@ -0,0 +1,255 @@
+use crate::{
+    annotations::{
+        annotate::format_annotation,
+        extract::ANNOTATION_LINE_REGEX,
+        front_matter::{format_front_matter, parse_front_matter, split_front_matter, FrontMatter},
+        models::Annotation,
+        parse::parse_annotation,
+    },
+    configurations::models::MetadataStyle,
+};
+
+// Parse the front matter of a note, an empty one when it has none
+fn read_front_matter(yaml: Option<&str>) -> Result<FrontMatter, String> {
+    match yaml {
+        Some(yaml) => parse_front_matter(yaml),
+        None => Ok(FrontMatter::default()),
+    }
+}
+
+// Move the annotation lines of the note into its front matter, created when missing
+fn to_front_matter(content: &str) -> Result<String, String> {
+    let (yaml, body) = split_front_matter(content);
+    let existing = read_front_matter(yaml)?;
+
+    let mut annotations = existing.to_annotations()?;
+    let mut lines = Vec::new();
+    for line in body.lines() {
+        match ANNOTATION_LINE_REGEX
+            .captures(line)
+            .and_then(|caps| caps.get(1))
+        {
+            Some(annotation) => annotations.push(parse_annotation(annotation.as_str())?),
+            None => lines.push(line),
+        }
+    }
+
+    // the metadata already in the front matter comes first and is kept, the uid and the type
+    // too when it has no creation date to give a CREATE_NOT annotation
+    let mut front_matter = FrontMatter::from_annotations(&annotations);
+    front_matter.uid = existing.uid.clone().or(front_matter.uid);
+    front_matter.note_type = existing.note_type.clone().or(front_matter.note_type);
+    front_matter.raw_lines = existing.raw_lines.clone();
+    let mut tags = existing.tags.clone();
+    tags.extend(
+        front_matter
+            .tags
+            .drain(..)
+            .filter(|tag| !existing.tags.contains(tag)),
+    );
+    front_matter.tags = tags;
+
+    Ok(format!(
+        "{}{}\n",
+        format_front_matter(&front_matter),
+        lines.join("\n").trim_end()
+    ))
+}
+
+// Move the front matter of the note into annotation lines at its end
+fn to_annotations(content: &str) -> Result<String, String> {
+    let (yaml, body) = split_front_matter(content);
+    let front_matter = read_front_matter(yaml)?;
+    let annotations: Vec<Annotation> = front_matter.to_annotations()?;
+
+    // without creation date, the uid, the type and the tags would be lost
+    if front_matter.created_at.is_none()
+        && (front_matter.uid.is_some()
+            || front_matter.note_type.is_some()
+            || !front_matter.tags.is_empty())
+    {
+        return Err(
+            "the front matter has no created_at to keep its uid, type and tags".to_string(),
+        );
+    }
+
+    // the keys of other tools stay in the front matter
+    let mut converted = if front_matter.raw_lines.is_empty() {
+        String::new()
+    } else {
+        format!("---\n{}\n---\n", front_matter.raw_lines.join("\n"))
+    };
+    converted.push_str(body.trim_end());
+    for annotation in &annotations {
+        converted.push_str(&format!(
+            "\n\n[//]: # \"{}\"",
+            format_annotation(annotation)
+        ));
+    }
+    converted.push('\n');
+
+    Ok(converted)
+}
+
+/**
+ * Convert the metadata of a note to another style, see `annotations::front_matter`.
+ * Returns None when there is nothing to convert, i.e. a note already in the style or front
+ * matter without nost metadata.
+ */
+pub fn convert_note_metadata(content: &str, to: MetadataStyle) -> Result<Option<String>, String> {
+    let (yaml, body) = split_front_matter(content);
+
+    let has_metadata = match to {
+        MetadataStyle::FrontMatter => body
+            .lines()
+            .any(|line| ANNOTATION_LINE_REGEX.is_match(line)),
+        MetadataStyle::Annotations => {
+            let front_matter = read_front_matter(yaml)?;
+            front_matter.created_at.is_some() || !front_matter.sessions.is_empty()
+        }
+    };
+    if !has_metadata {
+        return Ok(None);
+    }
+
+    let converted = match to {
+        MetadataStyle::FrontMatter => to_front_matter(content)?,
+        MetadataStyle::Annotations => to_annotations(content)?,
+    };
+
+    Ok(Some(converted).filter(|converted| converted != content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANNOTATED: &str = "# Wednesday 5 August 2026
+
+Meeting with the client.
+
+[//]: # \"not:{v:2,date:'2026-08-05T09:00:00+02:00',event:'CREATE_NOT',uid:'b86bc6ed-50a5-4ef2-bdd3-e17baef11eff',tags:'client, review',type:'work'}\"
+
+[//]: # \"not:{v:2,date:'2026-08-05T09:05:00+02:00',event:'START_WORK',uid:'d6330352-e55c-4944-9496-0f819a826905',workday:'2026-08-05'}\"
+";
+
+    #[test]
+    fn annotations_are_moved_to_front_matter_and_back() {
+        let front_matter = convert_note_metadata(ANNOTATED, MetadataStyle::FrontMatter)
+            .unwrap()
+            .unwrap();
+
+        assert!(front_matter.starts_with("---\nuid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff\n"));
+        assert!(front_matter.contains("type: work\ntags: [client, review]\nsessions:\n"));
+        assert!(front_matter.contains("    workday: 2026-08-05\n"));
+        assert!(
+            front_matter.ends_with("---\n# Wednesday 5 August 2026\n\nMeeting with the client.\n")
+        );
+        // already converted
+        assert_eq!(
+            convert_note_metadata(&front_matter, MetadataStyle::FrontMatter).unwrap(),
+            None
+        );
+
+        let annotated = convert_note_metadata(&front_matter, MetadataStyle::Annotations)
+            .unwrap()
+            .unwrap();
+        assert_eq!(annotated, ANNOTATED);
+    }
+
+    #[test]
+    fn front_matter_without_nost_metadata_is_kept() {
+        let note = "---\ntags: [recipe]\n---\n# Pancakes\n";
+
+        assert_eq!(
+            convert_note_metadata(note, MetadataStyle::Annotations).unwrap(),
+            None
+        );
+
+        // annotations are merged into the existing front matter
+        let note = format!(
+            "{}\n[//]: # \"not:{{v:2,date:'2026-08-05T09:00:00+02:00',event:'CREATE_NOT',uid:'b86bc6ed-50a5-4ef2-bdd3-e17baef11eff',type:'default'}}\"\n",
+            note
+        );
+        let converted = convert_note_metadata(&note, MetadataStyle::FrontMatter)
+            .unwrap()
+            .unwrap();
+        assert!(converted.contains("type: default\ntags: [recipe]\n"));
+        assert!(converted.ends_with("---\n# Pancakes\n"));
+    }
+
+    #[test]
+    fn uid_without_creation_date_is_kept() {
+        let note = "---
+uid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff
+summary: |
+  Kept as written.
+---
+# Wednesday
+
+[//]: # \"not:{v:2,date:'2026-08-05T09:05:00+02:00',event:'START_WORK',uid:'d6330352-e55c-4944-9496-0f819a826905'}\"
+";
+
+        let converted = convert_note_metadata(note, MetadataStyle::FrontMatter)
+            .unwrap()
+            .unwrap();
+
+        let (yaml, body) = split_front_matter(&converted);
+        let front_matter = parse_front_matter(yaml.unwrap()).unwrap();
+        assert_eq!(
+            front_matter.uid.as_deref(),
+            Some("b86bc6ed-50a5-4ef2-bdd3-e17baef11eff")
+        );
+        assert_eq!(front_matter.sessions.len(), 1);
+        assert_eq!(
+            front_matter.raw_lines,
+            vec!["summary: |", "  Kept as written."]
+        );
+        assert_eq!(body, "# Wednesday\n");
+        // written back the same
+        assert_eq!(
+            convert_note_metadata(&converted, MetadataStyle::FrontMatter).unwrap(),
+            None
+        );
+        // annotations cannot keep a uid without creation date
+        assert!(convert_note_metadata(&converted, MetadataStyle::Annotations).is_err());
+    }
+
+    #[test]
+    fn keys_of_other_tools_stay_in_the_front_matter() {
+        let note = "---
+uid: b86bc6ed-50a5-4ef2-bdd3-e17baef11eff
+created_at: 2026-08-05T07:00:00Z
+type: work
+tags: [client]
+sessions: []
+# written by Obsidian
+title: Kickoff
+aliases: [Acme kickoff, kickoff]
+cssclasses:
+  - wide
+---
+# Kickoff
+";
+        let other_keys = "---
+# written by Obsidian
+title: Kickoff
+aliases: [Acme kickoff, kickoff]
+cssclasses:
+  - wide
+---
+";
+
+        let annotated = convert_note_metadata(note, MetadataStyle::Annotations)
+            .unwrap()
+            .unwrap();
+        assert!(annotated.starts_with(&format!("{}# Kickoff\n\n[//]: # ", other_keys)));
+        assert!(!annotated.contains("aliases:'"));
+        assert!(!annotated.contains("title:'"));
+
+        let converted = convert_note_metadata(&annotated, MetadataStyle::FrontMatter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(converted, note);
+    }
+}
@@ -1,31 +1,42 @@
 use std::{
     fs::{create_dir_all, File},
-    io::Error,
     path::Path,
 };
 
-use chrono::{DateTime, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate};
 
 use crate::{
-    annotations::annotate::annotate,
-    configurations::get::get_value_from_config,
-    dates::get::{get_date_as_text_en, get_date_as_text_fr, get_day_as_string},
+    annotations::{
+        annotate::annotate,
+        front_matter::{format_front_matter, FrontMatter},
+        models::AnnotationValue,
+    },
+    configurations::{get::get_not_path, models::MetadataStyle},
+    dates::get::get_date_as_text,
+    errors::models::{NostError, NostResult},
     events::{
         models::{Event, EventName},
-        record::record_event,
+        record::record_event_after,
     },
     files::{
         append::append,
         build_paths::{
-            build_file_path_for_date, build_file_path_for_now, build_folder_path_for_now,
+            build_file_path_for_date, build_file_path_for_now, build_folder_path_with_layout,
         },
+        models::NoteFile,
         name::{name, name_for_date},
     },
+    hooks::models::Hooks,
+    locales::{
+        get::{fill, get_current_locale},
+        models::Locale,
+    },
+    projects::initialize::get_project_config_path_for,
 };
 
-pub fn create_file(date: Option<NaiveDate>) -> std::io::Result<String> {
+pub fn create_file(date: Option<NaiveDate>) -> NostResult<String> {
     // handle paths
-    let not_path = get_value_from_config("not_path").unwrap();
+    let not_path = get_not_path()?;
 
     let (not_file_path, not_file_name) = match date {
         Some(d) => (build_file_path_for_date(&not_path, d), name_for_date(d)),
@@ -33,116 +44,123 @@ pub fn create_file(date: Option<NaiveDate>) -> std::io::Result<String> {
     };
 
     let full_not_file_path = format!("{}{}", not_file_path, not_file_name);
+    let locale = get_current_locale();
 
     // create folders if needed
-    if let Err(e) = create_dir_all(&not_file_path) {
-        return Err(Error::other(format!(
-            "🛑 Failed to create directory: {}",
-            e
-        )));
-    }
+    create_dir_all(&not_file_path)
+        .map_err(|e| NostError::io(format!("Failed to create directory {}", not_file_path), e))?;
 
     // only create the file if it does not exist
     if Path::new(&full_not_file_path).exists() {
-        println!("Not already existed.");
+        println!("{}", locale.messages.note_already_exists);
         return Ok(full_not_file_path);
     }
 
     // create the file
-    match File::create(&full_not_file_path) {
-        Ok(_file) => {
-            println!("✅ File created: {}", full_not_file_path);
-        }
-        Err(e) => {
-            eprintln!("Error creating file: {}", e);
-        }
-    };
+    File::create(&full_not_file_path)
+        .map_err(|e| NostError::io(format!("Failed to create file {}", full_not_file_path), e))?;
+    println!(
+        "{}",
+        fill(locale.messages.file_created, &[&full_not_file_path])
+    );
 
     annotate(
         None,
         EventName::CreateNot,
         None,
         full_not_file_path.as_str(),
-        None,
-    );
+        &[],
+    )?;
 
-    let datetime = match date {
-        Some(naive_date) => naive_date
-            .and_hms_opt(0, 0, 0)
-            .unwrap()
-            .and_local_timezone(Local)
-            .unwrap(),
-        None => Local::now(),
-    };
-
-    let date_line = match get_value_from_config("language").unwrap().as_str() {
-        "fr" => get_date_as_text_fr(datetime),
-        _ => get_date_as_text_en(datetime), // default to English
-    };
+    let date_line = get_date_as_text(date.unwrap_or_else(|| Local::now().date_naive()), locale);
 
     append(full_not_file_path.clone().into(), &date_line)
-        .expect("🛑 Failed to append date as text.");
+        .map_err(|e| NostError::io(format!("Failed to write {}", full_not_file_path), e))?;
 
-    println!("✅ New \"not\" has successfully being initiated.");
+    println!("{}", locale.messages.note_initiated);
 
     Ok(full_not_file_path)
 }
 
-pub fn create_note_file_with_folders(note_type: String) -> std::io::Result<String> {
+/**
+ * Create the note of a date in a notes folder, with its folders and its date header, and
+ * record its creation in the journal. An existing note is left untouched, and a failing
+ * pre_new hook cancels the creation. With a metadata style, the uid, the creation date and
+ * the type of the note are written in it.
+ */
+pub fn create_note_in(
+    not_path: &str,
+    layout: &str,
+    locale: &Locale,
+    hooks: &Hooks,
+    metadata: Option<MetadataStyle>,
+    date: NaiveDate,
+    note_type: &str,
+) -> NostResult<NoteFile> {
     // get the path of the folder to create
-    let not_path = get_value_from_config("not_path").unwrap();
-    let today_folder_path = build_folder_path_for_now(&not_path);
-
-    log::debug!(
-        "🚨 Creating note file with folders at path: {}",
-        today_folder_path
-    );
-
-    let now: DateTime<Local> = Local::now();
-    let today_file_name = get_day_as_string(now);
-    let today_file_path = format!(
-        "{}{}{}{}{}",
-        today_folder_path, today_file_name, ".", note_type, ".md"
-    );
+    let folder_path = build_folder_path_with_layout(not_path, layout, date);
+    let file_path = format!("{}{:02}.{}.md", folder_path, date.day(), note_type);
 
     // only create if not does not already exists
-    if Path::new(&today_file_path).exists() {
-        println!("Not already existed.");
-        return Ok(today_file_path);
-    }
-
-    // create folders if needed
-    if let Err(e) = create_dir_all(&today_folder_path) {
-        return Err(Error::other(format!(
-            "🛑 Failed to create directory: {}",
-            e
-        )));
+    if Path::new(&file_path).exists() {
+        return Ok(NoteFile {
+            path: file_path.into(),
+            created: false,
+        });
     }
 
-    log::debug!(
-        "🚨 Creating note file with folders at path: {}",
-        today_file_path
-    );
-
-    // create the file
-    match File::create(&today_file_path) {
-        Ok(_file) => {
-            record_event(Event::now(EventName::CreateNot, note_type.clone()))?;
-            println!("✅ File created: {}", today_file_path);
-        }
-        Err(e) => {
-            eprintln!("Error creating file: {}", e);
-        }
-    };
-
-    let date_line = match get_value_from_config("language").unwrap().as_str() {
-        "fr" => get_date_as_text_fr(Local::now()),
-        _ => get_date_as_text_en(Local::now()), // default to English
-    };
-
-    append(today_file_path.clone().into(), &date_line).expect("🛑 Failed to append date as text.");
-
-    println!("✅ New \"not\" has successfully being initiated.");
+    let event = Event::now(EventName::CreateNot, note_type.to_string());
+
+    record_event_after(
+        &get_project_config_path_for(not_path),
+        event.clone(),
+        hooks,
+        || {
+            // create folders if needed
+            create_dir_all(&folder_path).map_err(|e| {
+                NostError::io(format!("Failed to create directory {}", folder_path), e)
+            })?;
+
+            log::debug!("Creating note file with folders at path: {}", file_path);
+
+            // create the file
+            File::create(&file_path)
+                .map_err(|e| NostError::io(format!("Failed to create file {}", file_path), e))?;
+
+            let date_line = get_date_as_text(date, locale);
+            let header = match metadata {
+                Some(MetadataStyle::FrontMatter) => format!(
+                    "{}{}",
+                    format_front_matter(&FrontMatter {
+                        uid: Some(event.uid.clone()),
+                        created_at: Some(event.datetime.clone()),
+                        note_type: Some(note_type.to_string()),
+                        ..FrontMatter::default()
+                    }),
+                    date_line
+                ),
+                _ => date_line,
+            };
+            append(file_path.clone().into(), &header)
+                .map_err(|e| NostError::io(format!("Failed to write {}", file_path), e))?;
+
+            if metadata == Some(MetadataStyle::Annotations) {
+                let uid = event.uid.parse().ok();
+                annotate(
+                    Some(&event.datetime),
+                    EventName::CreateNot,
+                    uid.as_ref(),
+                    &file_path,
+                    &[("type", AnnotationValue::Text(note_type.to_string()))],
+                )?;
+            }
+
+            Ok(())
+        },
+    )?;
 
-    Ok(today_file_path)
+    Ok(NoteFile {
+        path: file_path.into(),
+        created: true,
+    })
 }
@@ -1,12 +1,29 @@
 use regex::Regex;
 use std::{env, fs::read_dir, io::Result as IoResult, path::PathBuf};
 
+use crate::files::layout::{build_folder_regex, get_layout};
+
 pub fn find_all_not_files(path: PathBuf) -> IoResult<Vec<PathBuf>> {
+    find_all_not_files_with_layouts(path, &[get_layout()])
+}
+
+/**
+ * Find the notes in the folders that any of the given layouts can produce.
+ * i.e. while reorganizing, notes are searched with both the previous and the new layouts.
+ */
+pub fn find_all_not_files_with_layouts(
+    path: PathBuf,
+    layouts: &[String],
+) -> IoResult<Vec<PathBuf>> {
     let mut files = Vec::new();
     let mut paths = vec![path];
 
-    let folder_regex = Regex::new(r"^\d+$").unwrap();
-    let file_regex = Regex::new(r".*\d+\.md$").unwrap();
+    let folder_regexes: Vec<Regex> = layouts
+        .iter()
+        .map(|layout| build_folder_regex(layout))
+        .collect();
+    // i.e. "05.md" for legacy notes or "05.work.md" for typed notes
+    let file_regex = Regex::new(r".*\d+(\.[\w-]+)?\.md$").unwrap();
 
     while let Some(current) = paths.pop() {
         // if path is a directory, read its content
@@ -15,8 +32,9 @@ pub fn find_all_not_files(path: PathBuf) -> IoResult<Vec<PathBuf>> {
                 for entry in entries.flatten() {
                     let current_path = entry.path();
                     if let Some(name) = current_path.file_name().and_then(|name| name.to_str()) {
-                        if current_path.is_dir() {
-                            if folder_regex.is_match(name) {
+                        // symlinked folders are not followed, they could loop
+                        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                            if folder_regexes.iter().any(|regex| regex.is_match(name)) {
                                 paths.push(current_path);
                             }
                         } else if file_regex.is_match(name) {
@@ -42,6 +60,37 @@ pub fn find_all_not_files(path: PathBuf) -> IoResult<Vec<PathBuf>> {
     Ok(files)
 }
 
+/**
+ * Find every markdown file of a folder, the day notes and any other note, i.e. "topics/acme.md".
+ * Hidden folders like ".nost" or ".git" are skipped, symlinked folders are not followed so that
+ * a link to a parent folder does not loop.
+ */
+pub fn find_all_markdown_files(path: PathBuf) -> IoResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut paths = vec![path];
+
+    while let Some(current) = paths.pop() {
+        for entry in read_dir(&current)?.flatten() {
+            let current_path = entry.path();
+            let Some(name) = current_path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            if entry.file_type()?.is_dir() {
+                if !name.starts_with('.') {
+                    paths.push(current_path);
+                }
+            } else if name.ends_with(".md") {
+                files.push(current_path);
+            }
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
 pub fn get_current_directory() -> Result<PathBuf, Box<dyn std::error::Error>> {
     let execution_path = env::current_exe()?;
     let execution_dir = execution_path
@@ -59,14 +108,14 @@ pub fn get_project_root() -> Result<PathBuf, Box<dyn std::error::Error>> {
     let project_root = target_dir
         .parent()
         .ok_or("Could not determine project root")?;
-    println!("Project root: {:?}\n", project_root);
+    log::debug!("Project root: {:?}", project_root);
 
     Ok(project_root.to_path_buf())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::files::find::find_all_not_files;
+    use crate::files::find::{find_all_markdown_files, find_all_not_files};
 
     #[test]
     #[serial_test::serial]
@@ -86,6 +135,7 @@ mod tests {
         let file1 = week_folder.join("01.md");
         let file2 = week_folder.join("02.md");
         let file3 = week_folder.join("not_a_note.txt");
+        let file4 = week_folder.join("03.work.md");
 
         File::create(&file1).unwrap().write_all(b"note 1").unwrap();
         File::create(&file2).unwrap().write_all(b"note 2").unwrap();
@@ -93,6 +143,7 @@ mod tests {
             .unwrap()
             .write_all(b"not a note")
             .unwrap();
+        File::create(&file4).unwrap().write_all(b"note 3").unwrap();
 
         // Should find only .md files in numeric folders
         let found = find_all_not_files(base.to_path_buf()).unwrap();
@@ -104,8 +155,9 @@ mod tests {
 
         assert!(found_files.contains(&"01.md".to_string()));
         assert!(found_files.contains(&"02.md".to_string()));
+        assert!(found_files.contains(&"03.work.md".to_string()));
         assert!(!found_files.contains(&"not_a_note.txt".to_string()));
-        assert_eq!(found_files.len(), 2);
+        assert_eq!(found_files.len(), 3);
     }
 
     #[test]
@@ -154,4 +206,22 @@ mod tests {
 
         assert!(last_file.is_none());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_folders_are_not_followed() {
+        use std::fs;
+        use tempfile::tempdir;
+
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir(base.join("topics")).unwrap();
+        fs::write(base.join("topics/acme.md"), "acme").unwrap();
+        // a link to the vault itself would be walked forever
+        std::os::unix::fs::symlink(base, base.join("topics/loop")).unwrap();
+
+        let found = find_all_markdown_files(base.to_path_buf()).unwrap();
+
+        assert_eq!(found, vec![base.join("topics/acme.md")]);
+    }
 }
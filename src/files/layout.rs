@@ -0,0 +1,261 @@
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+
+use crate::{
+    configurations::get::get_value_from_config, dates::get::get_week_of_month_for_date,
+    locales::catalog::EN,
+};
+
+/// Folder layout used when no `layout` is configured: year/month/week-of-month/day.
+pub const DEFAULT_LAYOUT: &str = "{year}/{month}/{week}/{day}";
+
+/// Tokens supported in a layout.
+const TOKENS: [&str; 7] = [
+    "{year}",
+    "{month}",
+    "{week}",
+    "{day}",
+    "{iso_year}",
+    "{iso_week}",
+    "{weekday}",
+];
+
+// Weekday folders always use the english names, so that the vault does not move
+// when the language changes
+fn weekday_folder(date: NaiveDate) -> String {
+    EN.weekdays[date.weekday().num_days_from_monday() as usize].to_lowercase()
+}
+
+// The pattern matching the rendered value of a token
+fn token_pattern(token: &str) -> String {
+    match token {
+        "{year}" | "{iso_year}" => r"\d{4}".to_string(),
+        "{month}" | "{iso_week}" => r"\d{2}".to_string(),
+        "{week}" => r"\d".to_string(),
+        "{day}" => r"\d{1,2}".to_string(),
+        _ => EN
+            .weekdays
+            .iter()
+            .map(|weekday| weekday.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("|"),
+    }
+}
+
+/**
+ * Check that a layout only contains known tokens and no empty segment.
+ * i.e. "{year}/W{iso_week}" is valid, "{year}/{quarter}" is not.
+ */
+pub fn validate_layout(layout: &str) -> Result<(), String> {
+    if layout.trim().is_empty() {
+        return Err("Layout cannot be empty".to_string());
+    }
+
+    for segment in layout.split('/') {
+        if segment.is_empty() {
+            return Err(format!("Layout '{}' contains an empty folder", layout));
+        }
+
+        let mut rest = segment.to_string();
+        for token in TOKENS {
+            rest = rest.replace(token, "");
+        }
+        if rest.contains('{') || rest.contains('}') {
+            return Err(format!(
+                "Layout '{}' contains an unknown token in '{}'",
+                layout, segment
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// Get the configured layout, or the default one if it is missing or invalid
+pub fn get_layout() -> String {
+    let layout = match get_value_from_config("layout") {
+        Ok(layout) => layout,
+        Err(_) => return DEFAULT_LAYOUT.to_string(),
+    };
+
+    match validate_layout(&layout) {
+        Ok(()) => layout,
+        Err(e) => {
+            eprintln!(
+                "⚠️  {}. Using the default layout \"{}\".",
+                e, DEFAULT_LAYOUT
+            );
+            DEFAULT_LAYOUT.to_string()
+        }
+    }
+}
+
+/**
+ * Render the folders of a layout for a date, without leading or trailing slash.
+ * i.e. render_layout("{year}/{month}/{week}/{day}", 2026-07-31) will return "2026/07/5/31".
+ */
+pub fn render_layout(layout: &str, date: NaiveDate) -> String {
+    layout
+        .replace("{year}", &date.year().to_string())
+        .replace("{month}", &format!("{:02}", date.month()))
+        .replace("{week}", &get_week_of_month_for_date(date).to_string())
+        .replace("{day}", &date.day().to_string())
+        .replace("{iso_year}", &date.iso_week().year().to_string())
+        .replace("{iso_week}", &format!("{:02}", date.iso_week().week()))
+        .replace("{weekday}", &weekday_folder(date))
+}
+
+/**
+ * Get the layout of the folder containing legacy notes, which is the parent of the day folder.
+ * i.e. "{year}/{month}/{week}" for the default layout.
+ */
+pub fn get_parent_layout(layout: &str) -> &str {
+    match layout.rsplit_once('/') {
+        Some((parent, _)) => parent,
+        None => "",
+    }
+}
+
+/**
+ * Get the layout of the folder of legacy notes. Their file name only holds the day, so the
+ * folder must tell the month or the ISO week apart: it is the parent of the day folder
+ * when it does, else the layout itself, else the layout with a month folder added.
+ * i.e. "{year}/{month}/{week}" for the default layout, "{year}/{month}" for "{year}/{month}".
+ */
+pub fn get_legacy_layout(layout: &str) -> String {
+    let tells_days_apart =
+        |layout: &str| layout.contains("{month}") || layout.contains("{iso_week}");
+
+    let parent = get_parent_layout(layout);
+    if tells_days_apart(parent) {
+        parent.to_string()
+    } else if tells_days_apart(layout) {
+        layout.to_string()
+    } else {
+        format!("{}/{{month}}", layout)
+    }
+}
+
+/**
+ * Render the leading folders of a layout which only depend on the year and the month,
+ * i.e. "2026/07" for the default layout, or "" for "{iso_year}/W{iso_week}".
+ */
+pub fn render_layout_month_prefix(layout: &str, date: NaiveDate) -> String {
+    let month_tokens = ["{year}", "{month}"];
+
+    let prefix: Vec<&str> = layout
+        .split('/')
+        .take_while(|segment| {
+            TOKENS
+                .iter()
+                .filter(|token| segment.contains(*token))
+                .all(|token| month_tokens.contains(token))
+        })
+        .collect();
+
+    render_layout(&prefix.join("/"), date)
+}
+
+/**
+ * Build a regex matching any folder name that the layout can produce.
+ * i.e. "{year}/W{iso_week}" will match "2026" and "W07".
+ */
+pub fn build_folder_regex(layout: &str) -> Regex {
+    let segments: Vec<String> = layout
+        .split('/')
+        .map(|segment| {
+            let mut pattern = regex::escape(segment);
+            for token in TOKENS {
+                pattern = pattern.replace(&regex::escape(token), &token_pattern(token));
+            }
+            format!("(?:{})", pattern)
+        })
+        .collect();
+
+    Regex::new(&format!("^(?:{})$", segments.join("|"))).expect("valid layout regex")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::date;
+
+    #[test]
+    fn default_layout_matches_historical_structure() {
+        assert_eq!(
+            render_layout(DEFAULT_LAYOUT, date(2026, 7, 31)),
+            "2026/07/5/31"
+        );
+        assert_eq!(
+            render_layout(DEFAULT_LAYOUT, date(2026, 6, 6)),
+            "2026/06/1/6"
+        );
+    }
+
+    #[test]
+    fn render_iso_week_and_weekday_tokens() {
+        // January 1st 2027 is a Friday in ISO week 53 of 2026
+        assert_eq!(
+            render_layout("{iso_year}/W{iso_week}/{weekday}", date(2027, 1, 1)),
+            "2026/W53/friday"
+        );
+        assert_eq!(render_layout("{year}/{month}", date(2026, 8, 5)), "2026/08");
+    }
+
+    #[test]
+    fn validate_layout_rejects_unknown_tokens_and_empty_folders() {
+        assert!(validate_layout(DEFAULT_LAYOUT).is_ok());
+        assert!(validate_layout("{year}/W{iso_week}").is_ok());
+        assert!(validate_layout("{year}/{quarter}").is_err());
+        assert!(validate_layout("{year}//{month}").is_err());
+        assert!(validate_layout("").is_err());
+    }
+
+    #[test]
+    fn parent_layout_drops_last_folder() {
+        assert_eq!(get_parent_layout(DEFAULT_LAYOUT), "{year}/{month}/{week}");
+        assert_eq!(get_parent_layout("{year}"), "");
+    }
+
+    #[test]
+    fn month_prefix_stops_at_first_non_month_folder() {
+        assert_eq!(
+            render_layout_month_prefix(DEFAULT_LAYOUT, date(2026, 7, 31)),
+            "2026/07"
+        );
+        assert_eq!(
+            render_layout_month_prefix("{iso_year}/W{iso_week}", date(2026, 7, 31)),
+            ""
+        );
+    }
+
+    #[test]
+    fn folder_regex_matches_rendered_folders_only() {
+        let regex = build_folder_regex("{year}/W{iso_week}");
+        assert!(regex.is_match("2026"));
+        assert!(regex.is_match("W07"));
+        assert!(!regex.is_match("07"));
+        assert!(!regex.is_match("W7x"));
+
+        let default_regex = build_folder_regex(DEFAULT_LAYOUT);
+        assert!(default_regex.is_match("5"));
+        assert!(default_regex.is_match("31"));
+        assert!(!default_regex.is_match(".nost"));
+
+        let weekday_regex = build_folder_regex("{iso_year}/W{iso_week}/{weekday}");
+        assert!(weekday_regex.is_match("friday"));
+        assert!(!weekday_regex.is_match("attachments"));
+        assert!(!weekday_regex.is_match("drafts"));
+    }
+
+    #[test]
+    fn legacy_layout_tells_the_days_of_a_year_apart() {
+        assert_eq!(get_legacy_layout(DEFAULT_LAYOUT), "{year}/{month}/{week}");
+        assert_eq!(get_legacy_layout("{year}/{month}"), "{year}/{month}");
+        assert_eq!(
+            get_legacy_layout("{iso_year}/W{iso_week}/{weekday}"),
+            "{iso_year}/W{iso_week}"
+        );
+        assert_eq!(get_legacy_layout("{year}"), "{year}/{month}");
+    }
+}
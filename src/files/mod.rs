@@ -1,6 +1,10 @@
 pub mod append;
 pub mod build_paths;
 pub mod check_content;
+pub mod convert_metadata;
 pub mod create;
 pub mod find;
+pub mod layout;
+pub mod models;
 pub mod name;
+pub mod reorganize;
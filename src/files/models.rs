@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteFile {
+    pub path: PathBuf,
+    // false when the note already existed
+    pub created: bool,
+}
+
+/// A note with the date and the type inferred from its path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatedNote {
+    pub path: PathBuf,
+    pub date: NaiveDate,
+    // None for legacy notes, i.e. "05.md"
+    pub note_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteMove {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteConflict {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReorganizationPlan {
+    pub moves: Vec<NoteMove>,
+    pub conflicts: Vec<NoteConflict>,
+    // notes whose date cannot be inferred from their path
+    pub unresolved: Vec<PathBuf>,
+    pub unchanged: usize,
+}
+
+/// Kept under `.nost/` so that an applied reorganization can be rolled back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReorganizationManifest {
+    pub created_at: String,
+    pub from_layouts: Vec<String>,
+    pub to_layout: String,
+    pub moves: Vec<NoteMove>,
+}
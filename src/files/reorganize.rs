@@ -0,0 +1,617 @@
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir, rename, write},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use crate::{
+    annotations::extract::extract_annotations_from_content,
+    dates::get::get_now_as_string,
+    errors::models::{NostError, NostResult},
+    files::{
+        layout::{get_legacy_layout, get_parent_layout, render_layout},
+        models::{DatedNote, NoteConflict, NoteMove, ReorganizationManifest, ReorganizationPlan},
+    },
+};
+
+static NOTE_FILE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<day>\d{1,2})(\.(?P<type>[\w-]+))?\.md$").expect("valid note file regex")
+});
+
+static YEAR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d{4}").expect("valid year regex"));
+
+/**
+ * Split a note file name into its day of the month and its note type.
+ * i.e. "05.work.md" will return (5, Some("work")) and "05.md" (5, None) for legacy notes.
+ */
+pub fn parse_note_file_name(file_name: &str) -> Option<(u32, Option<String>)> {
+    let caps = NOTE_FILE_REGEX.captures(file_name)?;
+    let day = caps.name("day")?.as_str().parse().ok()?;
+    let note_type = caps.name("type").map(|m| m.as_str().to_string());
+
+    Some((day, note_type))
+}
+
+/**
+ * Infer the date of a note from its folders, relative to the vault, for any of the given layouts.
+ * Legacy notes (without type) live in the parent folder of the day folder.
+ * Returns None when no date or several dates match.
+ */
+pub fn infer_note_date(
+    relative_folder: &str,
+    day: u32,
+    is_legacy: bool,
+    layouts: &[String],
+) -> Option<NaiveDate> {
+    let candidate_years: HashSet<i32> = YEAR_REGEX
+        .find_iter(relative_folder)
+        .filter_map(|m| m.as_str().parse::<i32>().ok())
+        // ISO years overlap the previous and the next calendar years
+        .flat_map(|year| [year - 1, year, year + 1])
+        .collect();
+
+    let mut matches = HashSet::new();
+    for year in candidate_years {
+        for month in 1..=12 {
+            let Some(date) = NaiveDate::from_ymd_opt(year, month, day) else {
+                continue;
+            };
+
+            for layout in layouts {
+                // older versions wrote legacy notes in the parent folder whatever the layout
+                let folder_layouts = if is_legacy {
+                    vec![
+                        get_legacy_layout(layout),
+                        get_parent_layout(layout).to_string(),
+                    ]
+                } else {
+                    vec![layout.to_string()]
+                };
+                if folder_layouts
+                    .iter()
+                    .any(|folder_layout| render_layout(folder_layout, date) == relative_folder)
+                {
+                    matches.insert(date);
+                }
+            }
+        }
+    }
+
+    if matches.len() == 1 {
+        matches.into_iter().next()
+    } else {
+        None
+    }
+}
+
+// Fall back on the first annotation of the note when its folders are ambiguous
+fn infer_note_date_from_annotations(path: &Path, day: u32) -> Option<NaiveDate> {
+    extract_annotations_from_content(&read_to_string(path).ok()?)
+        .iter()
+        .map(|annotation| annotation.datetime.date_naive())
+        .find(|date| date.day() == day)
+}
+
+pub fn build_note_target(
+    vault_path: &Path,
+    date: NaiveDate,
+    file_name: &str,
+    is_legacy: bool,
+    layout: &str,
+) -> PathBuf {
+    let folder_layout = if is_legacy {
+        get_legacy_layout(layout)
+    } else {
+        layout.to_string()
+    };
+
+    let folders = render_layout(&folder_layout, date);
+    if folders.is_empty() {
+        vault_path.join(file_name)
+    } else {
+        vault_path.join(folders).join(file_name)
+    }
+}
+
+/**
+ * Get the date and the type of a note from its file name and its folders, or from its
+ * annotations when the folders are ambiguous. None for files which are not day notes.
+ */
+pub fn infer_dated_note(vault_path: &Path, path: &Path, layouts: &[String]) -> Option<DatedNote> {
+    let file_name = path.file_name().and_then(|name| name.to_str())?;
+    let (day, note_type) = parse_note_file_name(file_name)?;
+
+    let relative_folder = path
+        .parent()
+        .and_then(|parent| parent.strip_prefix(vault_path).ok())
+        .map(|folder| {
+            folder
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<String>>()
+                .join("/")
+        })
+        .unwrap_or_default();
+
+    let date = infer_note_date(&relative_folder, day, note_type.is_none(), layouts)
+        .or_else(|| infer_note_date_from_annotations(path, day))?;
+
+    Some(DatedNote {
+        path: path.to_path_buf(),
+        date,
+        note_type,
+    })
+}
+
+/**
+ * Compute where every note should live with the target layout.
+ * Nothing is written: notes already in place are counted as unchanged, and notes which would
+ * overwrite another file are reported as conflicts.
+ */
+pub fn plan_reorganization(
+    vault_path: &Path,
+    files: Vec<PathBuf>,
+    from_layouts: &[String],
+    to_layout: &str,
+) -> ReorganizationPlan {
+    let mut plan = ReorganizationPlan::default();
+    let mut targets: HashMap<PathBuf, PathBuf> = HashMap::new();
+    let sources: HashSet<PathBuf> = files.iter().cloned().collect();
+
+    for path in files {
+        let Some(note) = infer_dated_note(vault_path, &path, from_layouts) else {
+            plan.unresolved.push(path);
+            continue;
+        };
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let is_legacy = note.note_type.is_none();
+
+        let target = build_note_target(vault_path, note.date, &file_name, is_legacy, to_layout);
+
+        if target == path {
+            plan.unchanged += 1;
+            continue;
+        }
+
+        if let Some(other) = targets.get(&target) {
+            plan.conflicts.push(NoteConflict {
+                from: path,
+                to: target,
+                reason: format!("{} is moved to the same place", other.display()),
+            });
+            continue;
+        }
+
+        // an existing file is only a collision if it is not itself moved away
+        if target.exists() && !sources.contains(&target) {
+            plan.conflicts.push(NoteConflict {
+                from: path,
+                to: target,
+                reason: "a file already exists at the target".to_string(),
+            });
+            continue;
+        }
+
+        targets.insert(target.clone(), path.clone());
+        plan.moves.push(NoteMove {
+            from: path,
+            to: target,
+        });
+    }
+
+    plan
+}
+
+// Remove the folders left empty by a move, up to the vault root
+fn remove_empty_folders(from: &Path, vault_path: &Path) {
+    let mut current = from.parent();
+    while let Some(folder) = current {
+        if folder == vault_path || !folder.starts_with(vault_path) {
+            break;
+        }
+        let is_empty = read_dir(folder)
+            .map(|mut entries| entries.next().is_none())
+            .unwrap_or(false);
+        if !is_empty || remove_dir(folder).is_err() {
+            break;
+        }
+        current = folder.parent();
+    }
+}
+
+// Move notes in an order which never overwrites a note that still has to move
+fn execute_moves(moves: &[NoteMove], vault_path: &Path) -> NostResult<()> {
+    let mut pending: Vec<&NoteMove> = moves.iter().collect();
+
+    while !pending.is_empty() {
+        let blocked: HashSet<&PathBuf> = pending.iter().map(|m| &m.from).collect();
+        let (ready, rest): (Vec<&NoteMove>, Vec<&NoteMove>) =
+            pending.into_iter().partition(|m| !blocked.contains(&m.to));
+
+        if ready.is_empty() {
+            return Err(NostError::Conflict(
+                "Notes are moved in a cycle, the reorganization cannot be applied".to_string(),
+            ));
+        }
+
+        for note_move in ready {
+            if let Some(parent) = note_move.to.parent() {
+                create_dir_all(parent).map_err(|e| {
+                    NostError::io(format!("Failed to create {}", parent.display()), e)
+                })?;
+            }
+            rename(&note_move.from, &note_move.to).map_err(|e| {
+                NostError::io(
+                    format!(
+                        "Failed to move '{}' to '{}'",
+                        note_move.from.display(),
+                        note_move.to.display()
+                    ),
+                    e,
+                )
+            })?;
+            remove_empty_folders(&note_move.from, vault_path);
+        }
+
+        pending = rest;
+    }
+
+    Ok(())
+}
+
+/**
+ * Replace every string of the journal equal to a moved path by its new path.
+ * Returns the number of replaced values.
+ */
+pub fn replace_paths_in_journal(journal_file_path: &Path, moves: &[NoteMove]) -> NostResult<usize> {
+    if !journal_file_path.exists() {
+        return Ok(0);
+    }
+
+    let content = read_to_string(journal_file_path)
+        .map_err(|e| NostError::io(format!("Cannot read {}", journal_file_path.display()), e))?;
+    let mut journal: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| NostError::CorruptJournal {
+            path: journal_file_path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+    let replacements: HashMap<String, String> = moves
+        .iter()
+        .map(|m| {
+            (
+                m.from.to_string_lossy().into_owned(),
+                m.to.to_string_lossy().into_owned(),
+            )
+        })
+        .collect();
+
+    fn replace(value: &mut serde_json::Value, replacements: &HashMap<String, String>) -> usize {
+        match value {
+            serde_json::Value::String(s) => match replacements.get(s.as_str()) {
+                Some(new_path) => {
+                    *s = new_path.clone();
+                    1
+                }
+                None => 0,
+            },
+            serde_json::Value::Array(values) => {
+                values.iter_mut().map(|v| replace(v, replacements)).sum()
+            }
+            serde_json::Value::Object(map) => {
+                map.values_mut().map(|v| replace(v, replacements)).sum()
+            }
+            _ => 0,
+        }
+    }
+
+    let replaced = replace(&mut journal, &replacements);
+    if replaced > 0 {
+        let updated = serde_json::to_string_pretty(&journal)
+            .map_err(|e| NostError::io("Failed to serialize the journal", e.into()))?;
+        write(journal_file_path, format!("{}\n", updated)).map_err(|e| {
+            NostError::io(format!("Cannot write {}", journal_file_path.display()), e)
+        })?;
+    }
+
+    Ok(replaced)
+}
+
+/**
+ * Apply a plan: write the rollback manifest in the configuration folder, move the notes and
+ * update the journal. Returns the path of the manifest.
+ */
+pub fn apply_reorganization(
+    plan: &ReorganizationPlan,
+    vault_path: &Path,
+    config_path: &Path,
+    from_layouts: &[String],
+    to_layout: &str,
+) -> NostResult<PathBuf> {
+    create_dir_all(config_path)
+        .map_err(|e| NostError::io(format!("Cannot create {}", config_path.display()), e))?;
+
+    let created_at = get_now_as_string();
+    let manifest = ReorganizationManifest {
+        created_at: created_at.clone(),
+        from_layouts: from_layouts.to_vec(),
+        to_layout: to_layout.to_string(),
+        moves: plan.moves.clone(),
+    };
+
+    // a second reorganization in the same second gets a numbered manifest, i.e. "-2"
+    let manifest_stem = format!(
+        "reorganize-{}",
+        created_at
+            .get(0..19)
+            .unwrap_or(&created_at)
+            .replace(':', "")
+    );
+    let mut manifest_path = config_path.join(format!("{}.json", manifest_stem));
+    let mut counter = 1;
+    while manifest_path.exists() {
+        counter += 1;
+        manifest_path = config_path.join(format!("{}-{}.json", manifest_stem, counter));
+    }
+    let manifest_content = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| NostError::io("Failed to serialize the manifest", e.into()))?;
+    write(&manifest_path, format!("{}\n", manifest_content))
+        .map_err(|e| NostError::io(format!("Cannot write {}", manifest_path.display()), e))?;
+
+    execute_moves(&plan.moves, vault_path)?;
+    replace_paths_in_journal(&config_path.join("journal.json"), &plan.moves)?;
+
+    Ok(manifest_path)
+}
+
+/**
+ * Move the notes of a manifest back to where they were. Notes which are no longer at their
+ * new place are skipped. Returns the moves done to restore the notes.
+ */
+pub fn rollback_reorganization(
+    manifest_path: &Path,
+    vault_path: &Path,
+    config_path: &Path,
+) -> NostResult<Vec<NoteMove>> {
+    let content = read_to_string(manifest_path)
+        .map_err(|e| NostError::io(format!("Cannot read {}", manifest_path.display()), e))?;
+    let manifest: ReorganizationManifest =
+        serde_json::from_str(&content).map_err(|e| NostError::CorruptJournal {
+            path: manifest_path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+    let reversed: Vec<NoteMove> = manifest
+        .moves
+        .iter()
+        .filter(|m| m.to.exists() && !m.from.exists())
+        .map(|m| NoteMove {
+            from: m.to.clone(),
+            to: m.from.clone(),
+        })
+        .collect();
+
+    execute_moves(&reversed, vault_path)?;
+    replace_paths_in_journal(&config_path.join("journal.json"), &reversed)?;
+
+    Ok(reversed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files::layout::DEFAULT_LAYOUT;
+    use crate::test_helpers::date;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn create_note(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn parse_note_file_name_reads_day_and_type() {
+        assert_eq!(
+            parse_note_file_name("05.work.md"),
+            Some((5, Some("work".to_string())))
+        );
+        assert_eq!(parse_note_file_name("31.md"), Some((31, None)));
+        assert_eq!(parse_note_file_name("notes.md"), None);
+    }
+
+    #[test]
+    fn infer_note_date_from_default_layout() {
+        let layouts = vec![DEFAULT_LAYOUT.to_string()];
+        assert_eq!(
+            infer_note_date("2026/07/5/31", 31, false, &layouts),
+            Some(date(2026, 7, 31))
+        );
+        // legacy notes live in the week folder
+        assert_eq!(
+            infer_note_date("2026/07/5", 31, true, &layouts),
+            Some(date(2026, 7, 31))
+        );
+        assert_eq!(infer_note_date("2026/07/1/31", 31, false, &layouts), None);
+    }
+
+    #[test]
+    fn infer_note_date_across_iso_years() {
+        let layouts = vec!["{iso_year}/W{iso_week}".to_string()];
+        assert_eq!(
+            infer_note_date("2026/W53", 1, false, &layouts),
+            Some(date(2027, 1, 1))
+        );
+    }
+
+    #[test]
+    fn plan_moves_notes_and_reports_conflicts() {
+        let dir = tempdir().unwrap();
+        let vault = dir.path();
+        let layouts = vec![DEFAULT_LAYOUT.to_string()];
+
+        let note = vault.join("2026/08/2/5/05.work.md");
+        let legacy = vault.join("2026/08/2/05.md");
+        create_note(&note, "# work");
+        create_note(&legacy, "# legacy");
+
+        let plan = plan_reorganization(
+            vault,
+            vec![note.clone(), legacy.clone()],
+            &layouts,
+            "{iso_year}/W{iso_week}/{weekday}",
+        );
+
+        assert_eq!(
+            plan.moves,
+            vec![
+                NoteMove {
+                    from: note,
+                    to: vault.join("2026/W32/wednesday/05.work.md"),
+                },
+                NoteMove {
+                    from: legacy,
+                    to: vault.join("2026/W32/05.md"),
+                },
+            ]
+        );
+        assert!(plan.conflicts.is_empty());
+        assert_eq!(plan.unchanged, 0);
+
+        // the note of the 6th would overwrite an existing file
+        let other = vault.join("2026/08/2/6/06.default.md");
+        create_note(&other, "# other");
+        create_note(&vault.join("2026/W32/thursday/06.default.md"), "# taken");
+        let plan = plan_reorganization(
+            vault,
+            vec![other],
+            &layouts,
+            "{iso_year}/W{iso_week}/{weekday}",
+        );
+        assert_eq!(plan.conflicts.len(), 1);
+        assert!(plan.moves.is_empty());
+    }
+
+    #[test]
+    fn plan_keeps_legacy_notes_of_two_months_apart() {
+        let dir = tempdir().unwrap();
+        let vault = dir.path();
+        let layouts = vec![DEFAULT_LAYOUT.to_string()];
+
+        let august = vault.join("2026/08/2/05.md");
+        let september = vault.join("2026/09/1/05.md");
+        create_note(&august, "# august");
+        create_note(&september, "# september");
+
+        let plan = plan_reorganization(
+            vault,
+            vec![august.clone(), september.clone()],
+            &layouts,
+            "{year}/{month}",
+        );
+
+        assert_eq!(
+            plan.moves,
+            vec![
+                NoteMove {
+                    from: august,
+                    to: vault.join("2026/08/05.md"),
+                },
+                NoteMove {
+                    from: september,
+                    to: vault.join("2026/09/05.md"),
+                },
+            ]
+        );
+        assert!(plan.conflicts.is_empty());
+
+        let config_path = vault.join(".nost");
+        fs::create_dir_all(&config_path).unwrap();
+        fs::write(config_path.join("journal.json"), "[]").unwrap();
+        apply_reorganization(&plan, vault, &config_path, &layouts, "{year}/{month}").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(vault.join("2026/08/05.md")).unwrap(),
+            "# august"
+        );
+        assert_eq!(
+            fs::read_to_string(vault.join("2026/09/05.md")).unwrap(),
+            "# september"
+        );
+        // the notes of the new layout are found again
+        let new_layouts = vec!["{year}/{month}".to_string()];
+        assert_eq!(
+            infer_note_date("2026/09", 5, true, &new_layouts),
+            Some(date(2026, 9, 5))
+        );
+    }
+
+    #[test]
+    fn apply_and_rollback_restore_the_vault() {
+        let dir = tempdir().unwrap();
+        let vault = dir.path();
+        let config_path = vault.join(".nost");
+        let layouts = vec![DEFAULT_LAYOUT.to_string()];
+
+        let note = vault.join("2026/08/2/5/05.work.md");
+        create_note(&note, "# work");
+        fs::create_dir_all(&config_path).unwrap();
+        fs::write(
+            config_path.join("journal.json"),
+            format!(
+                "[{{\"event\":\"CREATE_NOT\",\"path\":\"{}\"}}]",
+                note.display()
+            ),
+        )
+        .unwrap();
+
+        let plan = plan_reorganization(vault, vec![note.clone()], &layouts, "{year}/{month}");
+        let manifest_path =
+            apply_reorganization(&plan, vault, &config_path, &layouts, "{year}/{month}").unwrap();
+
+        let target = vault.join("2026/08/05.work.md");
+        assert!(target.exists());
+        assert!(!note.exists());
+        // the emptied week and day folders are removed
+        assert!(!vault.join("2026/08/2").exists());
+        assert!(manifest_path.starts_with(&config_path));
+        let journal = fs::read_to_string(config_path.join("journal.json")).unwrap();
+        assert!(journal.contains(target.to_str().unwrap()));
+
+        let restored = rollback_reorganization(&manifest_path, vault, &config_path).unwrap();
+
+        assert_eq!(restored.len(), 1);
+        assert!(note.exists());
+        assert!(!target.exists());
+        let journal = fs::read_to_string(config_path.join("journal.json")).unwrap();
+        assert!(journal.contains(note.to_str().unwrap()));
+    }
+
+    #[test]
+    fn reorganizations_in_the_same_second_keep_their_manifests() {
+        let dir = tempdir().unwrap();
+        let vault = dir.path();
+        let config_path = vault.join(".nost");
+        let layouts = vec![DEFAULT_LAYOUT.to_string()];
+        let plan = plan_reorganization(vault, Vec::new(), &layouts, "{year}/{month}");
+
+        let manifest_paths: Vec<PathBuf> = (0..3)
+            .map(|_| {
+                apply_reorganization(&plan, vault, &config_path, &layouts, "{year}/{month}")
+                    .unwrap()
+            })
+            .collect();
+
+        assert!(manifest_paths.iter().all(|path| path.exists()));
+        assert_ne!(manifest_paths[0], manifest_paths[1]);
+        assert_ne!(manifest_paths[1], manifest_paths[2]);
+        assert_ne!(manifest_paths[0], manifest_paths[2]);
+    }
+}
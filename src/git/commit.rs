@@ -0,0 +1,195 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    vaults::vault::Vault,
+};
+
+// Run git in the given folder, the folder does not have to be the root of the repository
+pub fn run_git(repository: &Path, args: &[&str]) -> NostResult<Output> {
+    Command::new("git")
+        .arg("-C")
+        .arg(repository)
+        .args(args)
+        .output()
+        .map_err(|e| NostError::io("Cannot run git, is it installed?", e))
+}
+
+// Run git and fail with its error output when it does not succeed
+fn run_git_checked(repository: &Path, args: &[&str]) -> NostResult<Output> {
+    let output = run_git(repository, args)?;
+    if output.status.success() {
+        return Ok(output);
+    }
+
+    Err(NostError::io(
+        format!("git {} failed in {}", args[0], repository.display()),
+        io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+    ))
+}
+
+pub fn is_git_repository(path: &Path) -> bool {
+    path.is_dir()
+        && run_git(path, &["rev-parse", "--is-inside-work-tree"])
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+}
+
+/**
+ * Build the message of an automatic commit from the command and the recorded event.
+ * i.e. commit_message("work", "START_WORK", "2026-08-05T09:00:12+02:00") -> "work: START_WORK 2026-08-05T09:00"
+ */
+pub fn commit_message(command: &str, event: &str, datetime: &str) -> String {
+    format!(
+        "{}: {} {}",
+        command,
+        event,
+        datetime.get(0..16).unwrap_or(datetime)
+    )
+}
+
+/**
+ * Stage and commit the given paths only, leaving the other changes of the repository alone.
+ * Deleted and moved files are staged too. Returns false when there was nothing to commit.
+ */
+pub fn commit_paths(repository: &Path, paths: &[PathBuf], message: &str) -> NostResult<bool> {
+    // git refuses paths which do not exist and were never committed
+    let paths: Vec<&str> = paths
+        .iter()
+        .filter(|path| path.exists() || is_tracked(repository, path))
+        .filter_map(|path| path.to_str())
+        .collect();
+    if paths.is_empty() {
+        return Ok(false);
+    }
+
+    let mut add = vec!["add", "--all", "--"];
+    add.extend(&paths);
+    run_git_checked(repository, &add)?;
+
+    let mut diff = vec!["diff", "--cached", "--quiet", "--"];
+    diff.extend(&paths);
+    if run_git(repository, &diff)?.status.success() {
+        return Ok(false);
+    }
+
+    let mut commit = vec!["commit", "--quiet", "--message", message, "--"];
+    commit.extend(&paths);
+    run_git_checked(repository, &commit)?;
+
+    Ok(true)
+}
+
+fn is_tracked(repository: &Path, path: &Path) -> bool {
+    let Some(path) = path.to_str() else {
+        return false;
+    };
+
+    run_git(repository, &["ls-files", "--error-unmatch", "--", path])
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/**
+ * Commit the files touched by a command and the journal, when `git.autocommit` is set for
+ * the vault. The command is already done, so a failure is only reported.
+ */
+pub fn autocommit(vault: &Vault, message: &str, paths: &[PathBuf]) {
+    if !vault.autocommit() {
+        return;
+    }
+
+    if !is_git_repository(vault.path()) {
+        eprintln!(
+            "⚠️  git.autocommit is set but {} is not a git repository, nothing was committed.",
+            vault.path().display()
+        );
+        return;
+    }
+
+    // the journal, the state and the project file of `.nost/` are updated by most commands
+    let mut paths = paths.to_vec();
+    paths.push(vault.journal_path());
+    for file_name in ["state.json", "project.json"] {
+        paths.push(Path::new(&vault.config_path()).join(file_name));
+    }
+
+    match commit_paths(vault.path(), &paths, message) {
+        Ok(true) => log::debug!("Committed \"{}\"", message),
+        Ok(false) => log::debug!("Nothing to commit for \"{}\"", message),
+        Err(e) => eprintln!("⚠️  Nothing was committed: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn init_repository(path: &Path) {
+        run_git_checked(path, &["init", "--quiet"]).unwrap();
+        run_git_checked(path, &["config", "user.name", "Nost"]).unwrap();
+        run_git_checked(path, &["config", "user.email", "nost@example.com"]).unwrap();
+    }
+
+    fn last_commit(path: &Path) -> String {
+        let output = run_git_checked(path, &["log", "-1", "--name-only", "--format=%s"]).unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn commit_message_keeps_the_minutes() {
+        assert_eq!(
+            commit_message("work", "START_WORK", "2026-08-05T09:00:12.5+02:00"),
+            "work: START_WORK 2026-08-05T09:00"
+        );
+    }
+
+    #[test]
+    fn only_the_given_paths_are_committed() {
+        let dir = tempdir().unwrap();
+        init_repository(dir.path());
+        let note = dir.path().join("05.work.md");
+        let other = dir.path().join("other.md");
+        fs::write(&note, "# note").unwrap();
+        fs::write(&other, "# other").unwrap();
+
+        let committed = commit_paths(
+            dir.path(),
+            &[note.clone(), dir.path().join("missing.md")],
+            "new: CREATE_NOT 2026-08-05T09:00",
+        )
+        .unwrap();
+
+        assert!(committed);
+        assert_eq!(
+            last_commit(dir.path()),
+            "new: CREATE_NOT 2026-08-05T09:00\n\n05.work.md"
+        );
+
+        // nothing changed since the last commit
+        assert!(!commit_paths(dir.path(), &[note], "new").unwrap());
+    }
+
+    #[test]
+    fn moved_notes_are_committed() {
+        let dir = tempdir().unwrap();
+        init_repository(dir.path());
+        let from = dir.path().join("05.md");
+        let to = dir.path().join("2026").join("05.md");
+        fs::write(&from, "# note").unwrap();
+        commit_paths(dir.path(), std::slice::from_ref(&from), "new").unwrap();
+
+        fs::create_dir_all(to.parent().unwrap()).unwrap();
+        fs::rename(&from, &to).unwrap();
+
+        assert!(commit_paths(dir.path(), &[from, to], "reorganize").unwrap());
+        let output = run_git_checked(dir.path(), &["status", "--porcelain"]).unwrap();
+        assert!(output.stdout.is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod commit;
+pub mod models;
+pub mod status;
@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitChange {
+    // two letters of `git status --porcelain`, i.e. " M", "??" or "R "
+    pub status: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitStatus {
+    // None on a detached HEAD
+    pub branch: Option<String>,
+    // i.e. "origin/main"
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    pub changes: Vec<GitChange>,
+}
@@ -0,0 +1,101 @@
+use std::path::Path;
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    git::{
+        commit::run_git,
+        models::{GitChange, GitStatus},
+    },
+};
+
+/**
+ * Parse the output of `git status --porcelain --branch`.
+ * i.e. "## main...origin/main [ahead 1]" then " M 2026/08/2/5/05.work.md"
+ */
+pub fn parse_git_status(output: &str) -> GitStatus {
+    let mut status = GitStatus::default();
+
+    for line in output.lines() {
+        if let Some(branch_line) = line.strip_prefix("## ") {
+            let (branches, counts) = match branch_line.split_once(" [") {
+                Some((branches, counts)) => (branches, counts.trim_end_matches(']')),
+                None => (branch_line, ""),
+            };
+
+            let (branch, upstream) = match branches.split_once("...") {
+                Some((branch, upstream)) => (branch, Some(upstream.to_string())),
+                None => (branches, None),
+            };
+            // i.e. "HEAD (no branch)" or "No commits yet on main"
+            status.branch = match branch.strip_prefix("No commits yet on ") {
+                Some(branch) => Some(branch.to_string()),
+                None if branch.starts_with("HEAD ") => None,
+                None => Some(branch.to_string()),
+            };
+            status.upstream = upstream;
+
+            for count in counts.split(", ") {
+                if let Some(ahead) = count.strip_prefix("ahead ") {
+                    status.ahead = ahead.parse().unwrap_or(0);
+                } else if let Some(behind) = count.strip_prefix("behind ") {
+                    status.behind = behind.parse().unwrap_or(0);
+                }
+            }
+        } else if line.len() > 3 {
+            status.changes.push(GitChange {
+                status: line[0..2].to_string(),
+                path: line[3..].to_string(),
+            });
+        }
+    }
+
+    status
+}
+
+pub fn get_git_status(repository: &Path) -> NostResult<GitStatus> {
+    let output = run_git(
+        repository,
+        &["status", "--porcelain", "--branch", "--untracked-files=all"],
+    )?;
+    if !output.status.success() {
+        return Err(NostError::io(
+            format!("git status failed in {}", repository.display()),
+            std::io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        ));
+    }
+
+    Ok(parse_git_status(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_branch_counts_and_changes() {
+        let status = parse_git_status(
+            "## main...origin/main [ahead 2, behind 1]\n M 2026/08/2/5/05.work.md\n?? .nost/journal.json\nR  01.md -> 2026/01.md\n",
+        );
+
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.upstream.as_deref(), Some("origin/main"));
+        assert_eq!((status.ahead, status.behind), (2, 1));
+        assert_eq!(status.changes.len(), 3);
+        assert_eq!(
+            status.changes[1],
+            GitChange {
+                status: "??".to_string(),
+                path: ".nost/journal.json".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_repository_without_commits_or_upstream() {
+        let status = parse_git_status("## No commits yet on main\n");
+
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.upstream, None);
+        assert!(status.changes.is_empty());
+    }
+}
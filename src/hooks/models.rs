@@ -0,0 +1,107 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt};
+
+use crate::events::models::EventName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStage {
+    // before the event is recorded, a failure cancels the action
+    Pre,
+    // after the event is recorded, a failure is only reported
+    Post,
+}
+
+impl fmt::Display for HookStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HookStage::Pre => write!(f, "pre"),
+            HookStage::Post => write!(f, "post"),
+        }
+    }
+}
+
+/**
+ * Shell commands of the `[hooks]` section of the configuration, by hook name.
+ * i.e. `post_new = "make -C ~/notes site"` or `pre_work_start = "~/bin/slack-status busy"`
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Hooks(BTreeMap<String, String>);
+
+impl Hooks {
+    pub fn new(commands: BTreeMap<String, String>) -> Self {
+        Hooks(commands)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // The command to run for an event, i.e. "pre_work_start" for START_WORK
+    pub fn command(&self, stage: HookStage, event: &str) -> Option<&str> {
+        self.0
+            .get(&hook_name(stage, event))
+            .map(String::as_str)
+            .filter(|command| !command.trim().is_empty())
+    }
+
+    // The hooks of a vault, overriding the top-level hooks with the same name
+    pub fn merge(&self, other: &Hooks) -> Hooks {
+        let mut commands = self.0.clone();
+        commands.extend(other.0.clone());
+        Hooks(commands)
+    }
+}
+
+/**
+ * Get the name of the hook of an event: CREATE_NOT is "new", START_WORK and STOP_WORK are
+ * "work_start" and "work_stop", plugin events are lowercased.
+ * i.e. hook_name(HookStage::Post, "FOCUS_END") -> "post_focus_end"
+ */
+pub fn hook_name(stage: HookStage, event: &str) -> String {
+    let suffix = match event.parse::<EventName>() {
+        Ok(EventName::CreateNot) => "new".to_string(),
+        Ok(EventName::StartWork) => "work_start".to_string(),
+        Ok(EventName::StopWork) => "work_stop".to_string(),
+        _ => event.to_lowercase(),
+    };
+
+    format!("{}_{}", stage, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hook_names_follow_the_commands() {
+        assert_eq!(hook_name(HookStage::Post, "CREATE_NOT"), "post_new");
+        assert_eq!(hook_name(HookStage::Pre, "START_WORK"), "pre_work_start");
+        assert_eq!(hook_name(HookStage::Post, "STOP_WORK"), "post_work_stop");
+        assert_eq!(hook_name(HookStage::Pre, "FOCUS_START"), "pre_focus_start");
+    }
+
+    #[test]
+    fn vault_hooks_override_top_level_hooks() {
+        let top_level = Hooks::new(BTreeMap::from([
+            ("post_new".to_string(), "echo top".to_string()),
+            ("pre_work_start".to_string(), "echo start".to_string()),
+        ]));
+        let vault = Hooks::new(BTreeMap::from([(
+            "post_new".to_string(),
+            "echo vault".to_string(),
+        )]));
+
+        let hooks = top_level.merge(&vault);
+
+        assert_eq!(
+            hooks.command(HookStage::Post, "CREATE_NOT"),
+            Some("echo vault")
+        );
+        assert_eq!(
+            hooks.command(HookStage::Pre, "START_WORK"),
+            Some("echo start")
+        );
+        assert_eq!(hooks.command(HookStage::Post, "START_WORK"), None);
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    events::models::Event,
+    hooks::models::{hook_name, HookStage, Hooks},
+};
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(not(unix))]
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+/**
+ * Run the hook of an event, if configured, from the notes folder. The event is given as JSON
+ * in NOST_EVENT and on the standard input, and its fields in NOST_EVENT_* variables.
+ * A failing hook is an error, the caller decides whether it cancels the action.
+ */
+pub fn run_hook(hooks: &Hooks, stage: HookStage, event: &Event, not_path: &Path) -> NostResult<()> {
+    let Some(command) = hooks.command(stage, &event.event) else {
+        return Ok(());
+    };
+    let hook = hook_name(stage, &event.event);
+
+    let event_json = serde_json::to_string(event)
+        .map_err(|e| NostError::io("Failed to serialize the event", e.into()))?;
+
+    log::debug!("Running hook {}: {}", hook, command);
+    let mut shell = shell_command(command);
+    shell
+        .env("NOST_HOOK", &hook)
+        .env("NOST_EVENT", &event_json)
+        .env("NOST_EVENT_NAME", &event.event)
+        .env("NOST_EVENT_DATETIME", &event.datetime)
+        .env("NOST_EVENT_DAY", &event.day)
+        .env("NOST_EVENT_NOT_TYPE", &event.not_type)
+        .env("NOST_EVENT_UID", &event.uid)
+        .env("NOST_VAULT_PATH", not_path)
+        .stdin(Stdio::piped());
+    if not_path.is_dir() {
+        shell.current_dir(not_path);
+    }
+
+    let mut child = shell
+        .spawn()
+        .map_err(|e| NostError::io(format!("Cannot run hook {}", hook), e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // a hook which does not read its standard input closes it early, this is fine
+        let _ = writeln!(stdin, "{}", event_json);
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| NostError::io(format!("Cannot run hook {}", hook), e))?;
+
+    if status.success() {
+        return Ok(());
+    }
+
+    Err(NostError::HookFailed {
+        hook,
+        code: status.code(),
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::events::models::EventName;
+    use std::{collections::BTreeMap, fs};
+    use tempfile::tempdir;
+
+    #[test]
+    fn hook_receives_the_event_in_environment_and_stdin() {
+        let dir = tempdir().unwrap();
+        let hooks = Hooks::new(BTreeMap::from([(
+            "post_work_stop".to_string(),
+            "echo \"$NOST_HOOK $NOST_EVENT_NAME $PWD\" > hook.txt; cat >> hook.txt".to_string(),
+        )]));
+        let event = Event::now(EventName::StopWork, "work".to_string());
+
+        run_hook(&hooks, HookStage::Post, &event, dir.path()).unwrap();
+
+        let content = fs::read_to_string(dir.path().join("hook.txt")).unwrap();
+        let mut lines = content.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            format!("post_work_stop STOP_WORK {}", dir.path().display())
+        );
+        let json: serde_json::Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(json["uid"], event.uid.as_str());
+    }
+
+    #[test]
+    fn failing_hook_is_an_error() {
+        let dir = tempdir().unwrap();
+        let hooks = Hooks::new(BTreeMap::from([(
+            "pre_work_start".to_string(),
+            "exit 4".to_string(),
+        )]));
+        let event = Event::now(EventName::StartWork, "work".to_string());
+
+        let error = run_hook(&hooks, HookStage::Pre, &event, dir.path()).unwrap_err();
+
+        assert!(matches!(error, NostError::HookFailed { code: Some(4), .. }));
+        // no hook for this stage
+        assert!(run_hook(&hooks, HookStage::Post, &event, dir.path()).is_ok());
+    }
+}
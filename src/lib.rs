@@ -0,0 +1,45 @@
+//! Nost creates markdown notes following the NOT format and keeps a journal of
+//! events (note creation, work sessions) in `<notes folder>/.nost/journal.json`.
+//!
+//! The [`Vault`] type is the entry point of the library:
+//!
+//! ```no_run
+//! use chrono::NaiveDate;
+//! use nost::Vault;
+//!
+//! let vault = Vault::open("/path/to/notes").with_language("fr");
+//!
+//! let date = NaiveDate::from_ymd_opt(2026, 8, 5).unwrap();
+//! let note = vault.create_note(date, "work")?;
+//! println!("{}", note.path.display());
+//!
+//! let stats = vault.month_stats(Some("2026-08"))?;
+//! println!("{} minutes worked", stats.total_duration_in_minutes);
+//! # Ok::<(), nost::errors::models::NostError>(())
+//! ```
+//!
+//! The `nost` binary is a thin wrapper over the [`commands`] of this crate.
+
+pub mod annotations;
+pub mod calendar;
+pub mod commands;
+pub mod configurations;
+pub mod dates;
+pub mod errors;
+pub mod events;
+pub mod export;
+pub mod files;
+pub mod git;
+pub mod hooks;
+pub mod links;
+pub mod locales;
+pub mod plugins;
+pub mod projects;
+pub mod statistics;
+#[cfg(test)]
+mod test_helpers;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod vaults;
+
+pub use vaults::vault::Vault;
@@ -0,0 +1,198 @@
+use chrono::NaiveDate;
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    files::{find::find_all_markdown_files, models::DatedNote, reorganize::parse_note_file_name},
+    links::{
+        models::{display_note, DayIndex, LinkEdge, LinkGraph},
+        parse::parse_links,
+    },
+    vaults::vault::Vault,
+};
+
+fn relative(path: &Path, vault_path: &Path) -> PathBuf {
+    path.strip_prefix(vault_path).unwrap_or(path).to_path_buf()
+}
+
+fn is_day_note(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(parse_note_file_name)
+        .is_some()
+}
+
+/**
+ * Index the day notes of a vault by date, relative to the vault, whatever their type.
+ */
+pub fn build_day_index(vault_path: &Path, notes: &[DatedNote]) -> DayIndex {
+    let mut days = DayIndex::new();
+    for note in notes {
+        days.entry(note.date)
+            .or_default()
+            .push(relative(&note.path, vault_path));
+    }
+
+    days
+}
+
+/**
+ * Get the notes a link target points to, relative to the vault: the notes of a day for a
+ * date, otherwise the notes with this path or this name, ignoring the case. Day notes are
+ * only found by their date, "05" would match every fifth of the month.
+ * i.e. "2026-08-05" gives ["2026/08/2/5/05.work.md"] and "acme" gives ["topics/acme.md"]
+ */
+pub fn resolve_target(days: &DayIndex, notes: &[PathBuf], target: &str) -> Vec<PathBuf> {
+    let mut resolved: Vec<PathBuf> = NaiveDate::parse_from_str(target, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| days.get(&date))
+        .cloned()
+        .unwrap_or_default();
+
+    let target = target.replace('\\', "/").to_lowercase();
+    for note in notes {
+        let path = display_note(note).to_lowercase();
+        let path = path.strip_suffix(".md").unwrap_or(&path);
+        let name = path.rsplit('/').next().unwrap_or(path);
+
+        let matches = if target.contains('/') {
+            path == target
+        } else {
+            name == target && !is_day_note(note)
+        };
+        if matches && !resolved.contains(note) {
+            resolved.push(note.clone());
+        }
+    }
+
+    resolved
+}
+
+/**
+ * Read the links of every markdown file of the vault and resolve them.
+ */
+pub fn build_link_graph(vault: &Vault) -> NostResult<LinkGraph> {
+    let files = find_all_markdown_files(vault.path().to_path_buf()).map_err(|e| {
+        NostError::io(
+            format!("Cannot read the notes in {}", vault.path().display()),
+            e,
+        )
+    })?;
+    let notes: Vec<PathBuf> = files
+        .iter()
+        .map(|path| relative(path, vault.path()))
+        .collect();
+    let days = build_day_index(vault.path(), &vault.dated_notes()?);
+
+    let mut links = Vec::new();
+    for (path, note) in files.iter().zip(&notes) {
+        let content = read_to_string(path)
+            .map_err(|e| NostError::io(format!("Cannot read {}", path.display()), e))?;
+
+        for link in parse_links(&content) {
+            links.push(LinkEdge {
+                source: note.clone(),
+                resolved: resolve_target(&days, &notes, &link.target),
+                link,
+            });
+        }
+    }
+
+    Ok(LinkGraph { notes, links, days })
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/**
+ * Write the graph in the DOT language of Graphviz, broken links point to their target in red.
+ * i.e. `nost links --export dot | dot -Tsvg > links.svg`
+ */
+pub fn format_dot(graph: &LinkGraph) -> String {
+    let mut content = String::from("digraph links {\n  node [shape=box];\n");
+
+    for note in &graph.notes {
+        content.push_str(&format!("  {};\n", quote(&display_note(note))));
+    }
+    for edge in &graph.links {
+        let source = quote(&display_note(&edge.source));
+        if edge.is_broken() {
+            content.push_str(&format!(
+                "  {} -> {} [style=dashed, color=red];\n",
+                source,
+                quote(&edge.link.target)
+            ));
+        }
+        for target in &edge.resolved {
+            content.push_str(&format!(
+                "  {} -> {};\n",
+                source,
+                quote(&display_note(target))
+            ));
+        }
+    }
+
+    content.push_str("}\n");
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::date;
+    use std::fs::{create_dir_all, write};
+    use tempfile::tempdir;
+
+    #[test]
+    fn links_are_resolved_to_days_and_named_notes() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path());
+        let monday = vault.create_note(date(2026, 8, 3), "work").unwrap().path;
+        let tuesday = vault.create_note(date(2026, 8, 4), "default").unwrap().path;
+        create_dir_all(dir.path().join("topics")).unwrap();
+        write(
+            dir.path().join("topics/Acme.md"),
+            "Client, see [[2026-08-04]]\n",
+        )
+        .unwrap();
+        write(
+            &monday,
+            "[[2026-08-04]] with [[acme]], [[missing]] and [[03]]\n",
+        )
+        .unwrap();
+        write(&tuesday, "Back to [[topics/acme|Acme]]\n").unwrap();
+
+        let graph = build_link_graph(&vault).unwrap();
+        let monday = relative(&monday, dir.path());
+        let tuesday = relative(&tuesday, dir.path());
+        let acme = PathBuf::from("topics/Acme.md");
+
+        assert_eq!(graph.notes.len(), 3);
+        let outgoing = graph.outgoing(std::slice::from_ref(&monday));
+        assert_eq!(outgoing.len(), 4);
+        assert_eq!(outgoing[0].resolved, vec![tuesday.clone()]);
+        assert_eq!(outgoing[1].resolved, vec![acme.clone()]);
+
+        let broken: Vec<&str> = graph
+            .broken()
+            .iter()
+            .map(|edge| edge.link.target.as_str())
+            .collect();
+        assert_eq!(broken, vec!["missing", "03"]);
+
+        let backlinks = graph.backlinks(std::slice::from_ref(&acme));
+        let sources: Vec<&PathBuf> = backlinks.iter().map(|edge| &edge.source).collect();
+        assert_eq!(sources, vec![&monday, &tuesday]);
+
+        let dot = format_dot(&graph);
+        assert!(dot.contains(&format!(
+            "\"topics/Acme.md\" -> {};",
+            quote(&display_note(&tuesday))
+        )));
+        assert!(dot.contains("-> \"missing\" [style=dashed, color=red];"));
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// The notes of each day, relative to the vault, so that date links are resolved without
+/// reading the folders of the day again.
+pub type DayIndex = HashMap<NaiveDate, Vec<PathBuf>>;
+
+/// A `[[target#heading|alias]]` link written in a note.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WikiLink {
+    // a day, i.e. "2026-08-05", or a note, i.e. "acme" or "topics/acme"
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    // starting at 1
+    pub line: usize,
+}
+
+/// A link between two notes, with the notes its target resolves to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkEdge {
+    pub source: PathBuf,
+    #[serde(flatten)]
+    pub link: WikiLink,
+    // empty when the link is broken, several notes for a day with typed notes
+    pub resolved: Vec<PathBuf>,
+}
+
+impl LinkEdge {
+    pub fn is_broken(&self) -> bool {
+        self.resolved.is_empty()
+    }
+}
+
+/// The links between the notes of a vault, with paths relative to the vault.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LinkGraph {
+    pub notes: Vec<PathBuf>,
+    pub links: Vec<LinkEdge>,
+    #[serde(skip)]
+    pub days: DayIndex,
+}
+
+impl LinkGraph {
+    // The links written in the given notes
+    pub fn outgoing(&self, notes: &[PathBuf]) -> Vec<&LinkEdge> {
+        self.links
+            .iter()
+            .filter(|edge| notes.contains(&edge.source))
+            .collect()
+    }
+
+    // The links of other notes resolved to the given notes
+    pub fn backlinks(&self, notes: &[PathBuf]) -> Vec<&LinkEdge> {
+        self.links
+            .iter()
+            .filter(|edge| !notes.contains(&edge.source))
+            .filter(|edge| edge.resolved.iter().any(|path| notes.contains(path)))
+            .collect()
+    }
+
+    pub fn broken(&self) -> Vec<&LinkEdge> {
+        self.links.iter().filter(|edge| edge.is_broken()).collect()
+    }
+}
+
+// i.e. "2026/08/1/05/05.work.md", with forward slashes on every platform
+pub fn display_note(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
@@ -0,0 +1,123 @@
+use crate::links::models::WikiLink;
+
+const OPEN: &str = "[[";
+const CLOSE: &str = "]]";
+const FENCE: &str = "```";
+
+// Remove the inline code of a line, i.e. "`[[not a link]]`", keeping the other characters
+fn without_inline_code(line: &str) -> String {
+    let mut kept = String::new();
+    let mut in_code = false;
+    for c in line.chars() {
+        if c == '`' {
+            in_code = !in_code;
+            kept.push(' ');
+        } else if in_code {
+            kept.push(' ');
+        } else {
+            kept.push(c);
+        }
+    }
+
+    kept
+}
+
+// Split the inside of a link, "target#heading|alias"
+pub fn parse_wiki_link(inner: &str, line: usize) -> Option<WikiLink> {
+    let (destination, alias) = match inner.split_once('|') {
+        Some((destination, alias)) => (destination, Some(alias.trim().to_string())),
+        None => (inner, None),
+    };
+    let (target, heading) = match destination.split_once('#') {
+        Some((target, heading)) => (target, Some(heading.trim().to_string())),
+        None => (destination, None),
+    };
+
+    let target = target.trim();
+    if target.is_empty() {
+        return None;
+    }
+
+    Some(WikiLink {
+        target: target.strip_suffix(".md").unwrap_or(target).to_string(),
+        heading: heading.filter(|heading| !heading.is_empty()),
+        alias: alias.filter(|alias| !alias.is_empty()),
+        line,
+    })
+}
+
+/**
+ * Find the wiki links of a note, i.e. `[[2026-08-05]]`, `[[acme|the client]]` or
+ * `[[acme#Contacts]]`. Links in code and embeds like `![[diagram.png]]` are skipped.
+ */
+pub fn parse_links(content: &str) -> Vec<WikiLink> {
+    let mut links = Vec::new();
+    let mut in_fence = false;
+
+    for (index, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with(FENCE) {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let line_content = without_inline_code(line);
+        let mut rest = line_content.as_str();
+        while let Some(start) = rest.find(OPEN) {
+            let is_embed = rest[..start].ends_with('!');
+            let after = &rest[start + OPEN.len()..];
+            let Some(end) = after.find(CLOSE) else {
+                break;
+            };
+
+            if !is_embed {
+                links.extend(parse_wiki_link(&after[..end], index + 1));
+            }
+            rest = &after[end + CLOSE.len()..];
+        }
+    }
+
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_are_parsed_with_their_heading_and_alias() {
+        let content = "# Wednesday
+See [[2026-08-04]] and [[acme#Contacts|the client]].
+![[diagram.png]] [[ ]] [[topics/acme.md]]
+";
+
+        let links = parse_links(content);
+
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[0].target, "2026-08-04");
+        assert_eq!(links[0].line, 2);
+        assert_eq!(links[1].target, "acme");
+        assert_eq!(links[1].heading, Some("Contacts".to_string()));
+        assert_eq!(links[1].alias, Some("the client".to_string()));
+        assert_eq!(links[2].target, "topics/acme");
+        assert_eq!(links[2].line, 3);
+    }
+
+    #[test]
+    fn links_in_code_are_skipped() {
+        let content = "`[[inline]]` [[kept]]
+```
+[[fenced]]
+```
+";
+
+        let targets: Vec<String> = parse_links(content)
+            .into_iter()
+            .map(|link| link.target)
+            .collect();
+
+        assert_eq!(targets, vec!["kept"]);
+    }
+}
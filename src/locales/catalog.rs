@@ -0,0 +1,929 @@
+use crate::{
+    dates::get::get_day_suffix,
+    locales::models::{Locale, Messages, StatsLabels, TuiLabels},
+};
+
+fn ordinal_en(day: u32) -> String {
+    format!("{}{}", day, get_day_suffix(day))
+}
+
+fn ordinal_plain(day: u32) -> String {
+    day.to_string()
+}
+
+fn ordinal_dot(day: u32) -> String {
+    format!("{}.", day)
+}
+
+fn ordinal_first_masculine(day: u32) -> String {
+    match day {
+        1 => "1º".to_string(),
+        _ => day.to_string(),
+    }
+}
+
+pub static EN: Locale = Locale {
+    code: "en",
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    date_header: "{weekday}, {month} {day}, {year}",
+    ordinal: ordinal_en,
+    stats: StatsLabels {
+        day: "Day",
+        date: "Date",
+        hours: "Hours",
+        acc: "Acc",
+        work_days: "Work Days",
+        total: "Total",
+        salary: "Salary",
+        expected: "Expected",
+        delta: "Delta",
+        balance: "Balance",
+        leave: "Leave",
+        day_off: "day off",
+        holiday: "holiday",
+        sick: "sick",
+        half_day: "half day",
+        focus_blocks: "Focus blocks",
+        focus_time: "Focus time",
+    },
+    messages: Messages {
+        missing_argument: "You need to pass at least one argument.",
+        unknown_command: "Unknown command: \"{}\"",
+        file_created: "✅ File created: {}",
+        note_already_exists: "Not already existed.",
+        note_initiated: "✅ New \"not\" has successfully being initiated.",
+        creating_note_today: "Creating new note for today...",
+        note_created: "✅ Note has been created successfully!",
+        open_session_warning:
+            "⚠️  Warning: you should first end the last work session before creating a new note.",
+        work_started: "✅ Work session started.",
+        work_closed: "✅ Work session closed.",
+        invalid_month: "Invalid month format. Please use YYYY-MM.",
+        notes_title: "Notes",
+        week_title: "Week {}",
+        stats_title: "Stats",
+        days_count: "{} day(s)",
+        notes_count: "{} note(s)",
+        heatmap_written: "✅ Heatmap of {} written to {}",
+        status_running_since: "⏱  Work session running since {} ({})",
+        today_note: "Today's note: {}",
+        sync_notes_folder: "Notes folder: {}",
+        sync_autocommit: "Automatic commits: {}",
+        on: "on",
+        off: "off",
+        sync_branch: "Branch: {} ({} ahead, {} behind {})",
+        sync_no_upstream: "Branch: {} (no upstream)",
+        sync_detached: "Branch: detached HEAD",
+        sync_committed: "✅ Everything is committed.",
+        sync_changes: "{} uncommitted change(s):",
+        reorganize_start: "Reorganizing notes with layout \"{}\":",
+        reorganize_move: "  move      {} → {}",
+        reorganize_conflict: "  conflict  {} → {} ({})",
+        reorganize_skipped: "  skipped   {} (cannot infer its date)",
+        reorganize_summary: "{} to move, {} already in place, {} conflict(s), {} skipped.",
+        reorganize_dry_run: "Dry run: nothing has been moved. Run with --apply to move the notes.",
+        nothing_to_move: "Nothing to move.",
+        notes_moved: "✅ {} note(s) moved.",
+        notes_moved_back: "✅ {} note(s) moved back.",
+        reorganize_undo: "To undo: nost reorganize --rollback {}",
+        convert_start: "Converting the metadata of the notes to {}:",
+        convert_note: "  convert   {}",
+        convert_skipped: "  skipped   {} ({})",
+        convert_summary: "{} to convert, {} skipped.",
+        convert_dry_run: "Dry run: nothing has been changed. Run with --apply to convert the notes.",
+        nothing_to_convert: "Nothing to convert.",
+        notes_converted: "✅ {} note(s) converted.",
+        broken_links: "{} broken link(s).",
+        outgoing_links: "Outgoing ({}):",
+        backlinks: "Backlinks ({}):",
+        broken: "broken",
+        builtin_plugins: "Built-in plugins:",
+        external_plugins: "External plugins:",
+        no_external_plugin: "none, add a nost-<name> executable to your PATH to create one",
+        no_stale_session: "No stale work session.",
+        stale_closed: "Work session started {} closed at {} ({}).",
+        stale_still_running: "⚠️  The work session started {} is still running. Set the policy of [work.end_of_day] to \"fixed\" or \"last_activity\" to close it.",
+        leave_recorded: "{} recorded for {}",
+        focus_started: "🎯 Focus for {} min.",
+        focus_left: "⏳ {} left",
+        focus_left_stop: "⏳ {} left, press Enter to stop",
+        focus_done: "✅ Focus block done: {}",
+        focus_stopped: "⏹  Focus block stopped: {}",
+    },
+    tui: TuiLabels {
+        running_since: "● Running since {}",
+        paused: "⏸ Paused",
+        stopped: "■ Stopped",
+        today: "Today",
+        this_week: "This week",
+        this_month: "This month",
+        today_title: "Today · {}",
+        vault: "Vault",
+        session: "Session",
+        month: "Month",
+        session_paused: "Work session paused.",
+        session_resumed: "Work session resumed.",
+        session_already_running: "The work session is already running.",
+        no_session_running: "No work session running.",
+        no_note_today: "No note today, create it with \"nost new\".",
+    },
+};
+
+pub static FR: Locale = Locale {
+    code: "fr",
+    weekdays: [
+        "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche",
+    ],
+    weekdays_short: ["Lun", "Mar", "Mer", "Jeu", "Ven", "Sam", "Dim"],
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    date_header: "{weekday} {day} {month} {year}",
+    ordinal: ordinal_plain,
+    stats: StatsLabels {
+        day: "Jour",
+        date: "Date",
+        hours: "Heures",
+        acc: "Cumul",
+        work_days: "Jours travaillés",
+        total: "Total",
+        salary: "Salaire",
+        expected: "Attendu",
+        delta: "Écart",
+        balance: "Solde",
+        leave: "Absences",
+        day_off: "repos",
+        holiday: "jour férié",
+        sick: "maladie",
+        half_day: "demi-journée",
+        focus_blocks: "Blocs de concentration",
+        focus_time: "Temps de concentration",
+    },
+    messages: Messages {
+        missing_argument: "Vous devez passer au moins un argument.",
+        unknown_command: "Commande inconnue : \"{}\"",
+        file_created: "✅ Fichier créé : {}",
+        note_already_exists: "Le not existe déjà.",
+        note_initiated: "✅ Le nouveau \"not\" a bien été initialisé.",
+        creating_note_today: "Création de la note du jour...",
+        note_created: "✅ La note a bien été créée !",
+        open_session_warning:
+            "⚠️  Attention : vous devriez d'abord terminer la dernière session de travail avant de créer une nouvelle note.",
+        work_started: "✅ Session de travail démarrée.",
+        work_closed: "✅ Session de travail terminée.",
+        invalid_month: "Format de mois invalide. Utilisez AAAA-MM.",
+        notes_title: "Notes",
+        week_title: "Semaine {}",
+        stats_title: "Statistiques",
+        days_count: "{} jour(s)",
+        notes_count: "{} note(s)",
+        heatmap_written: "✅ Carte de chaleur de {} écrite dans {}",
+        status_running_since: "⏱  Session de travail en cours depuis {} ({})",
+        today_note: "Note du jour : {}",
+        sync_notes_folder: "Dossier des notes : {}",
+        sync_autocommit: "Commits automatiques : {}",
+        on: "activés",
+        off: "désactivés",
+        sync_branch: "Branche : {} ({} en avance, {} en retard sur {})",
+        sync_no_upstream: "Branche : {} (pas de branche distante)",
+        sync_detached: "Branche : HEAD détachée",
+        sync_committed: "✅ Tout est commité.",
+        sync_changes: "{} changement(s) non commité(s) :",
+        reorganize_start: "Réorganisation des notes avec la structure \"{}\" :",
+        reorganize_move: "  déplacer  {} → {}",
+        reorganize_conflict: "  conflit   {} → {} ({})",
+        reorganize_skipped: "  ignorée   {} (date introuvable)",
+        reorganize_summary: "{} à déplacer, {} déjà en place, {} conflit(s), {} ignorée(s).",
+        reorganize_dry_run: "Simulation : rien n'a été déplacé. Relancez avec --apply pour déplacer les notes.",
+        nothing_to_move: "Rien à déplacer.",
+        notes_moved: "✅ {} note(s) déplacée(s).",
+        notes_moved_back: "✅ {} note(s) remise(s) en place.",
+        reorganize_undo: "Pour annuler : nost reorganize --rollback {}",
+        convert_start: "Conversion des métadonnées des notes en {} :",
+        convert_note: "  convertir {}",
+        convert_skipped: "  ignorée   {} ({})",
+        convert_summary: "{} à convertir, {} ignorée(s).",
+        convert_dry_run: "Simulation : rien n'a été modifié. Relancez avec --apply pour convertir les notes.",
+        nothing_to_convert: "Rien à convertir.",
+        notes_converted: "✅ {} note(s) convertie(s).",
+        broken_links: "{} lien(s) cassé(s).",
+        outgoing_links: "Sortants ({}) :",
+        backlinks: "Rétroliens ({}) :",
+        broken: "cassé",
+        builtin_plugins: "Plugins intégrés :",
+        external_plugins: "Plugins externes :",
+        no_external_plugin: "aucun, ajoutez un exécutable nost-<nom> à votre PATH pour en créer un",
+        no_stale_session: "Aucune session de travail oubliée.",
+        stale_closed: "Session de travail commencée le {} fermée à {} ({}).",
+        stale_still_running: "⚠️  La session de travail commencée le {} est toujours en cours. Réglez la politique de [work.end_of_day] sur \"fixed\" ou \"last_activity\" pour la fermer.",
+        leave_recorded: "{} enregistré pour le {}",
+        focus_started: "🎯 Concentration pendant {} min.",
+        focus_left: "⏳ {} restantes",
+        focus_left_stop: "⏳ {} restantes, appuyez sur Entrée pour arrêter",
+        focus_done: "✅ Bloc de concentration terminé : {}",
+        focus_stopped: "⏹  Bloc de concentration arrêté : {}",
+    },
+    tui: TuiLabels {
+        running_since: "● En cours depuis {}",
+        paused: "⏸ En pause",
+        stopped: "■ Arrêtée",
+        today: "Aujourd'hui",
+        this_week: "Cette semaine",
+        this_month: "Ce mois-ci",
+        today_title: "Aujourd'hui · {}",
+        vault: "Coffre",
+        session: "Session",
+        month: "Mois",
+        session_paused: "Session de travail en pause.",
+        session_resumed: "Session de travail reprise.",
+        session_already_running: "La session de travail est déjà en cours.",
+        no_session_running: "Aucune session de travail en cours.",
+        no_note_today: "Pas de note aujourd'hui, créez-la avec \"nost new\".",
+    },
+};
+
+pub static DE: Locale = Locale {
+    code: "de",
+    weekdays: [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    weekdays_short: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    date_header: "{weekday}, {day} {month} {year}",
+    ordinal: ordinal_dot,
+    stats: StatsLabels {
+        day: "Tag",
+        date: "Datum",
+        hours: "Stunden",
+        acc: "Summe",
+        work_days: "Arbeitstage",
+        total: "Gesamt",
+        salary: "Gehalt",
+        expected: "Soll",
+        delta: "Differenz",
+        balance: "Saldo",
+        leave: "Abwesenheit",
+        day_off: "freier Tag",
+        holiday: "Feiertag",
+        sick: "krank",
+        half_day: "halber Tag",
+        focus_blocks: "Fokusblöcke",
+        focus_time: "Fokuszeit",
+    },
+    messages: Messages {
+        missing_argument: "Mindestens ein Argument ist erforderlich.",
+        unknown_command: "Unbekannter Befehl: \"{}\"",
+        file_created: "✅ Datei erstellt: {}",
+        note_already_exists: "Die Notiz existiert bereits.",
+        note_initiated: "✅ Neue Notiz wurde erfolgreich angelegt.",
+        creating_note_today: "Notiz für heute wird erstellt...",
+        note_created: "✅ Notiz wurde erfolgreich erstellt!",
+        open_session_warning:
+            "⚠️  Warnung: Beende zuerst die letzte Arbeitssitzung, bevor du eine neue Notiz erstellst.",
+        work_started: "✅ Arbeitssitzung gestartet.",
+        work_closed: "✅ Arbeitssitzung beendet.",
+        invalid_month: "Ungültiges Monatsformat. Bitte JJJJ-MM verwenden.",
+        notes_title: "Notizen",
+        week_title: "Woche {}",
+        stats_title: "Statistiken",
+        days_count: "{} Tag(e)",
+        notes_count: "{} Notiz(en)",
+        heatmap_written: "✅ Heatmap von {} in {} geschrieben",
+        status_running_since: "⏱  Arbeitssitzung läuft seit {} ({})",
+        today_note: "Notiz von heute: {}",
+        sync_notes_folder: "Notizordner: {}",
+        sync_autocommit: "Automatische Commits: {}",
+        on: "an",
+        off: "aus",
+        sync_branch: "Branch: {} ({} voraus, {} hinter {})",
+        sync_no_upstream: "Branch: {} (kein Upstream)",
+        sync_detached: "Branch: losgelöster HEAD",
+        sync_committed: "✅ Alles ist committet.",
+        sync_changes: "{} nicht committete Änderung(en):",
+        reorganize_start: "Notizen werden mit dem Layout \"{}\" neu geordnet:",
+        reorganize_move: "  verschieben {} → {}",
+        reorganize_conflict: "  Konflikt   {} → {} ({})",
+        reorganize_skipped: "  übersprungen {} (Datum nicht erkennbar)",
+        reorganize_summary: "{} zu verschieben, {} bereits am Platz, {} Konflikt(e), {} übersprungen.",
+        reorganize_dry_run: "Probelauf: nichts wurde verschoben. Mit --apply ausführen, um die Notizen zu verschieben.",
+        nothing_to_move: "Nichts zu verschieben.",
+        notes_moved: "✅ {} Notiz(en) verschoben.",
+        notes_moved_back: "✅ {} Notiz(en) zurückverschoben.",
+        reorganize_undo: "Rückgängig machen: nost reorganize --rollback {}",
+        convert_start: "Metadaten der Notizen werden in {} umgewandelt:",
+        convert_note: "  umwandeln {}",
+        convert_skipped: "  übersprungen {} ({})",
+        convert_summary: "{} umzuwandeln, {} übersprungen.",
+        convert_dry_run: "Probelauf: nichts wurde geändert. Mit --apply ausführen, um die Notizen umzuwandeln.",
+        nothing_to_convert: "Nichts umzuwandeln.",
+        notes_converted: "✅ {} Notiz(en) umgewandelt.",
+        broken_links: "{} defekte(r) Link(s).",
+        outgoing_links: "Ausgehend ({}):",
+        backlinks: "Rückverweise ({}):",
+        broken: "defekt",
+        builtin_plugins: "Eingebaute Plugins:",
+        external_plugins: "Externe Plugins:",
+        no_external_plugin: "keine, lege eine ausführbare Datei nost-<name> in deinen PATH, um eines zu erstellen",
+        no_stale_session: "Keine vergessene Arbeitssitzung.",
+        stale_closed: "Arbeitssitzung vom {} um {} geschlossen ({}).",
+        stale_still_running: "⚠️  Die Arbeitssitzung vom {} läuft noch. Setze die Richtlinie von [work.end_of_day] auf \"fixed\" oder \"last_activity\", um sie zu schließen.",
+        leave_recorded: "{} für {} erfasst",
+        focus_started: "🎯 Fokus für {} Min.",
+        focus_left: "⏳ noch {}",
+        focus_left_stop: "⏳ noch {}, Enter zum Beenden drücken",
+        focus_done: "✅ Fokusblock beendet: {}",
+        focus_stopped: "⏹  Fokusblock abgebrochen: {}",
+    },
+    tui: TuiLabels {
+        running_since: "● Läuft seit {}",
+        paused: "⏸ Pausiert",
+        stopped: "■ Gestoppt",
+        today: "Heute",
+        this_week: "Diese Woche",
+        this_month: "Dieser Monat",
+        today_title: "Heute · {}",
+        vault: "Tresor",
+        session: "Sitzung",
+        month: "Monat",
+        session_paused: "Arbeitssitzung pausiert.",
+        session_resumed: "Arbeitssitzung fortgesetzt.",
+        session_already_running: "Die Arbeitssitzung läuft bereits.",
+        no_session_running: "Keine laufende Arbeitssitzung.",
+        no_note_today: "Heute keine Notiz, erstelle sie mit \"nost new\".",
+    },
+};
+
+pub static ES: Locale = Locale {
+    code: "es",
+    weekdays: [
+        "Lunes",
+        "Martes",
+        "Miércoles",
+        "Jueves",
+        "Viernes",
+        "Sábado",
+        "Domingo",
+    ],
+    weekdays_short: ["Lun", "Mar", "Mié", "Jue", "Vie", "Sáb", "Dom"],
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    date_header: "{weekday}, {day} de {month} de {year}",
+    ordinal: ordinal_plain,
+    stats: StatsLabels {
+        day: "Día",
+        date: "Fecha",
+        hours: "Horas",
+        acc: "Acum",
+        work_days: "Días trabajados",
+        total: "Total",
+        salary: "Salario",
+        expected: "Previsto",
+        delta: "Diferencia",
+        balance: "Saldo",
+        leave: "Ausencias",
+        day_off: "día libre",
+        holiday: "festivo",
+        sick: "enfermedad",
+        half_day: "medio día",
+        focus_blocks: "Bloques de concentración",
+        focus_time: "Tiempo de concentración",
+    },
+    messages: Messages {
+        missing_argument: "Debe indicar al menos un argumento.",
+        unknown_command: "Comando desconocido: \"{}\"",
+        file_created: "✅ Archivo creado: {}",
+        note_already_exists: "La nota ya existía.",
+        note_initiated: "✅ La nueva nota se ha iniciado correctamente.",
+        creating_note_today: "Creando la nota de hoy...",
+        note_created: "✅ ¡La nota se ha creado correctamente!",
+        open_session_warning:
+            "⚠️  Aviso: primero debería cerrar la última sesión de trabajo antes de crear una nota nueva.",
+        work_started: "✅ Sesión de trabajo iniciada.",
+        work_closed: "✅ Sesión de trabajo cerrada.",
+        invalid_month: "Formato de mes no válido. Use AAAA-MM.",
+        notes_title: "Notas",
+        week_title: "Semana {}",
+        stats_title: "Estadísticas",
+        days_count: "{} día(s)",
+        notes_count: "{} nota(s)",
+        heatmap_written: "✅ Mapa de calor de {} escrito en {}",
+        status_running_since: "⏱  Sesión de trabajo en curso desde {} ({})",
+        today_note: "Nota de hoy: {}",
+        sync_notes_folder: "Carpeta de notas: {}",
+        sync_autocommit: "Commits automáticos: {}",
+        on: "activados",
+        off: "desactivados",
+        sync_branch: "Rama: {} ({} por delante, {} por detrás de {})",
+        sync_no_upstream: "Rama: {} (sin upstream)",
+        sync_detached: "Rama: HEAD separado",
+        sync_committed: "✅ Todo está confirmado.",
+        sync_changes: "{} cambio(s) sin confirmar:",
+        reorganize_start: "Reorganizando las notas con la estructura \"{}\":",
+        reorganize_move: "  mover     {} → {}",
+        reorganize_conflict: "  conflicto {} → {} ({})",
+        reorganize_skipped: "  omitida   {} (no se puede deducir su fecha)",
+        reorganize_summary: "{} por mover, {} ya en su sitio, {} conflicto(s), {} omitida(s).",
+        reorganize_dry_run: "Simulación: no se ha movido nada. Ejecute con --apply para mover las notas.",
+        nothing_to_move: "Nada que mover.",
+        notes_moved: "✅ {} nota(s) movida(s).",
+        notes_moved_back: "✅ {} nota(s) devuelta(s) a su sitio.",
+        reorganize_undo: "Para deshacer: nost reorganize --rollback {}",
+        convert_start: "Convirtiendo los metadatos de las notas a {}:",
+        convert_note: "  convertir {}",
+        convert_skipped: "  omitida   {} ({})",
+        convert_summary: "{} por convertir, {} omitida(s).",
+        convert_dry_run: "Simulación: no se ha cambiado nada. Ejecute con --apply para convertir las notas.",
+        nothing_to_convert: "Nada que convertir.",
+        notes_converted: "✅ {} nota(s) convertida(s).",
+        broken_links: "{} enlace(s) roto(s).",
+        outgoing_links: "Salientes ({}):",
+        backlinks: "Retroenlaces ({}):",
+        broken: "roto",
+        builtin_plugins: "Plugins integrados:",
+        external_plugins: "Plugins externos:",
+        no_external_plugin: "ninguno, añada un ejecutable nost-<nombre> a su PATH para crear uno",
+        no_stale_session: "Ninguna sesión de trabajo olvidada.",
+        stale_closed: "Sesión de trabajo iniciada el {} cerrada a las {} ({}).",
+        stale_still_running: "⚠️  La sesión de trabajo iniciada el {} sigue en curso. Ajuste la política de [work.end_of_day] a \"fixed\" o \"last_activity\" para cerrarla.",
+        leave_recorded: "{} registrado para el {}",
+        focus_started: "🎯 Concentración durante {} min.",
+        focus_left: "⏳ quedan {}",
+        focus_left_stop: "⏳ quedan {}, pulse Intro para parar",
+        focus_done: "✅ Bloque de concentración terminado: {}",
+        focus_stopped: "⏹  Bloque de concentración detenido: {}",
+    },
+    tui: TuiLabels {
+        running_since: "● En curso desde {}",
+        paused: "⏸ En pausa",
+        stopped: "■ Detenida",
+        today: "Hoy",
+        this_week: "Esta semana",
+        this_month: "Este mes",
+        today_title: "Hoy · {}",
+        vault: "Bóveda",
+        session: "Sesión",
+        month: "Mes",
+        session_paused: "Sesión de trabajo en pausa.",
+        session_resumed: "Sesión de trabajo reanudada.",
+        session_already_running: "La sesión de trabajo ya está en curso.",
+        no_session_running: "Ninguna sesión de trabajo en curso.",
+        no_note_today: "No hay nota hoy, créala con \"nost new\".",
+    },
+};
+
+pub static IT: Locale = Locale {
+    code: "it",
+    weekdays: [
+        "Lunedì",
+        "Martedì",
+        "Mercoledì",
+        "Giovedì",
+        "Venerdì",
+        "Sabato",
+        "Domenica",
+    ],
+    weekdays_short: ["Lun", "Mar", "Mer", "Gio", "Ven", "Sab", "Dom"],
+    months: [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    date_header: "{weekday} {day} {month} {year}",
+    ordinal: ordinal_first_masculine,
+    stats: StatsLabels {
+        day: "Giorno",
+        date: "Data",
+        hours: "Ore",
+        acc: "Cumul",
+        work_days: "Giorni lavorati",
+        total: "Totale",
+        salary: "Stipendio",
+        expected: "Previsto",
+        delta: "Differenza",
+        balance: "Saldo",
+        leave: "Assenze",
+        day_off: "giorno libero",
+        holiday: "festivo",
+        sick: "malattia",
+        half_day: "mezza giornata",
+        focus_blocks: "Blocchi di concentrazione",
+        focus_time: "Tempo di concentrazione",
+    },
+    messages: Messages {
+        missing_argument: "È necessario passare almeno un argomento.",
+        unknown_command: "Comando sconosciuto: \"{}\"",
+        file_created: "✅ File creato: {}",
+        note_already_exists: "La nota esisteva già.",
+        note_initiated: "✅ La nuova nota è stata avviata correttamente.",
+        creating_note_today: "Creazione della nota di oggi...",
+        note_created: "✅ La nota è stata creata correttamente!",
+        open_session_warning:
+            "⚠️  Attenzione: dovresti prima chiudere l'ultima sessione di lavoro prima di creare una nuova nota.",
+        work_started: "✅ Sessione di lavoro avviata.",
+        work_closed: "✅ Sessione di lavoro chiusa.",
+        invalid_month: "Formato del mese non valido. Usa AAAA-MM.",
+        notes_title: "Note",
+        week_title: "Settimana {}",
+        stats_title: "Statistiche",
+        days_count: "{} giorno/i",
+        notes_count: "{} nota/e",
+        heatmap_written: "✅ Mappa di calore del {} scritta in {}",
+        status_running_since: "⏱  Sessione di lavoro in corso dalle {} ({})",
+        today_note: "Nota di oggi: {}",
+        sync_notes_folder: "Cartella delle note: {}",
+        sync_autocommit: "Commit automatici: {}",
+        on: "attivi",
+        off: "disattivi",
+        sync_branch: "Branch: {} ({} avanti, {} indietro rispetto a {})",
+        sync_no_upstream: "Branch: {} (nessun upstream)",
+        sync_detached: "Branch: HEAD scollegato",
+        sync_committed: "✅ È tutto committato.",
+        sync_changes: "{} modifica/he non committata/e:",
+        reorganize_start: "Riorganizzazione delle note con la struttura \"{}\":",
+        reorganize_move: "  sposta    {} → {}",
+        reorganize_conflict: "  conflitto {} → {} ({})",
+        reorganize_skipped: "  saltata   {} (impossibile dedurre la data)",
+        reorganize_summary: "{} da spostare, {} già al loro posto, {} conflitto/i, {} saltata/e.",
+        reorganize_dry_run: "Prova: non è stato spostato nulla. Esegui con --apply per spostare le note.",
+        nothing_to_move: "Niente da spostare.",
+        notes_moved: "✅ {} nota/e spostata/e.",
+        notes_moved_back: "✅ {} nota/e rimessa/e al loro posto.",
+        reorganize_undo: "Per annullare: nost reorganize --rollback {}",
+        convert_start: "Conversione dei metadati delle note in {}:",
+        convert_note: "  converti  {}",
+        convert_skipped: "  saltata   {} ({})",
+        convert_summary: "{} da convertire, {} saltata/e.",
+        convert_dry_run: "Prova: non è stato cambiato nulla. Esegui con --apply per convertire le note.",
+        nothing_to_convert: "Niente da convertire.",
+        notes_converted: "✅ {} nota/e convertita/e.",
+        broken_links: "{} link interrotto/i.",
+        outgoing_links: "In uscita ({}):",
+        backlinks: "Backlink ({}):",
+        broken: "interrotto",
+        builtin_plugins: "Plugin integrati:",
+        external_plugins: "Plugin esterni:",
+        no_external_plugin: "nessuno, aggiungi un eseguibile nost-<nome> al tuo PATH per crearne uno",
+        no_stale_session: "Nessuna sessione di lavoro dimenticata.",
+        stale_closed: "Sessione di lavoro iniziata il {} chiusa alle {} ({}).",
+        stale_still_running: "⚠️  La sessione di lavoro iniziata il {} è ancora in corso. Imposta la politica di [work.end_of_day] su \"fixed\" o \"last_activity\" per chiuderla.",
+        leave_recorded: "{} registrato per il {}",
+        focus_started: "🎯 Concentrazione per {} min.",
+        focus_left: "⏳ mancano {}",
+        focus_left_stop: "⏳ mancano {}, premi Invio per fermare",
+        focus_done: "✅ Blocco di concentrazione terminato: {}",
+        focus_stopped: "⏹  Blocco di concentrazione interrotto: {}",
+    },
+    tui: TuiLabels {
+        running_since: "● In corso dalle {}",
+        paused: "⏸ In pausa",
+        stopped: "■ Fermata",
+        today: "Oggi",
+        this_week: "Questa settimana",
+        this_month: "Questo mese",
+        today_title: "Oggi · {}",
+        vault: "Archivio",
+        session: "Sessione",
+        month: "Mese",
+        session_paused: "Sessione di lavoro in pausa.",
+        session_resumed: "Sessione di lavoro ripresa.",
+        session_already_running: "La sessione di lavoro è già in corso.",
+        no_session_running: "Nessuna sessione di lavoro in corso.",
+        no_note_today: "Nessuna nota oggi, creala con \"nost new\".",
+    },
+};
+
+pub static PT: Locale = Locale {
+    code: "pt",
+    weekdays: [
+        "Segunda-feira",
+        "Terça-feira",
+        "Quarta-feira",
+        "Quinta-feira",
+        "Sexta-feira",
+        "Sábado",
+        "Domingo",
+    ],
+    weekdays_short: ["Seg", "Ter", "Qua", "Qui", "Sex", "Sáb", "Dom"],
+    months: [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ],
+    date_header: "{weekday}, {day} de {month} de {year}",
+    ordinal: ordinal_first_masculine,
+    stats: StatsLabels {
+        day: "Dia",
+        date: "Data",
+        hours: "Horas",
+        acc: "Acum",
+        work_days: "Dias trabalhados",
+        total: "Total",
+        salary: "Salário",
+        expected: "Previsto",
+        delta: "Diferença",
+        balance: "Saldo",
+        leave: "Ausências",
+        day_off: "folga",
+        holiday: "feriado",
+        sick: "doença",
+        half_day: "meio dia",
+        focus_blocks: "Blocos de foco",
+        focus_time: "Tempo de foco",
+    },
+    messages: Messages {
+        missing_argument: "É preciso passar pelo menos um argumento.",
+        unknown_command: "Comando desconhecido: \"{}\"",
+        file_created: "✅ Ficheiro criado: {}",
+        note_already_exists: "A nota já existia.",
+        note_initiated: "✅ A nova nota foi iniciada com sucesso.",
+        creating_note_today: "A criar a nota de hoje...",
+        note_created: "✅ A nota foi criada com sucesso!",
+        open_session_warning:
+            "⚠️  Aviso: deve terminar a última sessão de trabalho antes de criar uma nova nota.",
+        work_started: "✅ Sessão de trabalho iniciada.",
+        work_closed: "✅ Sessão de trabalho terminada.",
+        invalid_month: "Formato de mês inválido. Use AAAA-MM.",
+        notes_title: "Notas",
+        week_title: "Semana {}",
+        stats_title: "Estatísticas",
+        days_count: "{} dia(s)",
+        notes_count: "{} nota(s)",
+        heatmap_written: "✅ Mapa de calor de {} escrito em {}",
+        status_running_since: "⏱  Sessão de trabalho em curso desde {} ({})",
+        today_note: "Nota de hoje: {}",
+        sync_notes_folder: "Pasta das notas: {}",
+        sync_autocommit: "Commits automáticos: {}",
+        on: "ativados",
+        off: "desativados",
+        sync_branch: "Branch: {} ({} à frente, {} atrás de {})",
+        sync_no_upstream: "Branch: {} (sem upstream)",
+        sync_detached: "Branch: HEAD desanexado",
+        sync_committed: "✅ Está tudo confirmado.",
+        sync_changes: "{} alteração(ões) por confirmar:",
+        reorganize_start: "A reorganizar as notas com a estrutura \"{}\":",
+        reorganize_move: "  mover     {} → {}",
+        reorganize_conflict: "  conflito  {} → {} ({})",
+        reorganize_skipped: "  ignorada  {} (não é possível deduzir a data)",
+        reorganize_summary: "{} a mover, {} já no lugar, {} conflito(s), {} ignorada(s).",
+        reorganize_dry_run: "Simulação: nada foi movido. Execute com --apply para mover as notas.",
+        nothing_to_move: "Nada a mover.",
+        notes_moved: "✅ {} nota(s) movida(s).",
+        notes_moved_back: "✅ {} nota(s) reposta(s).",
+        reorganize_undo: "Para desfazer: nost reorganize --rollback {}",
+        convert_start: "A converter os metadados das notas para {}:",
+        convert_note: "  converter {}",
+        convert_skipped: "  ignorada  {} ({})",
+        convert_summary: "{} a converter, {} ignorada(s).",
+        convert_dry_run: "Simulação: nada foi alterado. Execute com --apply para converter as notas.",
+        nothing_to_convert: "Nada a converter.",
+        notes_converted: "✅ {} nota(s) convertida(s).",
+        broken_links: "{} ligação(ões) quebrada(s).",
+        outgoing_links: "De saída ({}):",
+        backlinks: "Retroligações ({}):",
+        broken: "quebrada",
+        builtin_plugins: "Plugins integrados:",
+        external_plugins: "Plugins externos:",
+        no_external_plugin: "nenhum, adicione um executável nost-<nome> ao seu PATH para criar um",
+        no_stale_session: "Nenhuma sessão de trabalho esquecida.",
+        stale_closed: "Sessão de trabalho iniciada em {} fechada às {} ({}).",
+        stale_still_running: "⚠️  A sessão de trabalho iniciada em {} ainda está em curso. Defina a política de [work.end_of_day] como \"fixed\" ou \"last_activity\" para a fechar.",
+        leave_recorded: "{} registado para {}",
+        focus_started: "🎯 Foco durante {} min.",
+        focus_left: "⏳ faltam {}",
+        focus_left_stop: "⏳ faltam {}, prima Enter para parar",
+        focus_done: "✅ Bloco de foco concluído: {}",
+        focus_stopped: "⏹  Bloco de foco interrompido: {}",
+    },
+    tui: TuiLabels {
+        running_since: "● Em curso desde {}",
+        paused: "⏸ Em pausa",
+        stopped: "■ Parada",
+        today: "Hoje",
+        this_week: "Esta semana",
+        this_month: "Este mês",
+        today_title: "Hoje · {}",
+        vault: "Cofre",
+        session: "Sessão",
+        month: "Mês",
+        session_paused: "Sessão de trabalho em pausa.",
+        session_resumed: "Sessão de trabalho retomada.",
+        session_already_running: "A sessão de trabalho já está em curso.",
+        no_session_running: "Nenhuma sessão de trabalho em curso.",
+        no_note_today: "Sem nota hoje, crie-a com \"nost new\".",
+    },
+};
+
+pub static NL: Locale = Locale {
+    code: "nl",
+    weekdays: [
+        "Maandag",
+        "Dinsdag",
+        "Woensdag",
+        "Donderdag",
+        "Vrijdag",
+        "Zaterdag",
+        "Zondag",
+    ],
+    weekdays_short: ["Ma", "Di", "Wo", "Do", "Vr", "Za", "Zo"],
+    months: [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    date_header: "{weekday} {day} {month} {year}",
+    ordinal: ordinal_plain,
+    stats: StatsLabels {
+        day: "Dag",
+        date: "Datum",
+        hours: "Uren",
+        acc: "Totaal",
+        work_days: "Werkdagen",
+        total: "Totaal",
+        salary: "Salaris",
+        expected: "Verwacht",
+        delta: "Verschil",
+        balance: "Saldo",
+        leave: "Afwezigheid",
+        day_off: "vrije dag",
+        holiday: "feestdag",
+        sick: "ziek",
+        half_day: "halve dag",
+        focus_blocks: "Focusblokken",
+        focus_time: "Focustijd",
+    },
+    messages: Messages {
+        missing_argument: "Je moet minstens één argument opgeven.",
+        unknown_command: "Onbekend commando: \"{}\"",
+        file_created: "✅ Bestand aangemaakt: {}",
+        note_already_exists: "De notitie bestond al.",
+        note_initiated: "✅ Nieuwe notitie is succesvol gestart.",
+        creating_note_today: "Notitie voor vandaag wordt aangemaakt...",
+        note_created: "✅ Notitie is succesvol aangemaakt!",
+        open_session_warning:
+            "⚠️  Waarschuwing: sluit eerst de laatste werksessie af voordat je een nieuwe notitie maakt.",
+        work_started: "✅ Werksessie gestart.",
+        work_closed: "✅ Werksessie afgesloten.",
+        invalid_month: "Ongeldig maandformaat. Gebruik JJJJ-MM.",
+        notes_title: "Notities",
+        week_title: "Week {}",
+        stats_title: "Statistieken",
+        days_count: "{} dag(en)",
+        notes_count: "{} notitie(s)",
+        heatmap_written: "✅ Heatmap van {} geschreven naar {}",
+        status_running_since: "⏱  Werksessie bezig sinds {} ({})",
+        today_note: "Notitie van vandaag: {}",
+        sync_notes_folder: "Notitiemap: {}",
+        sync_autocommit: "Automatische commits: {}",
+        on: "aan",
+        off: "uit",
+        sync_branch: "Branch: {} ({} voor, {} achter op {})",
+        sync_no_upstream: "Branch: {} (geen upstream)",
+        sync_detached: "Branch: losgekoppelde HEAD",
+        sync_committed: "✅ Alles is gecommit.",
+        sync_changes: "{} niet-gecommitte wijziging(en):",
+        reorganize_start: "Notities worden herschikt met de indeling \"{}\":",
+        reorganize_move: "  verplaats {} → {}",
+        reorganize_conflict: "  conflict  {} → {} ({})",
+        reorganize_skipped: "  overgeslagen {} (datum niet af te leiden)",
+        reorganize_summary: "{} te verplaatsen, {} al op hun plek, {} conflict(en), {} overgeslagen.",
+        reorganize_dry_run: "Proefrun: er is niets verplaatst. Voer uit met --apply om de notities te verplaatsen.",
+        nothing_to_move: "Niets te verplaatsen.",
+        notes_moved: "✅ {} notitie(s) verplaatst.",
+        notes_moved_back: "✅ {} notitie(s) teruggezet.",
+        reorganize_undo: "Ongedaan maken: nost reorganize --rollback {}",
+        convert_start: "De metadata van de notities wordt omgezet naar {}:",
+        convert_note: "  omzetten  {}",
+        convert_skipped: "  overgeslagen {} ({})",
+        convert_summary: "{} om te zetten, {} overgeslagen.",
+        convert_dry_run: "Proefrun: er is niets gewijzigd. Voer uit met --apply om de notities om te zetten.",
+        nothing_to_convert: "Niets om te zetten.",
+        notes_converted: "✅ {} notitie(s) omgezet.",
+        broken_links: "{} kapotte link(s).",
+        outgoing_links: "Uitgaand ({}):",
+        backlinks: "Terugverwijzingen ({}):",
+        broken: "kapot",
+        builtin_plugins: "Ingebouwde plugins:",
+        external_plugins: "Externe plugins:",
+        no_external_plugin: "geen, voeg een uitvoerbaar bestand nost-<naam> toe aan je PATH om er een te maken",
+        no_stale_session: "Geen vergeten werksessie.",
+        stale_closed: "Werksessie gestart op {} gesloten om {} ({}).",
+        stale_still_running: "⚠️  De werksessie gestart op {} loopt nog. Zet het beleid van [work.end_of_day] op \"fixed\" of \"last_activity\" om ze te sluiten.",
+        leave_recorded: "{} geregistreerd voor {}",
+        focus_started: "🎯 Focus voor {} min.",
+        focus_left: "⏳ nog {}",
+        focus_left_stop: "⏳ nog {}, druk op Enter om te stoppen",
+        focus_done: "✅ Focusblok klaar: {}",
+        focus_stopped: "⏹  Focusblok gestopt: {}",
+    },
+    tui: TuiLabels {
+        running_since: "● Bezig sinds {}",
+        paused: "⏸ Gepauzeerd",
+        stopped: "■ Gestopt",
+        today: "Vandaag",
+        this_week: "Deze week",
+        this_month: "Deze maand",
+        today_title: "Vandaag · {}",
+        vault: "Kluis",
+        session: "Sessie",
+        month: "Maand",
+        session_paused: "Werksessie gepauzeerd.",
+        session_resumed: "Werksessie hervat.",
+        session_already_running: "De werksessie loopt al.",
+        no_session_running: "Geen werksessie bezig.",
+        no_note_today: "Geen notitie vandaag, maak die met \"nost new\".",
+    },
+};
+
+pub static LOCALES: [&Locale; 7] = [&EN, &FR, &DE, &ES, &IT, &PT, &NL];
@@ -0,0 +1,84 @@
+use crate::{
+    configurations::get::get_value_from_config,
+    locales::{
+        catalog::{EN, LOCALES},
+        models::Locale,
+    },
+};
+
+/**
+ * Find the locale matching a language code, i.e. "de", "pt-BR" or "nl_NL".
+ * Unknown languages fall back to English.
+ */
+pub fn find_locale(language: &str) -> &'static Locale {
+    let code = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    match LOCALES.iter().find(|locale| locale.code == code) {
+        Some(locale) => locale,
+        None => {
+            log::debug!("No locale for language '{}', using English.", language);
+            &EN
+        }
+    }
+}
+
+// Get the locale for the configured language, English if none is configured
+pub fn get_current_locale() -> &'static Locale {
+    match get_value_from_config("language") {
+        Ok(language) => find_locale(&language),
+        Err(_) => &EN,
+    }
+}
+
+/**
+ * Fill the `{}` placeholders of a message template, in order.
+ * i.e. fill("File created: {}", &["01.md"]) will return "File created: 01.md".
+ */
+pub fn fill(template: &str, args: &[&str]) -> String {
+    let mut parts = template.split("{}");
+    let mut result = parts.next().unwrap_or_default().to_string();
+
+    for (index, part) in parts.enumerate() {
+        result.push_str(args.get(index).copied().unwrap_or_default());
+        result.push_str(part);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_locale_matches_supported_languages() {
+        for code in ["en", "fr", "de", "es", "it", "pt", "nl"] {
+            assert_eq!(find_locale(code).code, code);
+        }
+    }
+
+    #[test]
+    fn find_locale_ignores_region_and_case() {
+        assert_eq!(find_locale("pt-BR").code, "pt");
+        assert_eq!(find_locale("nl_NL").code, "nl");
+        assert_eq!(find_locale("DE").code, "de");
+    }
+
+    #[test]
+    fn find_locale_falls_back_to_english() {
+        assert_eq!(find_locale("ja").code, "en");
+        assert_eq!(find_locale("").code, "en");
+    }
+
+    #[test]
+    fn fill_replaces_placeholders_in_order() {
+        assert_eq!(fill("{} of {}", &["1", "2"]), "1 of 2");
+        assert_eq!(fill("no placeholder", &["1"]), "no placeholder");
+        assert_eq!(fill("missing {}", &[]), "missing ");
+    }
+}
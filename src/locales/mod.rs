@@ -0,0 +1,3 @@
+pub mod catalog;
+pub mod get;
+pub mod models;
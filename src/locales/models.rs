@@ -0,0 +1,137 @@
+/// Labels used by the stats tables.
+#[derive(Debug)]
+pub struct StatsLabels {
+    pub day: &'static str,
+    pub date: &'static str,
+    pub hours: &'static str,
+    pub acc: &'static str,
+    pub work_days: &'static str,
+    pub total: &'static str,
+    pub salary: &'static str,
+    pub expected: &'static str,
+    pub delta: &'static str,
+    pub balance: &'static str,
+    pub leave: &'static str,
+    // kinds of leave, i.e. "holiday"
+    pub day_off: &'static str,
+    pub holiday: &'static str,
+    pub sick: &'static str,
+    pub half_day: &'static str,
+    pub focus_blocks: &'static str,
+    pub focus_time: &'static str,
+}
+
+/// User-facing command messages. `{}` placeholders are filled in order by
+/// `locales::get::fill`.
+#[derive(Debug)]
+pub struct Messages {
+    pub missing_argument: &'static str,
+    pub unknown_command: &'static str,
+    pub file_created: &'static str,
+    pub note_already_exists: &'static str,
+    pub note_initiated: &'static str,
+    pub creating_note_today: &'static str,
+    pub note_created: &'static str,
+    pub open_session_warning: &'static str,
+    pub work_started: &'static str,
+    pub work_closed: &'static str,
+    pub invalid_month: &'static str,
+    // titles and counts of the html export
+    pub notes_title: &'static str,
+    pub week_title: &'static str,
+    pub stats_title: &'static str,
+    pub days_count: &'static str,
+    pub notes_count: &'static str,
+    pub heatmap_written: &'static str,
+    // nost status
+    pub status_running_since: &'static str,
+    pub today_note: &'static str,
+    // nost sync-status
+    pub sync_notes_folder: &'static str,
+    pub sync_autocommit: &'static str,
+    pub on: &'static str,
+    pub off: &'static str,
+    pub sync_branch: &'static str,
+    pub sync_no_upstream: &'static str,
+    pub sync_detached: &'static str,
+    pub sync_committed: &'static str,
+    pub sync_changes: &'static str,
+    // nost reorganize
+    pub reorganize_start: &'static str,
+    pub reorganize_move: &'static str,
+    pub reorganize_conflict: &'static str,
+    pub reorganize_skipped: &'static str,
+    pub reorganize_summary: &'static str,
+    pub reorganize_dry_run: &'static str,
+    pub nothing_to_move: &'static str,
+    pub notes_moved: &'static str,
+    pub notes_moved_back: &'static str,
+    pub reorganize_undo: &'static str,
+    // nost convert-metadata
+    pub convert_start: &'static str,
+    pub convert_note: &'static str,
+    pub convert_skipped: &'static str,
+    pub convert_summary: &'static str,
+    pub convert_dry_run: &'static str,
+    pub nothing_to_convert: &'static str,
+    pub notes_converted: &'static str,
+    // nost links
+    pub broken_links: &'static str,
+    pub outgoing_links: &'static str,
+    pub backlinks: &'static str,
+    pub broken: &'static str,
+    // nost plugins
+    pub builtin_plugins: &'static str,
+    pub external_plugins: &'static str,
+    pub no_external_plugin: &'static str,
+    // nost work close-stale and nost off
+    pub no_stale_session: &'static str,
+    pub stale_closed: &'static str,
+    pub stale_still_running: &'static str,
+    pub leave_recorded: &'static str,
+    // nost focus
+    pub focus_started: &'static str,
+    pub focus_left: &'static str,
+    pub focus_left_stop: &'static str,
+    pub focus_done: &'static str,
+    pub focus_stopped: &'static str,
+}
+
+/// Labels and messages of `nost tui`, `{}` placeholders are filled by `locales::get::fill`.
+#[derive(Debug)]
+pub struct TuiLabels {
+    pub running_since: &'static str,
+    pub paused: &'static str,
+    pub stopped: &'static str,
+    // totals of the session pane, i.e. "This week"
+    pub today: &'static str,
+    pub this_week: &'static str,
+    pub this_month: &'static str,
+    // titles of the panes
+    pub today_title: &'static str,
+    pub vault: &'static str,
+    pub session: &'static str,
+    pub month: &'static str,
+    // results of the keys
+    pub session_paused: &'static str,
+    pub session_resumed: &'static str,
+    pub session_already_running: &'static str,
+    pub no_session_running: &'static str,
+    pub no_note_today: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Locale {
+    pub code: &'static str,
+    // Monday first, as returned by `num_days_from_monday`
+    pub weekdays: [&'static str; 7],
+    pub weekdays_short: [&'static str; 7],
+    pub months: [&'static str; 12],
+    // Tokens: {weekday}, {day}, {month}, {year}
+    pub date_header: &'static str,
+    // Renders the day of the month, e.g. 1 -> "1st" in English
+    pub ordinal: fn(u32) -> String,
+    pub stats: StatsLabels,
+    pub messages: Messages,
+    pub tui: TuiLabels,
+}
@@ -1,20 +1,91 @@
-mod annotations;
-mod commands;
-mod configurations;
-mod dates;
-mod events;
-mod files;
-mod plugins;
-mod projects;
-mod statistics;
-use crate::commands::new::{new, new_legacy};
-use crate::commands::stats::stats;
-use crate::commands::work::work;
-use crate::plugins::gdarquie_work::commands::end_work::end_work;
-use crate::plugins::gdarquie_work::commands::start_work::start_work;
-use crate::plugins::gdarquie_work::commands::work_stats::work_stats;
 use dotenv::dotenv;
-use std::env;
+use nost::commands::cal::cal;
+use nost::commands::convert_metadata::convert_metadata;
+use nost::commands::export::export;
+use nost::commands::heatmap::heatmap;
+use nost::commands::links::links;
+use nost::commands::new::{new, new_legacy};
+use nost::commands::plugins::plugins;
+use nost::commands::prompt::prompt;
+use nost::commands::reorganize::reorganize;
+use nost::commands::stats::stats;
+use nost::commands::status::status;
+use nost::commands::sync_status::sync_status;
+use nost::commands::tui::tui;
+use nost::configurations::find::set_config_path_override;
+use nost::configurations::get::set_vault_override;
+use nost::errors::models::{NostError, NostResult};
+use nost::locales::get::{fill, get_current_locale};
+use nost::plugins::external::{
+    build_external_plugin_context, find_external_plugin, run_external_plugin,
+};
+use nost::plugins::registry::find_plugin_command;
+use std::{env, path::PathBuf};
+
+// Remove a global option and its value from the arguments, i.e. "--config <path>" or "--config=<path>"
+fn take_global_option(args: &mut Vec<String>, name: &str) -> NostResult<Option<String>> {
+    let prefix = format!("{}=", name);
+
+    if let Some(index) = args.iter().position(|arg| arg.starts_with(&prefix)) {
+        let value = args.remove(index)[prefix.len()..].to_string();
+        return Ok(Some(value));
+    }
+
+    let index = match args.iter().position(|arg| arg == name) {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+    if index + 1 >= args.len() {
+        return Err(NostError::Usage(format!("Missing value for {}.", name)));
+    }
+    args.remove(index);
+    Ok(Some(args.remove(index)))
+}
+
+fn run(mut args: Vec<String>) -> NostResult<()> {
+    if let Some(config_path) = take_global_option(&mut args, "--config")? {
+        set_config_path_override(PathBuf::from(config_path));
+    }
+    if let Some(vault) = take_global_option(&mut args, "--vault")? {
+        set_vault_override(vault);
+    }
+
+    if args.len() <= 1 {
+        return Err(NostError::Usage(
+            get_current_locale().messages.missing_argument.to_string(),
+        ));
+    }
+    match args[1].as_str() {
+        // deprecated
+        "new-legacy" | "nl" => new_legacy(args),
+        "new" | "n" => new(),
+        "stats" | "s" => stats(args),
+        "cal" => cal(args),
+        "heatmap" => heatmap(args),
+        "reorganize" => reorganize(args),
+        "convert-metadata" => convert_metadata(args),
+        "links" => links(args),
+        "export" => export(args),
+        "status" => status(args),
+        "prompt" => prompt(args),
+        "plugins" => plugins(),
+        "sync-status" => sync_status(),
+        "tui" => tui(args),
+        command => {
+            // built-in plugins first, then a nost-<command> executable on the PATH
+            if let Some(plugin_command) = find_plugin_command(command) {
+                return (plugin_command.run)(args);
+            }
+            match find_external_plugin(command) {
+                Some(plugin) => run_external_plugin(&plugin, &build_external_plugin_context(&args)),
+                None => Err(NostError::Usage(fill(
+                    get_current_locale().messages.unknown_command,
+                    &[command],
+                ))),
+            }
+        }
+    }
+}
 
 // todo: give the possibility to create a note for a specific day, eg: 2025-12-31
 // todo: add a version number for nost and for not
@@ -24,30 +95,10 @@ fn main() {
 
     let args: Vec<String> = env::args().collect();
 
-    if args.len() <= 1 {
-        println!("You need to pass at least one argument.");
-        std::process::exit(1);
-    }
-    if args[1] == "new-legacy" || args[1] == "nl" {
-        // deprecated
-        new_legacy(args);
-    } else if args[1] == "new" || args[1] == "n" {
-        new();
-    } else if args[1] == "work" || args[1] == "w" {
-        work();
-    } else if args[1] == "stats" || args[1] == "s" {
-        stats(args);
-    } else if args[1] == "start-work" || args[1] == "sw" {
-        // deprecated
-        start_work(args);
-    } else if args[1] == "end-work" || args[1] == "ew" {
-        // deprecated
-        end_work();
-    } else if args[1] == "work-stats" || args[1] == "ws" {
-        // deprecated
-        work_stats(args);
-    } else {
-        eprintln!("Unknown command: \"{}\"", args[1]);
-        std::process::exit(1);
+    // every error ends here, see NostError for the exit codes
+    if let Err(e) = run(args) {
+        eprintln!("🛑 {}", e);
+        eprintln!("{}", e.hint());
+        std::process::exit(e.exit_code());
     }
 }
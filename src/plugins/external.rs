@@ -0,0 +1,261 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fs::read_dir,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::{
+    configurations::find::find_config_path,
+    errors::models::{NostError, NostResult},
+    locales::get::get_current_locale,
+    plugins::models::{ExternalPlugin, ExternalPluginContext},
+    vaults::vault::Vault,
+};
+
+const EXTERNAL_PLUGIN_PREFIX: &str = "nost-";
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/**
+ * Find the `nost-<name>` executables in the folders of a PATH value. When several folders
+ * contain the same plugin, the first one wins, like the shell does.
+ */
+pub fn find_external_plugins_in(path_value: &OsStr) -> Vec<ExternalPlugin> {
+    let mut plugins: Vec<ExternalPlugin> = Vec::new();
+
+    for folder in env::split_paths(path_value) {
+        let Ok(entries) = read_dir(&folder) else {
+            continue;
+        };
+
+        let mut found: Vec<ExternalPlugin> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                let name = file_name.strip_prefix(EXTERNAL_PLUGIN_PREFIX)?;
+                // i.e. "nost-foo.exe" on Windows
+                let name = Path::new(name).file_stem()?.to_string_lossy().into_owned();
+                if name.is_empty() || !is_executable(&entry.path()) {
+                    return None;
+                }
+                Some(ExternalPlugin {
+                    name,
+                    path: entry.path(),
+                })
+            })
+            .collect();
+        found.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for plugin in found {
+            if !plugins.iter().any(|known| known.name == plugin.name) {
+                plugins.push(plugin);
+            }
+        }
+    }
+
+    plugins
+}
+
+pub fn find_external_plugins() -> Vec<ExternalPlugin> {
+    match env::var_os("PATH") {
+        Some(path_value) => find_external_plugins_in(&path_value),
+        None => Vec::new(),
+    }
+}
+
+pub fn find_external_plugin(name: &str) -> Option<ExternalPlugin> {
+    find_external_plugins()
+        .into_iter()
+        .find(|plugin| plugin.name == name)
+}
+
+// Describe the current vault for an external plugin, without failing when nost is not configured
+pub fn build_external_plugin_context(args: &[String]) -> ExternalPluginContext {
+    let vault = Vault::from_config().ok();
+
+    ExternalPluginContext {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        command: args.get(1).cloned().unwrap_or_default(),
+        args: args.iter().skip(2).cloned().collect(),
+        vault: vault
+            .as_ref()
+            .and_then(|vault| vault.name().map(str::to_string)),
+        vault_path: vault.as_ref().map(|vault| vault.path().to_path_buf()),
+        config_path: find_config_path().ok(),
+        journal_path: vault.as_ref().map(Vault::journal_path),
+        language: match &vault {
+            Some(vault) => vault.locale().code.to_string(),
+            None => get_current_locale().code.to_string(),
+        },
+    }
+}
+
+/**
+ * Run an external plugin with the arguments following its name. The context is given in
+ * NOST_* environment variables and as JSON on the standard input.
+ */
+pub fn run_external_plugin(
+    plugin: &ExternalPlugin,
+    context: &ExternalPluginContext,
+) -> NostResult<()> {
+    let context_json = serde_json::to_string(context)
+        .map_err(|e| NostError::io("Failed to serialize the plugin context", e.into()))?;
+
+    let mut command = Command::new(&plugin.path);
+    command
+        .args(&context.args)
+        .env("NOST_VERSION", &context.version)
+        .env("NOST_LANGUAGE", &context.language)
+        .stdin(Stdio::piped());
+
+    let optional_variables: [(&str, Option<&OsStr>); 4] = [
+        ("NOST_VAULT", context.vault.as_deref().map(OsStr::new)),
+        (
+            "NOST_VAULT_PATH",
+            context.vault_path.as_deref().map(Path::as_os_str),
+        ),
+        (
+            "NOST_CONFIG_PATH",
+            context.config_path.as_deref().map(Path::as_os_str),
+        ),
+        (
+            "NOST_JOURNAL_PATH",
+            context.journal_path.as_deref().map(Path::as_os_str),
+        ),
+    ];
+    for (key, value) in optional_variables {
+        match value {
+            Some(value) => command.env(key, value),
+            None => command.env_remove(key),
+        };
+    }
+
+    log::debug!("Running plugin {}", plugin.path.display());
+    let mut child = command
+        .spawn()
+        .map_err(|e| NostError::io(format!("Cannot run {}", plugin.path.display()), e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // a plugin which does not read its standard input closes it early, this is fine
+        let _ = writeln!(stdin, "{}", context_json);
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| NostError::io(format!("Cannot run {}", plugin.path.display()), e))?;
+
+    if status.success() {
+        return Ok(());
+    }
+
+    Err(NostError::ExternalPlugin {
+        name: plugin.name.clone(),
+        code: status.code(),
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::{fs, os::unix::fs::PermissionsExt, path::PathBuf};
+    use tempfile::tempdir;
+
+    fn create_script(folder: &Path, name: &str, content: &str, mode: u32) -> PathBuf {
+        let path = folder.join(name);
+        fs::write(&path, content).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(mode)).unwrap();
+        path
+    }
+
+    fn context(args: Vec<String>, journal_path: Option<PathBuf>) -> ExternalPluginContext {
+        ExternalPluginContext {
+            version: "0.0.0".to_string(),
+            command: "foo".to_string(),
+            args,
+            vault: Some("personal".to_string()),
+            vault_path: Some(PathBuf::from("/notes")),
+            config_path: None,
+            journal_path,
+            language: "fr".to_string(),
+        }
+    }
+
+    #[test]
+    fn only_executables_with_the_prefix_are_plugins() {
+        let first = tempdir().unwrap();
+        let second = tempdir().unwrap();
+        create_script(first.path(), "nost-foo", "#!/bin/sh\n", 0o755);
+        create_script(first.path(), "nost-notes.txt", "", 0o644);
+        create_script(first.path(), "other", "#!/bin/sh\n", 0o755);
+        create_script(second.path(), "nost-foo", "#!/bin/sh\n", 0o755);
+        create_script(second.path(), "nost-bar", "#!/bin/sh\n", 0o755);
+
+        let path_value = env::join_paths([first.path(), second.path()]).unwrap();
+        let plugins = find_external_plugins_in(&path_value);
+
+        let names: Vec<&str> = plugins.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["foo", "bar"]);
+        // the first folder of the PATH wins
+        assert!(plugins[0].path.starts_with(first.path()));
+    }
+
+    #[test]
+    fn plugin_receives_arguments_environment_and_context() {
+        let dir = tempdir().unwrap();
+        let output = dir.path().join("output.txt");
+        let script = create_script(
+            dir.path(),
+            "nost-foo",
+            &format!(
+                "#!/bin/sh\necho \"$1 $NOST_VAULT $NOST_VAULT_PATH $NOST_LANGUAGE ${{NOST_CONFIG_PATH:-none}}\" > {0}\ncat >> {0}\n",
+                output.display()
+            ),
+            0o755,
+        );
+        let plugin = ExternalPlugin {
+            name: "foo".to_string(),
+            path: script,
+        };
+
+        run_external_plugin(&plugin, &context(vec!["--bar".to_string()], None)).unwrap();
+
+        let content = fs::read_to_string(&output).unwrap();
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("--bar personal /notes fr none"));
+        let json: serde_json::Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(json["command"], "foo");
+        assert_eq!(json["args"][0], "--bar");
+        assert_eq!(json["journal_path"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn failing_plugin_is_an_error() {
+        let dir = tempdir().unwrap();
+        let script = create_script(dir.path(), "nost-foo", "#!/bin/sh\nexit 3\n", 0o755);
+        let plugin = ExternalPlugin {
+            name: "foo".to_string(),
+            path: script,
+        };
+
+        let error = run_external_plugin(&plugin, &context(Vec::new(), None)).unwrap_err();
+        assert!(matches!(
+            error,
+            NostError::ExternalPlugin { code: Some(3), .. }
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    fs::{read_to_string, OpenOptions},
+    io::{stdin, stdout, IsTerminal, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    commands::new::create_today_note,
+    errors::models::{NostError, NostResult},
+    events::models::{Event, EventName},
+    git::commit::{autocommit, commit_message},
+    locales::{get::fill, models::Messages},
+    plugins::focus::models::{DEFAULT_FOCUS_MINUTES, FOCUS_END, FOCUS_START, FOCUS_STOPPED},
+    projects::initialize::initialize_project,
+    vaults::vault::Vault,
+};
+
+const FOCUS_USAGE: &str = "Usage: nost focus [--minutes 25]";
+
+// The line appended to today's note, i.e. "- Focus 09:00-09:25 (25 min)"
+pub fn format_focus_line(start: DateTime<Local>, end: DateTime<Local>) -> String {
+    format!(
+        "- Focus {}-{} ({} min)",
+        start.format("%H:%M"),
+        end.format("%H:%M"),
+        (end - start).num_minutes()
+    )
+}
+
+/**
+ * Show the time left until the end of the block, or until Enter is pressed.
+ * Returns false when the block was stopped early.
+ */
+fn run_countdown(minutes: i64, messages: &Messages) -> NostResult<bool> {
+    let end = Local::now() + chrono::Duration::minutes(minutes);
+
+    // a closed standard input, i.e. in a script, does not stop the block
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut line = String::new();
+        if stdin().read_line(&mut line).is_ok_and(|read| read > 0) {
+            let _ = sender.send(());
+        }
+    });
+
+    loop {
+        let left = (end - Local::now()).num_seconds();
+        if left <= 0 {
+            // spaces clear the end of the longer line of the countdown
+            println!(
+                "\r{}{}",
+                fill(messages.focus_left, &["00:00"]),
+                " ".repeat(30)
+            );
+            return Ok(true);
+        }
+        print!(
+            "\r{} ",
+            fill(
+                messages.focus_left_stop,
+                &[&format!("{:02}:{:02}", left / 60, left % 60)]
+            )
+        );
+        stdout()
+            .flush()
+            .map_err(|e| NostError::io("Cannot show the countdown", e))?;
+
+        if receiver.recv_timeout(Duration::from_secs(1)).is_ok() {
+            // in a terminal, Enter already moved to the next line
+            if !stdin().is_terminal() {
+                println!();
+            }
+            return Ok(false);
+        }
+    }
+}
+
+// Append a line to a note, on a line of its own
+fn append_line(path: &Path, line: &str) -> NostResult<()> {
+    let content = read_to_string(path)
+        .map_err(|e| NostError::io(format!("Cannot read {}", path.display()), e))?;
+    let separator = if content.is_empty() || content.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(path)
+        .map_err(|e| NostError::io(format!("Cannot open {}", path.display()), e))?;
+    writeln!(file, "{}{}", separator, line)
+        .map_err(|e| NostError::io(format!("Cannot write {}", path.display()), e))
+}
+
+/**
+ * Run a focus block: record FOCUS_START, show a countdown, then record FOCUS_END and append
+ * a summary line to today's note. Pressing Enter ends the block early.
+ * usage: nost focus [--minutes 25]
+ */
+pub fn focus(args: Vec<String>) -> NostResult<()> {
+    let mut minutes = DEFAULT_FOCUS_MINUTES;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match (option.as_str(), options.next()) {
+            ("--minutes", Some(value)) => {
+                minutes = value
+                    .parse()
+                    .ok()
+                    .filter(|minutes| *minutes > 0)
+                    .ok_or_else(|| {
+                        NostError::Usage(format!(
+                            "Invalid number of minutes \"{}\". {}",
+                            value, FOCUS_USAGE
+                        ))
+                    })?;
+            }
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". {}",
+                    option, FOCUS_USAGE
+                )));
+            }
+        }
+    }
+
+    initialize_project()?;
+    let vault = Vault::from_config()?;
+    let messages = &vault.locale().messages;
+
+    let start = Local::now();
+    vault.record_event(Event::now(
+        EventName::Plugin(FOCUS_START.to_string()),
+        "focus".to_string(),
+    ))?;
+    println!("{}", fill(messages.focus_started, &[&minutes.to_string()]));
+
+    let completed = run_countdown(minutes, messages)?;
+
+    let end_event = Event {
+        marker: (!completed).then(|| FOCUS_STOPPED.to_string()),
+        ..Event::now(
+            EventName::Plugin(FOCUS_END.to_string()),
+            "focus".to_string(),
+        )
+    };
+    vault.record_event(end_event.clone())?;
+
+    // the summary goes to the first note of today, created when there is none
+    let note_path: PathBuf = match vault.notes_of_day(start.date_naive()).into_iter().next() {
+        Some(path) => path,
+        None => create_today_note(&vault, "default")?.path,
+    };
+    let line = format_focus_line(start, Local::now());
+    append_line(&note_path, &line)?;
+
+    let summary = if completed {
+        messages.focus_done
+    } else {
+        messages.focus_stopped
+    };
+    println!("{}", fill(summary, &[line.trim_start_matches("- ")]));
+
+    autocommit(
+        &vault,
+        &commit_message("focus", &end_event.event, &end_event.datetime),
+        &[note_path],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn focus_line_shows_the_times_and_the_length() {
+        let start = Local.with_ymd_and_hms(2026, 8, 5, 9, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2026, 8, 5, 9, 25, 30).unwrap();
+
+        assert_eq!(
+            format_focus_line(start, end),
+            "- Focus 09:00-09:25 (25 min)"
+        );
+    }
+}
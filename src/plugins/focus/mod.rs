@@ -0,0 +1,4 @@
+pub mod commands;
+pub mod models;
+pub mod plugin;
+pub mod stats;
@@ -0,0 +1,16 @@
+pub const FOCUS_START: &str = "FOCUS_START";
+pub const FOCUS_END: &str = "FOCUS_END";
+// Marker of the FOCUS_END events of a block stopped before its planned length
+pub const FOCUS_STOPPED: &str = "STOPPED";
+
+// length of a focus block without --minutes, the length of a pomodoro
+pub const DEFAULT_FOCUS_MINUTES: i64 = 25;
+
+/// The focus blocks of a month.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FocusStats {
+    // the blocks which lasted their planned length
+    pub blocks: i32,
+    // the time of every block, stopped ones included
+    pub minutes: i32,
+}
@@ -0,0 +1,37 @@
+use crate::{
+    errors::models::NostResult,
+    plugins::{
+        focus::{
+            commands::focus::focus,
+            models::{FOCUS_END, FOCUS_START},
+            stats::focus_stats_section,
+        },
+        models::{Plugin, PluginCommand, StatsContext},
+    },
+};
+
+/// Focus blocks of a few minutes, i.e. pomodoros, recorded with `nost focus`.
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn name(&self) -> &'static str {
+        "focus"
+    }
+
+    fn commands(&self) -> Vec<PluginCommand> {
+        vec![PluginCommand {
+            name: "focus",
+            aliases: &[],
+            description: "run a focus block with a countdown, 25 minutes by default",
+            run: focus,
+        }]
+    }
+
+    fn event_names(&self) -> &'static [&'static str] {
+        &[FOCUS_START, FOCUS_END]
+    }
+
+    fn stats(&self, context: &StatsContext) -> NostResult<Option<String>> {
+        focus_stats_section(context)
+    }
+}
@@ -0,0 +1,143 @@
+use chrono::{DateTime, FixedOffset, Local};
+
+use crate::{
+    errors::models::NostResult,
+    events::models::Event,
+    plugins::{
+        focus::models::{FocusStats, FOCUS_END, FOCUS_START, FOCUS_STOPPED},
+        models::StatsContext,
+    },
+    statistics::print::format_duration,
+};
+
+/**
+ * Compute the focus blocks started during a month ("YYYY-MM") by pairing FOCUS_START and
+ * FOCUS_END events. A block without end, i.e. interrupted with Ctrl-C, is not counted. A block
+ * stopped before its planned length counts in the focus time but not in the blocks.
+ */
+pub fn compute_focus_stats(events: &[Event], month: &str) -> FocusStats {
+    let mut focus_events: Vec<(DateTime<FixedOffset>, &Event)> = events
+        .iter()
+        .filter(|event| event.event == FOCUS_START || event.event == FOCUS_END)
+        .filter_map(|event| {
+            let datetime = DateTime::parse_from_rfc3339(&event.datetime).ok()?;
+            Some((datetime, event))
+        })
+        .collect();
+    focus_events.sort_by_key(|(datetime, _)| *datetime);
+
+    let mut stats = FocusStats::default();
+    let mut start = None;
+    for (datetime, event) in focus_events {
+        match (event.event.as_str(), start) {
+            (FOCUS_START, _) => start = Some(datetime),
+            (_, Some(start_datetime)) => {
+                if start_datetime.format("%Y-%m").to_string() == month {
+                    if event.marker.as_deref() != Some(FOCUS_STOPPED) {
+                        stats.blocks += 1;
+                    }
+                    stats.minutes += (datetime - start_datetime).num_minutes() as i32;
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    stats
+}
+
+// Build the focus section of `nost stats`, None when no focus block was run
+pub fn focus_stats_section(context: &StatsContext) -> NostResult<Option<String>> {
+    let month = match context.month {
+        Some(month) => month.to_string(),
+        None => Local::now().format("%Y-%m").to_string(),
+    };
+
+    let mut stats = FocusStats::default();
+    for vault in context.vaults {
+        let vault_stats = compute_focus_stats(&vault.events()?, &month);
+        stats.blocks += vault_stats.blocks;
+        stats.minutes += vault_stats.minutes;
+    }
+
+    if stats == FocusStats::default() {
+        return Ok(None);
+    }
+
+    let labels = &context.locale.stats;
+    let width = labels
+        .focus_blocks
+        .chars()
+        .count()
+        .max(labels.focus_time.chars().count());
+    Ok(Some(format!(
+        "| {:<width$} | {} |\n| {:<width$} | {} |\n",
+        labels.focus_blocks,
+        stats.blocks,
+        labels.focus_time,
+        format_duration(stats.minutes)
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{locales::get::find_locale, test_helpers::event_at, vaults::vault::Vault};
+    use tempfile::tempdir;
+
+    #[test]
+    fn completed_blocks_of_the_month_are_counted() {
+        let events = vec![
+            event_at(FOCUS_START, "2026-07-31T09:00:00+02:00"),
+            event_at(FOCUS_END, "2026-07-31T09:25:00+02:00"),
+            event_at(FOCUS_START, "2026-08-05T09:00:00+02:00"),
+            event_at(FOCUS_END, "2026-08-05T09:25:00+02:00"),
+            // interrupted
+            event_at(FOCUS_START, "2026-08-05T10:00:00+02:00"),
+            event_at(FOCUS_START, "2026-08-05T11:00:00+02:00"),
+            event_at(FOCUS_END, "2026-08-05T11:10:00+02:00"),
+            // stopped before its planned length
+            event_at(FOCUS_START, "2026-08-05T14:00:00+02:00"),
+            Event {
+                marker: Some(FOCUS_STOPPED.to_string()),
+                ..event_at(FOCUS_END, "2026-08-05T14:05:00+02:00")
+            },
+        ];
+
+        assert_eq!(
+            compute_focus_stats(&events, "2026-08"),
+            FocusStats {
+                blocks: 2,
+                minutes: 40
+            }
+        );
+    }
+
+    #[test]
+    fn focus_labels_are_aligned_on_their_characters() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path());
+        for event in [
+            event_at(FOCUS_START, "2026-08-05T09:00:00+02:00"),
+            event_at(FOCUS_END, "2026-08-05T09:25:00+02:00"),
+        ] {
+            vault.record_event(event).unwrap();
+        }
+        let vaults = [vault];
+
+        let section = focus_stats_section(&StatsContext {
+            vaults: &vaults,
+            month: Some("2026-08"),
+            locale: find_locale("es"),
+        })
+        .unwrap()
+        .unwrap();
+
+        // "Bloques de concentración" has an accent, which takes two bytes
+        assert_eq!(
+            section,
+            "| Bloques de concentración | 1 |\n| Tiempo de concentración  | 0h25 |\n"
+        );
+    }
+}
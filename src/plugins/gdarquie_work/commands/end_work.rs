@@ -2,14 +2,21 @@ use std::path::Path;
 
 use crate::{
     annotations::annotate::annotate,
+    errors::models::NostResult,
     events::models::EventName,
     files::create::create_file,
+    git::commit::{autocommit, commit_message},
     plugins::gdarquie_work::work_annotations::{
-        find::find_last_work_annotation, models::WorkAnnotationWithPath,
+        find::find_last_work_annotation,
+        models::{workday_fields, WorkAnnotationWithPath, WORKDAY_FIELD},
     },
+    vaults::vault::Vault,
 };
 
-fn add_stop_work_annotations(last_annotation: &WorkAnnotationWithPath, path: &Path) {
+fn add_stop_work_annotations(
+    last_annotation: &WorkAnnotationWithPath,
+    path: &Path,
+) -> NostResult<()> {
     // find the workday of the last annotation
     let today = chrono::Local::now()
         .date_naive()
@@ -17,26 +24,26 @@ fn add_stop_work_annotations(last_annotation: &WorkAnnotationWithPath, path: &Pa
         .to_string();
 
     // if the last annoation is not in correct format
-    if last_annotation.annotation.workday.is_none() {
+    if last_annotation.annotation.field(WORKDAY_FIELD).is_none() {
         println!("The last annotation does not have a workday. Repair the annotation before adding a STOP_WORK annotation. The annotation is: {:?}.", last_annotation.annotation);
-        return;
+        return Ok(());
     }
 
     // if this is not today
-    if last_annotation.annotation.workday.as_deref() != Some(&today) {
+    if last_annotation.annotation.field(WORKDAY_FIELD) != Some(today.as_str()) {
         let yesterday = (chrono::Local::now() - chrono::Duration::days(1))
             .date_naive()
             .format("%Y-%m-%d")
             .to_string();
 
         // if it is more than one day, we display a warning message with the last start work annotation and the date and path, etc.: not supposed to work more than one full day without stopping work, we skip it
-        if last_annotation.annotation.workday.as_deref() < Some(&yesterday) {
+        if last_annotation.annotation.field(WORKDAY_FIELD) < Some(yesterday.as_str()) {
             println!("The last annotation is from a previous day ({}), more than one day ago. No STOP_WORK annotation has been added. The annotation is: {:?}.", yesterday, last_annotation.annotation);
-            return;
+            return Ok(());
         }
 
         // if it is yesterday, we add a STOP_WORK annotation for yesterday at 23:59:59 and a START_WORK annotation for today at 00:00:00
-        if last_annotation.annotation.workday.as_deref() == Some(&yesterday) {
+        if last_annotation.annotation.field(WORKDAY_FIELD) == Some(yesterday.as_str()) {
             let yesterday_datetime_string = format!(
                 "{}T23:59:59.999999999{}",
                 yesterday,
@@ -48,9 +55,9 @@ fn add_stop_work_annotations(last_annotation: &WorkAnnotationWithPath, path: &Pa
                 Some(&yesterday_datetime_string),
                 EventName::StopWork,
                 None,
-                last_annotation.path.to_str().unwrap(),
-                last_annotation.annotation.workday.as_deref(),
-            );
+                &last_annotation.path.to_string_lossy(),
+                &workday_fields(last_annotation.annotation.field(WORKDAY_FIELD)),
+            )?;
 
             let today_datetime_string = format!(
                 "{}T00:00:00.000000000{}",
@@ -63,9 +70,9 @@ fn add_stop_work_annotations(last_annotation: &WorkAnnotationWithPath, path: &Pa
                 Some(&today_datetime_string),
                 EventName::StartWork,
                 None,
-                path.to_str().unwrap(),
-                last_annotation.annotation.workday.as_deref(),
-            );
+                &path.to_string_lossy(),
+                &workday_fields(last_annotation.annotation.field(WORKDAY_FIELD)),
+            )?;
         }
     }
 
@@ -74,9 +81,9 @@ fn add_stop_work_annotations(last_annotation: &WorkAnnotationWithPath, path: &Pa
         None,
         EventName::StopWork,
         None,
-        path.to_str().unwrap(),
-        last_annotation.annotation.workday.as_deref(),
-    );
+        &path.to_string_lossy(),
+        &workday_fields(last_annotation.annotation.field(WORKDAY_FIELD)),
+    )
 }
 
 pub fn has_active_session(last_work_annotation: &WorkAnnotationWithPath) -> bool {
@@ -87,16 +94,32 @@ pub fn has_active_session(last_work_annotation: &WorkAnnotationWithPath) -> bool
     false
 }
 
-pub fn end_work() {
-    let last_work_annotation = find_last_work_annotation().unwrap();
-    let path = Path::new(&create_file(None).unwrap()).to_path_buf();
+pub fn end_work() -> NostResult<()> {
+    let last_work_annotation = find_last_work_annotation()?;
+    let path = Path::new(&create_file(None)?).to_path_buf();
 
     // find last active session
-    if !has_active_session(&last_work_annotation) {
-        // return a message, we have nothin to do, there is no active session
-        println!("No working active session has been found. No annotation has been added.");
-        return;
-    }
+    let last_work_annotation = match last_work_annotation {
+        Some(annotation) if has_active_session(&annotation) => annotation,
+        _ => {
+            // return a message, we have nothin to do, there is no active session
+            println!("No working active session has been found. No annotation has been added.");
+            return Ok(());
+        }
+    };
+
+    add_stop_work_annotations(&last_work_annotation, &path)?;
+
+    // the STOP_WORK of a previous day is written in the note of that day
+    autocommit(
+        &Vault::from_config()?,
+        &commit_message(
+            "end-work",
+            &EventName::StopWork.to_string(),
+            &chrono::Local::now().to_rfc3339(),
+        ),
+        &[path, last_work_annotation.path],
+    );
 
-    add_stop_work_annotations(&last_work_annotation, &path);
+    Ok(())
 }
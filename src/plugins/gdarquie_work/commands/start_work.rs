@@ -1,21 +1,42 @@
 use crate::{
-    annotations::annotate::annotate, dates::parse::parse_iso_date, events::models::EventName,
+    annotations::annotate::annotate,
+    dates::parse::parse_iso_date,
+    errors::models::{NostError, NostResult},
+    events::models::EventName,
     files::create::create_file,
+    git::commit::{autocommit, commit_message},
+    plugins::gdarquie_work::work_annotations::models::workday_fields,
+    vaults::vault::Vault,
 };
 
-pub fn start_work(args: Vec<String>) {
-    let not_path = create_file(None).unwrap();
+pub fn start_work(args: Vec<String>) -> NostResult<()> {
+    let not_path = create_file(None)?;
     let default_workday;
     let workday = if args.len() > 2 {
-        if let Err(msg) = parse_iso_date(&args[2]) {
-            eprintln!("{}", msg);
-            std::process::exit(1);
-        }
+        parse_iso_date(&args[2]).map_err(NostError::Usage)?;
         Some(args[2].as_str())
     } else {
         println!("No date provided, using today's date.");
         default_workday = chrono::Local::now().format("%Y-%m-%d").to_string();
         Some(default_workday.as_str())
     };
-    annotate(None, EventName::StartWork, None, &not_path, workday);
+    annotate(
+        None,
+        EventName::StartWork,
+        None,
+        &not_path,
+        &workday_fields(workday),
+    )?;
+
+    autocommit(
+        &Vault::from_config()?,
+        &commit_message(
+            "start-work",
+            &EventName::StartWork.to_string(),
+            &chrono::Local::now().to_rfc3339(),
+        ),
+        &[not_path.into()],
+    );
+
+    Ok(())
 }
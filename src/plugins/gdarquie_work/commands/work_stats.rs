@@ -1,28 +1,23 @@
+use crate::errors::models::{NostError, NostResult};
 use crate::files::append::append;
 use crate::files::create::create_file;
 use crate::plugins::gdarquie_work::work;
 use std::path::PathBuf;
 
-pub fn work_stats(args: Vec<String>) {
+pub fn work_stats(args: Vec<String>) -> NostResult<()> {
     // Optional first arg is month in format YYYY-MM
     let month = if args.len() > 2 {
         let m = args[2].as_str();
         if !is_valid_year_month(m) {
-            eprintln!("Invalid month format. Please use YYYY-MM.");
-            std::process::exit(1);
+            return Err(NostError::Usage(
+                "Invalid month format. Please use YYYY-MM.".to_string(),
+            ));
         }
         Some(m)
     } else {
         None
     };
-    let stats = match work::compute_monthly_work_stats(month) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("💥 Cannot compute stats for the current month:\"{}\".", e);
-            eprintln!("Is there an existing note for this month?");
-            std::process::exit(1);
-        }
-    };
+    let stats = work::compute_monthly_work_stats(month)?;
 
     // Optional second flag to append stats in current note: true/1/yes/y
     let in_not = if args.len() > 3 {
@@ -34,12 +29,15 @@ pub fn work_stats(args: Vec<String>) {
     let stats_content = work::compose_monthly_work_stats(stats);
 
     if in_not {
-        let file_path = create_file(None).unwrap();
-        let _ = append(PathBuf::from(file_path), &stats_content);
+        let file_path = create_file(None)?;
+        append(PathBuf::from(&file_path), &stats_content)
+            .map_err(|e| NostError::io(format!("Failed to write {}", file_path), e))?;
         println!("Stats appended to the current not.");
     } else {
         println!("{}", stats_content);
     }
+
+    Ok(())
 }
 
 // Validate a string as year-month in format YYYY-MM (01..12)
@@ -1,3 +1,4 @@
 pub mod commands;
+pub mod plugin;
 pub mod work;
 pub mod work_annotations;
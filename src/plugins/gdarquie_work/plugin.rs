@@ -0,0 +1,47 @@
+use crate::plugins::{
+    gdarquie_work::{
+        commands::{end_work::end_work, start_work::start_work, work_stats::work_stats},
+        work_annotations::models::WORKDAY_FIELD,
+    },
+    models::{Plugin, PluginCommand},
+};
+
+/// Work sessions tracked with annotations in the notes (deprecated, see the work plugin).
+pub struct GdarquieWorkPlugin;
+
+impl Plugin for GdarquieWorkPlugin {
+    fn name(&self) -> &'static str {
+        "gdarquie_work"
+    }
+
+    fn commands(&self) -> Vec<PluginCommand> {
+        vec![
+            PluginCommand {
+                name: "start-work",
+                aliases: &["sw"],
+                description: "(deprecated) annotate the start of a work session",
+                run: start_work,
+            },
+            PluginCommand {
+                name: "end-work",
+                aliases: &["ew"],
+                description: "(deprecated) annotate the end of a work session",
+                run: |_| end_work(),
+            },
+            PluginCommand {
+                name: "work-stats",
+                aliases: &["ws"],
+                description: "(deprecated) work stats computed from annotations",
+                run: work_stats,
+            },
+        ]
+    }
+
+    fn event_names(&self) -> &'static [&'static str] {
+        &["START_WORK", "STOP_WORK"]
+    }
+
+    fn annotation_fields(&self) -> &'static [&'static str] {
+        &[WORKDAY_FIELD]
+    }
+}
@@ -1,9 +1,11 @@
 use crate::annotations::extract::extract_annotations_from_path;
 use crate::annotations::filter::filter_annotation_by_events;
 use crate::annotations::models::Annotation;
-use crate::configurations::get::get_value_from_config;
+use crate::configurations::get::get_not_path;
+use crate::errors::models::{NostError, NostResult};
 use crate::events::models::EventName;
 use crate::files::build_paths::build_file_path_for_month;
+use crate::plugins::gdarquie_work::work_annotations::models::WORKDAY_FIELD;
 use chrono::Datelike;
 use chrono::Local;
 use std::collections::HashMap;
@@ -58,20 +60,19 @@ pub fn compute_work_time_from_annotations(annotations: &Vec<Annotation>) -> i32
     total_time_in_minutes
 }
 
-pub fn compute_monthly_work_stats(month: Option<&str>) -> Result<MonthlyWorkStats, std::io::Error> {
+pub fn compute_monthly_work_stats(month: Option<&str>) -> NostResult<MonthlyWorkStats> {
     // get all annotations from not path
-    let not_path = get_value_from_config("not_path").unwrap();
+    let not_path = get_not_path()?;
 
     // convert month string to chrono::NaiveDate
     let date = match month {
         Some(m) => match chrono::NaiveDate::parse_from_str(&format!("{}-01", m), "%Y-%m-%d") {
             Ok(d) => d,
             Err(e) => {
-                eprintln!("💥 Invalid month format. Please use YYYY-MM. Error: {}", e);
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::InvalidInput,
-                    "Invalid month format",
-                ));
+                return Err(NostError::Usage(format!(
+                    "Invalid month format. Please use YYYY-MM. Error: {}",
+                    e
+                )));
             }
         },
         None => Local::now().date_naive(),
@@ -97,8 +98,8 @@ pub fn compute_monthly_work_stats(month: Option<&str>) -> Result<MonthlyWorkStat
     // group annotations by workday
     for annotation in work_annotations {
         let workday = annotation
-            .workday
-            .clone()
+            .field(WORKDAY_FIELD)
+            .map(str::to_string)
             .unwrap_or_else(|| annotation.datetime.format("%Y-%m-%d").to_string());
         annotations_hmap
             .entry(workday)
@@ -122,7 +123,10 @@ pub fn compute_monthly_work_stats(month: Option<&str>) -> Result<MonthlyWorkStat
     for (day, annotation) in annotations_hmap.iter() {
         let length_in_minutes = compute_work_time_from_annotations(annotation);
 
-        let date = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d").unwrap();
+        let date = match chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+            Ok(d) => d,
+            Err(_) => continue,
+        };
         let week_id = WeekId {
             year: date.iso_week().year(),
             week: date.iso_week().week(),
@@ -243,14 +247,18 @@ mod tests {
             _uid: Uuid::new_v4(),
             event: EventName::StartWork,
             datetime: start,
-            workday: None,
+            datetime_text: None,
+            fields: Default::default(),
+            keys: Vec::new(),
         };
 
         let stop_annotation = Annotation {
             _uid: Uuid::new_v4(),
             event: EventName::StopWork,
             datetime: stop,
-            workday: None,
+            datetime_text: None,
+            fields: Default::default(),
+            keys: Vec::new(),
         };
         let annotations = vec![start_annotation, stop_annotation];
         assert_eq!(compute_work_time_from_annotations(&annotations), 60);
@@ -261,7 +269,9 @@ mod tests {
             _uid: Uuid::new_v4(),
             event,
             datetime,
-            workday: None,
+            datetime_text: None,
+            fields: Default::default(),
+            keys: Vec::new(),
         }
     }
 
@@ -281,7 +291,10 @@ mod tests {
         for (day, annotation) in annotations_hmap.iter() {
             let length_in_minutes = compute_work_time_from_annotations(annotation);
 
-            let date = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d").unwrap();
+            let date = match chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+                Ok(d) => d,
+                Err(_) => continue,
+            };
             let week_id = WeekId {
                 year: date.iso_week().year(),
                 week: date.iso_week().week(),
@@ -1,21 +1,17 @@
 use crate::annotations::extract::extract_annotations_from_path;
 use crate::annotations::filter::filter_annotation_by_events;
-use crate::configurations::get::get_value_from_config;
+use crate::configurations::get::get_not_path;
+use crate::errors::models::{NostError, NostResult};
 use crate::events::models::EventName;
 use crate::files::find::find_all_not_files;
 use crate::plugins::gdarquie_work::work_annotations::models::WorkAnnotationWithPath;
 
-pub fn find_last_work_annotation() -> Option<WorkAnnotationWithPath> {
-    let not_path = match get_value_from_config("not_path") {
-        Ok(path) => path,
-        Err(_) => return None,
-    };
+pub fn find_last_work_annotation() -> NostResult<Option<WorkAnnotationWithPath>> {
+    let not_path = get_not_path()?;
 
     // Get all not files sorted
-    let mut files = match find_all_not_files(not_path.into()) {
-        Ok(file) => file,
-        Err(_) => return None,
-    };
+    let mut files = find_all_not_files(not_path.clone().into())
+        .map_err(|e| NostError::io(format!("Cannot read the notes in {}", not_path), e))?;
 
     // Process files in reverse (last first) to minimize iterations
     while let Some(path) = files.pop() {
@@ -31,13 +27,12 @@ pub fn find_last_work_annotation() -> Option<WorkAnnotationWithPath> {
                 // Sort by datetime to get the most recent annotation
                 work_annotations.sort_by_key(|a| a.datetime);
 
-                return Some(WorkAnnotationWithPath {
-                    annotation: work_annotations.last().cloned().unwrap(),
-                    path,
-                });
+                if let Some(annotation) = work_annotations.last().cloned() {
+                    return Ok(Some(WorkAnnotationWithPath { annotation, path }));
+                }
             }
         }
     }
 
-    None
+    Ok(None)
 }
@@ -1,8 +1,18 @@
 use std::path::PathBuf;
 
-use crate::annotations::models::Annotation;
+use crate::annotations::models::{Annotation, AnnotationValue};
 
 pub struct WorkAnnotationWithPath {
     pub annotation: Annotation,
     pub path: PathBuf,
 }
+
+// annotation field of the day a work session belongs to, i.e. "2025-09-29"
+pub const WORKDAY_FIELD: &str = "workday";
+
+// Get the annotation fields for a workday, none if there is no workday
+pub fn workday_fields(workday: Option<&str>) -> Vec<(&'static str, AnnotationValue)> {
+    workday
+        .map(|workday| vec![(WORKDAY_FIELD, AnnotationValue::Text(workday.to_string()))])
+        .unwrap_or_default()
+}
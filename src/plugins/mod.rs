@@ -1 +1,6 @@
+pub mod external;
+pub mod focus;
 pub mod gdarquie_work;
+pub mod models;
+pub mod registry;
+pub mod work;
@@ -0,0 +1,71 @@
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::{errors::models::NostResult, locales::models::Locale, vaults::vault::Vault};
+
+/// A subcommand added by a plugin, i.e. `nost work` or its alias `nost w`.
+pub struct PluginCommand {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+    // receives every argument, the binary name and the subcommand included
+    pub run: fn(Vec<String>) -> NostResult<()>,
+}
+
+/// What `nost stats` asks to each plugin.
+pub struct StatsContext<'a> {
+    // the selected vault, or every configured vault with --all-vaults
+    pub vaults: &'a [Vault],
+    // "YYYY-MM", the current month if None
+    pub month: Option<&'a str>,
+    pub locale: &'static Locale,
+}
+
+/**
+ * A domain of nost (work sessions, expenses, habits...) with its own subcommands, event names,
+ * annotation fields and stats. Every method has a default, so a plugin only implements what
+ * it adds. Plugins are listed in `plugins::registry::PLUGINS`.
+ */
+pub trait Plugin: Sync {
+    fn name(&self) -> &'static str;
+
+    fn commands(&self) -> Vec<PluginCommand> {
+        Vec::new()
+    }
+
+    // names of the events recorded in the journal or in annotations, i.e. "START_WORK"
+    fn event_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    // fields read from annotations besides date, event and uid, i.e. "workday"
+    fn annotation_fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    // a section of `nost stats`, None when the plugin has nothing to show
+    fn stats(&self, _context: &StatsContext) -> NostResult<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// A `nost-<name>` executable found on the PATH, run by `nost <name>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalPlugin {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// The JSON written on the standard input of an external plugin.
+/// Paths are missing when no notes folder or configuration file is found.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalPluginContext {
+    pub version: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub vault: Option<String>,
+    pub vault_path: Option<PathBuf>,
+    pub config_path: Option<PathBuf>,
+    pub journal_path: Option<PathBuf>,
+    pub language: String,
+}
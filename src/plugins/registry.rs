@@ -0,0 +1,56 @@
+use crate::plugins::{
+    focus::plugin::FocusPlugin,
+    gdarquie_work::plugin::GdarquieWorkPlugin,
+    models::{Plugin, PluginCommand},
+    work::plugin::WorkPlugin,
+};
+
+/// Every built-in plugin, in the order of their `nost stats` sections.
+pub static PLUGINS: [&dyn Plugin; 3] = [&WorkPlugin, &FocusPlugin, &GdarquieWorkPlugin];
+
+// Find the plugin command called by its name or one of its aliases
+pub fn find_plugin_command(name: &str) -> Option<PluginCommand> {
+    PLUGINS
+        .iter()
+        .flat_map(|plugin| plugin.commands())
+        .find(|command| command.name == name || command.aliases.contains(&name))
+}
+
+// Check that an event name is declared by a plugin
+pub fn is_plugin_event(name: &str) -> bool {
+    PLUGINS
+        .iter()
+        .any(|plugin| plugin.event_names().contains(&name))
+}
+
+// Get the annotation fields declared by the plugins, without duplicates
+pub fn get_annotation_fields() -> Vec<&'static str> {
+    let mut fields: Vec<&'static str> = Vec::new();
+    for field in PLUGINS.iter().flat_map(|plugin| plugin.annotation_fields()) {
+        if !fields.contains(field) {
+            fields.push(field);
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_are_found_by_name_and_alias() {
+        assert_eq!(find_plugin_command("work").unwrap().name, "work");
+        assert_eq!(find_plugin_command("w").unwrap().name, "work");
+        assert_eq!(find_plugin_command("ew").unwrap().name, "end-work");
+        assert!(find_plugin_command("new").is_none());
+    }
+
+    #[test]
+    fn plugin_events_and_fields_are_registered() {
+        assert!(is_plugin_event("START_WORK"));
+        assert!(is_plugin_event("FOCUS_START"));
+        assert!(!is_plugin_event("UNKNOWN"));
+        assert_eq!(get_annotation_fields(), vec!["workday"]);
+    }
+}
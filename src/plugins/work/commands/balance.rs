@@ -0,0 +1,75 @@
+use chrono::{Local, NaiveDate};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    events::models::EventName,
+    statistics::targets::compute_balance,
+    vaults::vault::Vault,
+};
+
+fn parse_month(month: &str) -> NostResult<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+        .map_err(|_| NostError::Usage(format!("Invalid month \"{}\", use YYYY-MM", month)))
+}
+
+/**
+ * Show the expected and worked hours of each month, with the overtime (or undertime)
+ * balance carried from month to month. Starts with the first work session by default.
+ * usage: nost balance [--from YYYY-MM] [--to YYYY-MM]
+ */
+pub fn balance(args: Vec<String>) -> NostResult<()> {
+    let today = Local::now().date_naive();
+    let mut from: Option<NaiveDate> = None;
+    let mut to = today;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match (option.as_str(), options.next()) {
+            ("--from", Some(month)) => from = Some(parse_month(month)?),
+            ("--to", Some(month)) => to = parse_month(month)?,
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". Usage: nost balance [--from YYYY-MM] [--to YYYY-MM]",
+                    option
+                )));
+            }
+        }
+    }
+
+    let vault = Vault::from_config()?;
+    let targets = vault.targets();
+    if targets.is_empty() {
+        return Err(NostError::InvalidConfig(
+            "No work targets configured, add a [work.targets] section to compute a balance"
+                .to_string(),
+        ));
+    }
+
+    let events = vault.events()?;
+    let from = match from {
+        Some(from) => from,
+        None => events
+            .iter()
+            .find(|event| event.event == EventName::StartWork.to_string())
+            .and_then(|event| NaiveDate::parse_from_str(&event.day, "%Y-%m-%d").ok())
+            .unwrap_or(today),
+    };
+
+    let labels = &vault.locale().stats;
+    println!(
+        "| {:<7} | {:>8} | {:>8} | {:>8} | {:>8} |",
+        labels.date, labels.expected, labels.total, labels.delta, labels.balance
+    );
+    for month in compute_balance(targets, &events, from, to, today) {
+        println!(
+            "| {:<7} | {:>8.2} | {:>8.2} | {:>+8.2} | {:>+8.2} |",
+            month.month,
+            month.expected_minutes as f32 / 60.0,
+            month.actual_minutes as f32 / 60.0,
+            month.delta_minutes as f32 / 60.0,
+            month.balance_minutes as f32 / 60.0
+        );
+    }
+
+    Ok(())
+}
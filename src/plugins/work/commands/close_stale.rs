@@ -0,0 +1,67 @@
+use chrono::{DateTime, FixedOffset, Local};
+
+use crate::{
+    configurations::get::get_work_end_of_day,
+    errors::models::NostResult,
+    events::models::{Event, EventName, AUTO_CLOSED},
+    git::commit::{autocommit, commit_message},
+    locales::get::fill,
+    plugins::work::stale::find_stale_session,
+    vaults::vault::Vault,
+};
+
+// Record the end of a forgotten session, marked as AUTO_CLOSED
+pub fn record_auto_close(vault: &Vault, close_at: DateTime<FixedOffset>) -> NostResult<Event> {
+    let event = Event {
+        marker: Some(AUTO_CLOSED.to_string()),
+        ..Event::at(EventName::StopWork, "work".to_string(), close_at)
+    };
+    vault.record_event(event.clone())?;
+
+    autocommit(
+        vault,
+        &commit_message("work close-stale", &event.event, &event.datetime),
+        &[],
+    );
+
+    Ok(event)
+}
+
+/**
+ * Close the work session left open after its day, following the end-of-day policy of
+ * `[work.end_of_day]`. With the default warn policy, the session is only reported.
+ * usage: nost work close-stale
+ */
+pub fn close_stale() -> NostResult<()> {
+    let vault = Vault::from_config()?;
+    let events = vault.events()?;
+
+    let Some(stale) =
+        find_stale_session(&get_work_end_of_day(), &events, Local::now().fixed_offset())?
+    else {
+        println!("{}", vault.locale().messages.no_stale_session);
+        return Ok(());
+    };
+
+    let messages = &vault.locale().messages;
+    let start = stale.start.format("%Y-%m-%d %H:%M").to_string();
+    match stale.close_at {
+        Some(close_at) => {
+            record_auto_close(&vault, close_at)?;
+            println!(
+                "{}",
+                fill(
+                    messages.stale_closed,
+                    &[
+                        &start,
+                        &close_at.format("%Y-%m-%d %H:%M").to_string(),
+                        AUTO_CLOSED
+                    ]
+                )
+            );
+        }
+        None => eprintln!("{}", fill(messages.stale_still_running, &[&start])),
+    }
+
+    Ok(())
+}
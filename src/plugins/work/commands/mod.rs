@@ -0,0 +1,4 @@
+pub mod balance;
+pub mod close_stale;
+pub mod off;
+pub mod work;
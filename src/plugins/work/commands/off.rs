@@ -0,0 +1,67 @@
+use chrono::{Local, NaiveDate};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    events::models::{Event, EventName},
+    git::commit::{autocommit, commit_message},
+    locales::get::fill,
+    projects::initialize::initialize_project,
+    statistics::print::leave_label,
+    vaults::vault::Vault,
+};
+
+const OFF_USAGE: &str = "Usage: nost off [YYYY-MM-DD] [--kind day_off|holiday|sick|half_day]";
+
+/**
+ * Record a day without work, today by default. Leave days lower the expected hours of the
+ * stats and the balance, and paid leave kinds are counted in the salary.
+ * usage: nost off [YYYY-MM-DD] [--kind day_off|holiday|sick|half_day]
+ * i.e. nost off 2026-08-14 --kind holiday
+ */
+pub fn off(args: Vec<String>) -> NostResult<()> {
+    let mut day = Local::now().date_naive();
+    let mut kind = EventName::DayOff;
+
+    let mut options = args.iter().skip(2);
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--kind" => {
+                let value = options.next().map(String::as_str).unwrap_or_default();
+                kind = EventName::from_leave_kind(value).ok_or_else(|| {
+                    NostError::Usage(format!("Unknown leave kind: \"{}\". {}", value, OFF_USAGE))
+                })?;
+            }
+            date if !date.starts_with("--") => {
+                day = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                    NostError::Usage(format!("Invalid date \"{}\". {}", date, OFF_USAGE))
+                })?;
+            }
+            _ => {
+                return Err(NostError::Usage(format!(
+                    "Unknown option: \"{}\". {}",
+                    option, OFF_USAGE
+                )));
+            }
+        }
+    }
+
+    initialize_project()?;
+    let vault = Vault::from_config()?;
+
+    let locale = vault.locale();
+    let label = leave_label(&locale.stats, &kind);
+    let event = Event::for_day(kind, "work".to_string(), day);
+    vault.record_event(event.clone())?;
+    println!(
+        "{}",
+        fill(locale.messages.leave_recorded, &[label, &event.day])
+    );
+
+    autocommit(
+        &vault,
+        &commit_message("off", &event.event, &event.datetime),
+        &[],
+    );
+
+    Ok(())
+}
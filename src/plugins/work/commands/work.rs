@@ -0,0 +1,247 @@
+use std::io::{stdin, stdout, IsTerminal, Write};
+
+use chrono::{DateTime, FixedOffset, Local};
+
+use crate::{
+    commands::new::create_today_note,
+    configurations::get::get_work_end_of_day,
+    errors::models::{NostError, NostResult},
+    events::models::{Event, EventName},
+    git::commit::{autocommit, commit_message},
+    plugins::work::{
+        commands::close_stale::{close_stale, record_auto_close},
+        stale::compute_close_time,
+    },
+    projects::initialize::initialize_project,
+    statistics::print::format_duration,
+    vaults::vault::Vault,
+};
+
+// `nost work` asks before closing a session older than this, see `[work.end_of_day]`
+const DEFAULT_ASK_AFTER_HOURS: f32 = 12.0;
+
+/// What to do with a session running for too long, answered by the user.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StaleChoice {
+    // close it now, as for any session
+    CloseNow,
+    // close it with the end-of-day policy, then start a new session
+    AutoClose,
+    Cancel,
+}
+
+// Anything else than a known answer cancels, i.e. an empty line
+pub fn parse_stale_choice(answer: &str, can_auto_close: bool) -> StaleChoice {
+    match answer.trim().to_lowercase().as_str() {
+        "n" | "now" => StaleChoice::CloseNow,
+        "e" | "end" if can_auto_close => StaleChoice::AutoClose,
+        _ => StaleChoice::Cancel,
+    }
+}
+
+// Ask what to do with a session running for too long. Without terminal to answer in, i.e. in a
+// script, nothing is recorded and the user is told how to close the session.
+fn ask_stale_choice(
+    start: DateTime<FixedOffset>,
+    close_at: Option<DateTime<FixedOffset>>,
+    interactive: bool,
+) -> NostResult<StaleChoice> {
+    if !interactive {
+        let advice = match close_at {
+            Some(_) => "run \"nost work close-stale\" to close it with the end-of-day policy",
+            None => "run \"nost work\" in a terminal to close it",
+        };
+        return Err(NostError::Usage(format!(
+            "The work session started {} is still running, {}.",
+            start.format("%Y-%m-%d %H:%M"),
+            advice
+        )));
+    }
+
+    let elapsed = (Local::now().fixed_offset() - start).num_minutes() as i32;
+    eprintln!(
+        "⚠️  The work session started {} is running for {}.",
+        start.format("%Y-%m-%d %H:%M"),
+        format_duration(elapsed)
+    );
+    match close_at {
+        Some(close_at) => print!(
+            "Close it now [n], at {} with the end-of-day policy and start a new session [e], or cancel [c]? ",
+            close_at.format("%Y-%m-%d %H:%M")
+        ),
+        None => print!("Close it now [n] or cancel [c]? "),
+    }
+    stdout()
+        .flush()
+        .map_err(|e| NostError::io("Cannot write the question", e))?;
+
+    let mut answer = String::new();
+    stdin()
+        .read_line(&mut answer)
+        .map_err(|e| NostError::io("Cannot read the answer", e))?;
+
+    Ok(parse_stale_choice(&answer, close_at.is_some()))
+}
+
+/// Pure function: decides which work event to record next based on the most
+/// recent work event. No I/O; easy to unit-test.
+///
+/// Returns the `EventName` to record: `StartWork` to open a session,
+/// `StopWork` to close the current one.
+pub fn determine_next_work_event(last_event: Option<&Event>) -> EventName {
+    match last_event {
+        // Last event was a start → close the current session
+        Some(e) if e.event == EventName::StartWork.to_string() => EventName::StopWork,
+        // No previous event, a previous stop, or any non-work event → start a
+        // fresh session
+        _ => EventName::StartWork,
+    }
+}
+
+/**
+ * Start or stop a work session. A session older than `ask_after_hours` is not closed
+ * without asking, since it was most likely forgotten.
+ * usage: nost work [close-stale]
+ */
+pub fn work(args: Vec<String>) -> NostResult<()> {
+    match args.get(2).map(String::as_str) {
+        Some("close-stale") => return close_stale(),
+        Some(option) => {
+            return Err(NostError::Usage(format!(
+                "Unknown option: \"{}\". Usage: nost work [close-stale]",
+                option
+            )));
+        }
+        None => {}
+    }
+
+    initialize_project()?;
+    let vault = Vault::from_config()?;
+
+    // Read journal.json to determine the current session state.
+    let last_event = vault.last_work_event()?;
+
+    let mut event_name = determine_next_work_event(last_event.as_ref());
+
+    let session_start = last_event
+        .as_ref()
+        .filter(|_| event_name == EventName::StopWork)
+        .and_then(|event| DateTime::parse_from_rfc3339(&event.datetime).ok());
+    if let Some(start) = session_start {
+        let end_of_day = get_work_end_of_day();
+        let now = Local::now().fixed_offset();
+        let ask_after_minutes = end_of_day
+            .ask_after_hours
+            .unwrap_or(DEFAULT_ASK_AFTER_HOURS)
+            * 60.0;
+
+        if (now - start).num_minutes() as f32 > ask_after_minutes {
+            let close_at = compute_close_time(&end_of_day, &vault.events()?, start)?
+                .filter(|close_at| *close_at < now);
+
+            match (
+                ask_stale_choice(start, close_at, stdin().is_terminal())?,
+                close_at,
+            ) {
+                (StaleChoice::AutoClose, Some(close_at)) => {
+                    record_auto_close(&vault, close_at)?;
+                    event_name = EventName::StartWork;
+                }
+                (StaleChoice::CloseNow, _) => {}
+                _ => {
+                    println!("Nothing recorded.");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Create (or reuse) today's work file using the configured layout:
+    // <not_path>/<year>/<month>/<week>/<day>/<DD>.work.md
+    let note = create_today_note(&vault, "work")?;
+
+    let locale = vault.locale();
+    let event = Event::now(event_name.clone(), "work".to_string());
+    vault.record_event(event.clone())?;
+
+    match event_name {
+        EventName::StartWork => println!("{}", locale.messages.work_started),
+        EventName::StopWork => println!("{}", locale.messages.work_closed),
+        _ => unreachable!("determine_next_work_event only returns StartWork or StopWork"),
+    }
+
+    autocommit(
+        &vault,
+        &commit_message("work", &event.event, &event.datetime),
+        &[note.path],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::models::{Event, EventName};
+
+    /// Helper: build a minimal Event with a given name.
+    fn make_event(event_name: EventName) -> Event {
+        Event::now(event_name, "work".to_string())
+    }
+
+    #[test]
+    fn test_determine_next_work_event_no_previous_event() {
+        // No journal entry at all → should start
+        assert_eq!(determine_next_work_event(None), EventName::StartWork);
+    }
+
+    #[test]
+    fn test_determine_next_work_event_after_stop_work() {
+        // Last event is STOP_WORK → should start a new session
+        let event = make_event(EventName::StopWork);
+        assert_eq!(
+            determine_next_work_event(Some(&event)),
+            EventName::StartWork
+        );
+    }
+
+    #[test]
+    fn test_determine_next_work_event_after_start_work() {
+        // Last event is START_WORK → should close the current session
+        let event = make_event(EventName::StartWork);
+        assert_eq!(determine_next_work_event(Some(&event)), EventName::StopWork);
+    }
+
+    #[test]
+    fn test_parse_stale_choice() {
+        assert_eq!(parse_stale_choice("n\n", true), StaleChoice::CloseNow);
+        assert_eq!(parse_stale_choice("E", true), StaleChoice::AutoClose);
+        // no end-of-day time to close it at
+        assert_eq!(parse_stale_choice("e", false), StaleChoice::Cancel);
+        assert_eq!(parse_stale_choice("", true), StaleChoice::Cancel);
+    }
+
+    #[test]
+    fn test_stale_choice_is_not_asked_without_terminal() {
+        let start = DateTime::parse_from_rfc3339("2026-08-05T09:00:00+02:00").unwrap();
+        let close_at = DateTime::parse_from_rfc3339("2026-08-05T18:00:00+02:00").unwrap();
+
+        match ask_stale_choice(start, Some(close_at), false) {
+            Err(NostError::Usage(message)) => {
+                assert!(message.contains("2026-08-05 09:00"));
+                assert!(message.contains("nost work close-stale"));
+            }
+            other => panic!("expected a usage error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_determine_next_work_event_after_create_not() {
+        // A non-work event (e.g. CreateNot) → start a fresh session
+        let event = make_event(EventName::CreateNot);
+        assert_eq!(
+            determine_next_work_event(Some(&event)),
+            EventName::StartWork
+        );
+    }
+}
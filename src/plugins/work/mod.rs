@@ -0,0 +1,4 @@
+pub mod commands;
+pub mod plugin;
+pub mod stale;
+pub mod stats;
@@ -0,0 +1,57 @@
+use crate::{
+    errors::models::NostResult,
+    plugins::{
+        models::{Plugin, PluginCommand, StatsContext},
+        work::{
+            commands::{balance::balance, off::off, work::work},
+            stats::work_stats_section,
+        },
+    },
+};
+
+/// Work sessions recorded in the journal with `nost work`.
+pub struct WorkPlugin;
+
+impl Plugin for WorkPlugin {
+    fn name(&self) -> &'static str {
+        "work"
+    }
+
+    fn commands(&self) -> Vec<PluginCommand> {
+        vec![
+            PluginCommand {
+                name: "work",
+                aliases: &["w"],
+                description: "start or stop a work session, close-stale closes a forgotten one",
+                run: work,
+            },
+            PluginCommand {
+                name: "balance",
+                aliases: &[],
+                description: "expected and worked hours per month, with the running balance",
+                run: balance,
+            },
+            PluginCommand {
+                name: "off",
+                aliases: &[],
+                description: "record a day off, a holiday, a sick day or a half day",
+                run: off,
+            },
+        ]
+    }
+
+    fn event_names(&self) -> &'static [&'static str] {
+        &[
+            "START_WORK",
+            "STOP_WORK",
+            "DAY_OFF",
+            "HOLIDAY",
+            "SICK",
+            "HALF_DAY",
+        ]
+    }
+
+    fn stats(&self, context: &StatsContext) -> NostResult<Option<String>> {
+        work_stats_section(context)
+    }
+}
@@ -0,0 +1,184 @@
+use chrono::{DateTime, FixedOffset, NaiveTime};
+
+use crate::{
+    configurations::models::{EndOfDay, EndOfDayPolicy},
+    errors::models::{NostError, NostResult},
+    events::models::{Event, EventName},
+};
+
+/// A work session still running after the day it started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleSession {
+    pub start: DateTime<FixedOffset>,
+    // when the end-of-day policy closes it, None with the warn policy
+    pub close_at: Option<DateTime<FixedOffset>>,
+}
+
+// Get the start of the running session, opened by the last work event of the journal
+pub fn find_running_session(events: &[Event]) -> Option<DateTime<FixedOffset>> {
+    events
+        .iter()
+        .rev()
+        .find(|event| {
+            event.event == EventName::StartWork.to_string()
+                || event.event == EventName::StopWork.to_string()
+        })
+        .filter(|event| event.event == EventName::StartWork.to_string())
+        .and_then(|event| DateTime::parse_from_rfc3339(&event.datetime).ok())
+}
+
+/**
+ * Get when the end-of-day policy closes a session started at `start`, None with the warn
+ * policy. The fixed policy closes it at the configured time of its day, or at the end of the
+ * day when it started later. The last activity policy closes it at the last event recorded
+ * the day it started, or at its start when nothing was recorded after it.
+ * i.e. 18:00 for a session started at 09:00 with `policy="fixed"` and `time="18:00"`
+ */
+pub fn compute_close_time(
+    end_of_day: &EndOfDay,
+    events: &[Event],
+    start: DateTime<FixedOffset>,
+) -> NostResult<Option<DateTime<FixedOffset>>> {
+    match end_of_day.policy {
+        EndOfDayPolicy::Warn => Ok(None),
+        EndOfDayPolicy::Fixed => {
+            let time = end_of_day.time.as_deref().unwrap_or_default();
+            let time = NaiveTime::parse_from_str(time, "%H:%M").map_err(|_| {
+                NostError::InvalidConfig(format!(
+                    "Invalid work.end_of_day.time \"{}\", the fixed policy needs a time like \"18:00\"",
+                    time
+                ))
+            })?;
+            let close_time = if time > start.time() {
+                time
+            } else {
+                let last_minute = NaiveTime::from_hms_opt(23, 59, 0).unwrap_or_default();
+                last_minute.max(start.time())
+            };
+
+            Ok(start
+                .date_naive()
+                .and_time(close_time)
+                .and_local_timezone(*start.offset())
+                .single())
+        }
+        EndOfDayPolicy::LastActivity => Ok(Some(
+            events
+                .iter()
+                .filter_map(|event| DateTime::parse_from_rfc3339(&event.datetime).ok())
+                .map(|datetime| datetime.with_timezone(start.offset()))
+                .filter(|datetime| *datetime > start && datetime.date_naive() == start.date_naive())
+                .max()
+                .unwrap_or(start),
+        )),
+    }
+}
+
+/**
+ * Find the running session when the day it started is over, with when the end-of-day policy
+ * closes it.
+ */
+pub fn find_stale_session(
+    end_of_day: &EndOfDay,
+    events: &[Event],
+    now: DateTime<FixedOffset>,
+) -> NostResult<Option<StaleSession>> {
+    let Some(start) = find_running_session(events) else {
+        return Ok(None);
+    };
+    if now.with_timezone(start.offset()).date_naive() <= start.date_naive() {
+        return Ok(None);
+    }
+
+    Ok(Some(StaleSession {
+        start,
+        close_at: compute_close_time(end_of_day, events, start)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{at, event_at};
+
+    fn end_of_day(policy: EndOfDayPolicy, time: Option<&str>) -> EndOfDay {
+        EndOfDay {
+            policy,
+            time: time.map(str::to_string),
+            ask_after_hours: None,
+        }
+    }
+
+    #[test]
+    fn session_of_today_is_not_stale() {
+        let events = vec![event_at(EventName::StartWork, "2026-08-05T09:00:00+02:00")];
+        let policy = end_of_day(EndOfDayPolicy::Fixed, Some("18:00"));
+
+        let stale = find_stale_session(&policy, &events, at("2026-08-05T22:00:00+02:00")).unwrap();
+
+        assert_eq!(stale, None);
+    }
+
+    #[test]
+    fn fixed_policy_closes_at_the_configured_time() {
+        let events = vec![event_at(EventName::StartWork, "2026-08-05T09:00:00+02:00")];
+        let now = at("2026-08-06T08:00:00+02:00");
+
+        let stale = find_stale_session(
+            &end_of_day(EndOfDayPolicy::Fixed, Some("18:00")),
+            &events,
+            now,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(stale.close_at, Some(at("2026-08-05T18:00:00+02:00")));
+
+        // started after the closing time, closed at the end of its day
+        let events = vec![event_at(EventName::StartWork, "2026-08-05T20:00:00+02:00")];
+        let stale = find_stale_session(
+            &end_of_day(EndOfDayPolicy::Fixed, Some("18:00")),
+            &events,
+            now,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(stale.close_at, Some(at("2026-08-05T23:59:00+02:00")));
+
+        assert!(matches!(
+            find_stale_session(&end_of_day(EndOfDayPolicy::Fixed, None), &events, now),
+            Err(NostError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn last_activity_policy_closes_at_the_last_event_of_the_day() {
+        let mut events = vec![
+            event_at(EventName::StartWork, "2026-08-05T09:00:00+02:00"),
+            event_at(EventName::CreateNot, "2026-08-05T16:30:00+02:00"),
+        ];
+        let now = at("2026-08-06T08:00:00+02:00");
+        let policy = end_of_day(EndOfDayPolicy::LastActivity, None);
+
+        let stale = find_stale_session(&policy, &events, now).unwrap().unwrap();
+        assert_eq!(stale.close_at, Some(at("2026-08-05T16:30:00+02:00")));
+
+        // the activity of the next day does not count
+        events.remove(1);
+        events.push(event_at(EventName::CreateNot, "2026-08-06T07:50:00+02:00"));
+        let stale = find_stale_session(&policy, &events, now).unwrap().unwrap();
+        assert_eq!(stale.close_at, Some(stale.start));
+    }
+
+    #[test]
+    fn warn_policy_does_not_close_and_closed_sessions_are_not_stale() {
+        let mut events = vec![event_at(EventName::StartWork, "2026-08-05T09:00:00+02:00")];
+        let now = at("2026-08-06T08:00:00+02:00");
+        let policy = EndOfDay::default();
+
+        let stale = find_stale_session(&policy, &events, now).unwrap().unwrap();
+        assert_eq!(stale.close_at, None);
+
+        events.push(event_at(EventName::StopWork, "2026-08-05T18:00:00+02:00"));
+        assert_eq!(find_stale_session(&policy, &events, now).unwrap(), None);
+    }
+}
@@ -0,0 +1,161 @@
+use chrono::{Local, NaiveDate};
+use std::collections::BTreeMap;
+
+use crate::{
+    configurations::get::get_work_currency,
+    errors::models::{NostError, NostResult},
+    plugins::models::StatsContext,
+    statistics::{
+        compute::merge_month_stats,
+        models::MonthExpectation,
+        print::{compute_salary, print_stats},
+        targets::compute_month_expectation,
+    },
+};
+
+/**
+ * Build the work section of `nost stats` from the journals of the vaults.
+ * With several vaults, a line is printed per vault and the stats are merged. Each vault is paid
+ * with its own salary, the salaries are added up per currency, and is expected to last its own
+ * targets.
+ */
+pub fn work_stats_section(context: &StatsContext) -> NostResult<Option<String>> {
+    let mut all_stats = Vec::new();
+    let mut salaries: BTreeMap<String, f32> = BTreeMap::new();
+    let mut section = String::new();
+
+    // the hours expected up to today, from the targets of each vault that has some
+    let today = Local::now().date_naive();
+    let month = match context.month {
+        Some(month) => NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+            .map_err(|_| NostError::Usage(format!("Invalid month \"{}\", use YYYY-MM", month)))?,
+        None => today,
+    };
+    let mut expectation: Option<MonthExpectation> = None;
+
+    for vault in context.vaults {
+        let stats = vault.month_stats(context.month)?;
+        if !vault.targets().is_empty() {
+            let vault_expectation =
+                compute_month_expectation(vault.targets(), month, today, &stats.leave_days);
+            let expectation = expectation.get_or_insert_with(MonthExpectation::default);
+            expectation.expected_minutes += vault_expectation.expected_minutes;
+            for (week_id, minutes) in vault_expectation.expected_by_week {
+                *expectation.expected_by_week.entry(week_id).or_default() += minutes;
+            }
+        }
+        *salaries.entry(get_work_currency(vault.pay())).or_default() +=
+            compute_salary(&stats, vault.pay());
+        if context.vaults.len() > 1 {
+            section.push_str(&format!(
+                "{}: {:.2} h, {} day(s)\n",
+                vault.name().unwrap_or("-"),
+                stats.total_duration_in_minutes as f32 / 60.0,
+                stats.total_work_days
+            ));
+        }
+        all_stats.push(stats);
+    }
+
+    let stats = merge_month_stats(all_stats);
+    log::debug!(
+        "Computed work stats for month {:?}: total_duration_in_minutes={:?}, stats={:?}",
+        context.month,
+        stats.total_duration_in_minutes,
+        stats
+    );
+
+    section.push_str(&print_stats(
+        stats,
+        context.locale,
+        expectation.as_ref(),
+        &salaries,
+    ));
+    Ok(Some(section))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        configurations::models::WorkTargets,
+        events::models::{Event, EventName},
+        locales::catalog::EN,
+        statistics::models::Pay,
+        vaults::vault::Vault,
+    };
+    use chrono::DateTime;
+    use tempfile::tempdir;
+
+    fn worked_and_holiday(vault: &Vault) {
+        for (event_name, datetime) in [
+            (EventName::StartWork, "2026-08-05T09:00:00+00:00"),
+            (EventName::StopWork, "2026-08-05T17:00:00+00:00"),
+            (EventName::Holiday, "2026-08-06T09:00:00+00:00"),
+        ] {
+            let datetime = DateTime::parse_from_rfc3339(datetime).unwrap();
+            vault
+                .record_event(Event::at(event_name, "work".to_string(), datetime))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn each_vault_is_paid_with_its_own_settings() {
+        let (personal_dir, acme_dir) = (tempdir().unwrap(), tempdir().unwrap());
+        let personal = Vault::open(personal_dir.path())
+            .with_name("personal")
+            .with_pay(Pay {
+                salary: Some(100.0),
+                currency: Some("EUR".to_string()),
+                paid_leave: vec![EventName::Holiday],
+            });
+        let acme = Vault::open(acme_dir.path())
+            .with_name("acme")
+            .with_pay(Pay {
+                salary: Some(50.0),
+                currency: Some("USD".to_string()),
+                paid_leave: Vec::new(),
+            });
+        worked_and_holiday(&personal);
+        worked_and_holiday(&acme);
+
+        let vaults = [personal, acme];
+        let section = work_stats_section(&StatsContext {
+            vaults: &vaults,
+            month: Some("2026-08"),
+            locale: &EN,
+        })
+        .unwrap()
+        .unwrap();
+
+        // the holiday is only paid in the personal vault
+        assert!(section.contains("| Salary    | 200.00 EUR + 50.00 USD |"));
+    }
+
+    #[test]
+    fn each_vault_is_expected_to_last_its_own_targets() {
+        let (personal_dir, acme_dir) = (tempdir().unwrap(), tempdir().unwrap());
+        let personal = Vault::open(personal_dir.path())
+            .with_name("personal")
+            .with_targets(WorkTargets {
+                week: Some(40.0),
+                ..WorkTargets::default()
+            });
+        let acme = Vault::open(acme_dir.path()).with_name("acme");
+        worked_and_holiday(&personal);
+        worked_and_holiday(&acme);
+
+        let vaults = [personal, acme];
+        let section = work_stats_section(&StatsContext {
+            vaults: &vaults,
+            month: Some("2026-08"),
+            locale: &EN,
+        })
+        .unwrap()
+        .unwrap();
+
+        // 21 weekdays in August 2026, less the holiday, and nothing expected in the acme vault
+        assert!(section.contains("| Expected  | 160.00 |"));
+    }
+}
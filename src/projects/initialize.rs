@@ -1,14 +1,20 @@
 use serde_json::{json, Value};
-use std::{env, fs::create_dir_all, path::Path};
+use std::{fs::create_dir_all, path::Path};
 
-use crate::dates::get::get_now_as_string;
+use crate::{
+    configurations::get::get_not_path,
+    dates::get::get_now_as_string,
+    errors::models::{NostError, NostResult},
+};
 
-pub fn get_project_config_path() -> String {
+pub fn get_project_config_path() -> NostResult<String> {
     // compose configuration path and create configuration folder
-    let not_path = env::var("NOT_PATH").unwrap_or_else(|_| {
-        eprintln!("NOT_PATH environment variable not set.");
-        std::process::exit(1);
-    });
+    let not_path = get_not_path()?;
+    Ok(get_project_config_path_for(&not_path))
+}
+
+// Get the configuration folder of a notes folder, i.e. "<not_path>/.nost/"
+pub fn get_project_config_path_for(not_path: &str) -> String {
     format!("{}/{}/", not_path, ".nost")
 }
 
@@ -16,46 +22,45 @@ pub fn get_project_config_path() -> String {
  * Checks if the configuration file `project.json` exists
  * in the configuration directory in root/.nost
  */
-pub fn is_project_initialized() -> bool {
-    let not_path = env::var("NOT_PATH").unwrap_or_else(|_| {
-        eprintln!("NOT_PATH environment variable not set.");
-        std::process::exit(1);
-    });
+pub fn is_project_initialized() -> NostResult<bool> {
+    let not_path = get_not_path()?;
 
     let project_config_path = format!("{}/.nost/project.json", not_path);
     log::debug!(
         "Checking if configuration exists at path: {}",
         project_config_path
     );
-    Path::new(&project_config_path).is_file()
+    Ok(Path::new(&project_config_path).is_file())
 }
 
 /**
  * Create a project.json file in the not path/.nost folder
  * if it does not exist, or update the last_updated timestamp if it does.
  */
-pub fn initialize_project() -> Result<String, Box<dyn std::error::Error>> {
+pub fn initialize_project() -> NostResult<String> {
+    let configuration_path = get_project_config_path()?;
+    let default_config_path = format!("{}{}", configuration_path, "project.json");
+
     // check if there is an existing configuration folder with a file
-    if is_project_initialized() {
+    if is_project_initialized()? {
         // if this file exists update the file
-        let project_config_path: String = get_project_config_path();
-        let config_content = std::fs::read_to_string(&project_config_path)?;
-        let mut config: Value = serde_json::from_str(&config_content)?;
+        let config_content = std::fs::read_to_string(&default_config_path)
+            .map_err(|e| NostError::io(format!("Cannot read {}", default_config_path), e))?;
+        let mut config: Value =
+            serde_json::from_str(&config_content).map_err(|e| NostError::CorruptJournal {
+                path: default_config_path.clone(),
+                reason: e.to_string(),
+            })?;
 
         config["last_updated"] = json!(get_now_as_string());
-        let config_file = std::fs::File::create(&project_config_path)?;
-        serde_json::to_writer_pretty(config_file, &config)?;
+        write_project_config(&default_config_path, &config)?;
 
         return Ok(String::from("Configuration already exists."));
     }
 
     log::debug!("No configuration found. Initializing configuration...");
-    let configuration_path = get_project_config_path();
-    create_dir_all(&configuration_path)?;
-
-    // create en empty configuration file
-    let default_config_path = format!("{}{}", configuration_path, "project.json");
-    let config_file = std::fs::File::create(&default_config_path)?;
+    create_dir_all(&configuration_path)
+        .map_err(|e| NostError::io(format!("Cannot create {}", configuration_path), e))?;
     log::debug!("Configuration initialized at path: {}", configuration_path);
 
     // append inital content to the configuration file
@@ -69,9 +74,16 @@ pub fn initialize_project() -> Result<String, Box<dyn std::error::Error>> {
         "version": NOST_VERSION,
     });
 
-    serde_json::to_writer_pretty(config_file, &initial_content)?;
+    write_project_config(&default_config_path, &initial_content)?;
 
     Ok(String::from(
         "Configuration has been initialized successfully!",
     ))
 }
+
+fn write_project_config(path: &str, config: &Value) -> NostResult<()> {
+    let config_file = std::fs::File::create(path)
+        .map_err(|e| NostError::io(format!("Cannot write {}", path), e))?;
+    serde_json::to_writer_pretty(config_file, config)
+        .map_err(|e| NostError::io(format!("Cannot write {}", path), e.into()))
+}
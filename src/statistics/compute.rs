@@ -1,56 +1,71 @@
 use crate::{
-    events::models::{Event, EventName},
+    errors::models::{NostError, NostResult},
+    events::{
+        find::{find_all_events, get_journal_file_path},
+        models::{Event, EventName},
+    },
     projects::initialize::get_project_config_path,
-    statistics::models::{MonthStats, Stats, WeekId, WeekStats},
+    statistics::models::{LeaveDay, MonthStats, Stats, WeekId, WeekStats},
 };
 use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate};
 use log::debug;
-use std::{
-    collections::{HashMap, HashSet},
-    fs::File,
-    io::BufReader,
-    path::Path,
-};
-
-fn load_events() -> Result<Vec<Event>, std::io::Error> {
-    let config_path = get_project_config_path();
-    let journal_file_path = format!("{}/journal.json", config_path);
-
-    // if there is no journal file yet, return an empty vec
-    if !Path::new(&journal_file_path).exists() {
-        return Ok(Vec::new());
-    }
+use std::collections::{HashMap, HashSet};
 
-    let file = File::open(&journal_file_path)?;
-    let reader = BufReader::new(file);
-    let events: Vec<Event> = serde_json::from_reader(reader).map_err(|e| {
-        std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            format!("Invalid JSON in journal file: {}", e),
-        )
-    })?;
-
-    Ok(events)
+fn is_leave_event(event: &Event) -> bool {
+    event
+        .event
+        .parse::<EventName>()
+        .is_ok_and(|event_name| event_name.leave_fraction().is_some())
 }
 
-fn filter_month_events(month: &str, events: Vec<Event>) -> Vec<Event> {
+// Keep the work sessions and the leave days of a month
+pub fn filter_month_events(month: &str, events: Vec<Event>) -> Vec<Event> {
     events
         .into_iter()
         .filter(|event| {
             event.day.starts_with(month)
-                && matches!(event.event.as_str(), "START_WORK" | "STOP_WORK")
+                && (matches!(event.event.as_str(), "START_WORK" | "STOP_WORK")
+                    || is_leave_event(event))
         })
         .collect()
 }
 
-pub fn compute_month_stats(month: Option<&str>) -> Result<MonthStats, std::io::Error> {
+/**
+ * Get the leave days of the events, sorted by day. When a day has several leave events,
+ * the last recorded one wins, i.e. a DAY_OFF turned into a SICK day.
+ */
+pub fn find_leave_days(events: &[Event]) -> Vec<LeaveDay> {
+    let mut leave_by_day: HashMap<String, EventName> = HashMap::new();
+    for event in events {
+        if let Ok(event_name) = event.event.parse::<EventName>() {
+            if event_name.leave_fraction().is_some() {
+                leave_by_day.insert(event.day.clone(), event_name);
+            }
+        }
+    }
+
+    let mut leave_days: Vec<LeaveDay> = leave_by_day
+        .into_iter()
+        .map(|(day, event)| LeaveDay { day, event })
+        .collect();
+    leave_days.sort_by(|a, b| a.day.cmp(&b.day));
+
+    leave_days
+}
+
+pub fn compute_month_stats(month: Option<&str>) -> NostResult<MonthStats> {
+    compute_month_stats_in(&get_project_config_path()?, month)
+}
+
+// Compute the stats of a month from the journal of the given configuration folder
+pub fn compute_month_stats_in(config_path: &str, month: Option<&str>) -> NostResult<MonthStats> {
     // get the month to compute stats for, defaulting to the current month if not provided
     let date = match month {
         Some(m) => NaiveDate::parse_from_str(&format!("{}-01", m), "%Y-%m-%d").map_err(|e| {
-            std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!("Invalid month format. Please use YYYY-MM. Error: {}", e),
-            )
+            NostError::Usage(format!(
+                "Invalid month format. Please use YYYY-MM. Error: {}",
+                e
+            ))
         })?,
         None => Local::now().date_naive(),
     };
@@ -58,19 +73,52 @@ pub fn compute_month_stats(month: Option<&str>) -> Result<MonthStats, std::io::E
     debug!("Computing stats for month: {}", date.format("%Y-%m"));
 
     let month = date.format("%Y-%m").to_string();
-    let events = load_events()?;
+    let events = find_all_events(config_path)?;
     let month_events = filter_month_events(&month, events);
 
+    // the durations cannot be computed without the time of every event
+    if let Some(event) = month_events
+        .iter()
+        .find(|event| DateTime::parse_from_rfc3339(&event.datetime).is_err())
+    {
+        return Err(NostError::CorruptJournal {
+            path: get_journal_file_path(config_path),
+            reason: format!(
+                "event {} has an invalid datetime '{}'",
+                event.uid, event.datetime
+            ),
+        });
+    }
+
     log::debug!("Loaded {} events from journal.", month_events.len());
 
     // we have the month events, now we can compute the stats
     Ok(compute_stats_from_events(month_events))
 }
 
+/// The worked minutes of each day of a period ("YYYY" or "YYYY-MM") from the journal of the
+/// given configuration folder, i.e. "2026-08-05" -> 133
+pub fn compute_minutes_by_day_in(
+    config_path: &str,
+    period: &str,
+) -> NostResult<HashMap<String, i32>> {
+    // the days of the period share its prefix, like the days of a month
+    let events = filter_month_events(period, find_all_events(config_path)?);
+
+    Ok(compute_stats_from_events(events)
+        .work_stats_by_week
+        .into_values()
+        .flat_map(|week_stats| week_stats.work_stats)
+        .map(|stat| (stat.day, stat.length_in_minutes))
+        .collect())
+}
+
 pub fn compute_stats_from_events(events: Vec<Event>) -> MonthStats {
+    let leave_days = find_leave_days(&events);
+
     // group events by workday (based on the event day, RFC3339-derived)
     let mut events_by_day: HashMap<String, Vec<Event>> = HashMap::new();
-    for event in events {
+    for event in events.into_iter().filter(|event| !is_leave_event(event)) {
         let day = event.day.clone();
         events_by_day.entry(day).or_default().push(event);
     }
@@ -122,31 +170,79 @@ pub fn compute_stats_from_events(events: Vec<Event>) -> MonthStats {
         total_duration_in_minutes: total_duration,
         total_work_days: worked_days_set.len() as i32,
         work_stats_by_week,
+        leave_days,
     }
 }
 
-/// Compute the total work time in minutes from a slice of work events
+/// Merge the stats of several vaults: durations of the same day are added up
+pub fn merge_month_stats(all_stats: Vec<MonthStats>) -> MonthStats {
+    let mut minutes_by_day: HashMap<String, i32> = HashMap::new();
+    let mut leave_days: Vec<LeaveDay> = Vec::new();
+    for stats in all_stats {
+        // a day off in any vault is a day off
+        for leave_day in stats.leave_days {
+            if !leave_days.iter().any(|known| known.day == leave_day.day) {
+                leave_days.push(leave_day);
+            }
+        }
+        for week_stats in stats.work_stats_by_week.into_values() {
+            for stat in week_stats.work_stats {
+                *minutes_by_day.entry(stat.day).or_default() += stat.length_in_minutes;
+            }
+        }
+    }
+
+    let mut work_stats_by_week: HashMap<WeekId, WeekStats> = HashMap::new();
+    for (day, length_in_minutes) in minutes_by_day.iter() {
+        let Ok(parsed_date) = NaiveDate::parse_from_str(day, "%Y-%m-%d") else {
+            continue;
+        };
+        let week_id = WeekId {
+            year: parsed_date.iso_week().year(),
+            week: parsed_date.iso_week().week(),
+        };
+
+        let week_stats = work_stats_by_week.entry(week_id).or_insert(WeekStats {
+            total_duration_in_minutes: 0,
+            work_stats: Vec::new(),
+        });
+        week_stats.total_duration_in_minutes += length_in_minutes;
+        week_stats.work_stats.push(Stats {
+            day: day.clone(),
+            length_in_minutes: *length_in_minutes,
+        });
+    }
+
+    MonthStats {
+        total_duration_in_minutes: minutes_by_day.values().sum(),
+        total_work_days: minutes_by_day.len() as i32,
+        work_stats_by_week,
+        leave_days: {
+            leave_days.sort_by(|a, b| a.day.cmp(&b.day));
+            leave_days
+        },
+    }
+}
+
+/// Compute the total work time in minutes from a slice of work events.
+/// Events with an invalid datetime or name are ignored.
 pub fn compute_workday_duration(events: &[Event]) -> i32 {
     // Sort events by datetime
-    let mut sorted_events: Vec<&Event> = events.iter().collect();
-    sorted_events.sort_by_key(|event| {
-        DateTime::parse_from_rfc3339(&event.datetime)
-            .expect("Invalid RFC3339 datetime in work event")
-    });
+    let mut sorted_events: Vec<(DateTime<FixedOffset>, EventName)> = events
+        .iter()
+        .filter_map(|event| {
+            let datetime = DateTime::parse_from_rfc3339(&event.datetime).ok()?;
+            let event_name = event.event.parse::<EventName>().ok()?;
+            Some((datetime, event_name))
+        })
+        .collect();
+    sorted_events.sort_by_key(|(datetime, _)| *datetime);
 
     // compute work length sessions by pairing START_WORK and STOP_WORK events
     let mut total_time_in_minutes = 0;
     let mut start_time: Option<DateTime<FixedOffset>> = None;
 
-    for event in sorted_events {
-        // todo: handle validation in one place somewhere, and not here
-        let datetime = DateTime::parse_from_rfc3339(&event.datetime)
-            .expect("Invalid RFC3339 datetime in work event");
-        let event_name = event
-            .event
-            .parse::<EventName>()
-            .expect("Invalid event name in work event");
-
+    for (datetime, event_name) in sorted_events {
         match event_name {
             EventName::StartWork => {
                 start_time = Some(datetime);
@@ -166,7 +262,9 @@ pub fn compute_workday_duration(events: &[Event]) -> i32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{compute_stats_from_events, compute_workday_duration, filter_month_events};
+    use super::{
+        compute_stats_from_events, compute_workday_duration, filter_month_events, merge_month_stats,
+    };
     use crate::events::models::{Event, EventName};
     use chrono::DateTime;
 
@@ -177,6 +275,7 @@ mod tests {
             day: day.to_string(),
             not_type: "work".to_string(),
             uid: uid.to_string(),
+            marker: None,
         }
     }
 
@@ -188,6 +287,7 @@ mod tests {
             day: dt.format("%Y-%m-%d").to_string(),
             not_type: "work".to_string(),
             uid: uid.to_string(),
+            marker: None,
         }
     }
 
@@ -291,4 +391,58 @@ mod tests {
         assert_eq!(stats.total_duration_in_minutes, 180);
         assert_eq!(stats.work_stats_by_week.len(), 1);
     }
+
+    #[test]
+    fn leave_days_are_not_worked_days() {
+        let mut holiday = make_event("2026-08-14", "holiday");
+        holiday.event = EventName::Holiday.to_string();
+        let mut sick = make_event("2026-08-13", "sick");
+        sick.event = EventName::DayOff.to_string();
+        let mut sick_again = make_event("2026-08-13", "sick-again");
+        sick_again.event = EventName::Sick.to_string();
+
+        let events = vec![
+            make_event_at(EventName::StartWork, "2026-08-12T09:00:00+00:00", "a"),
+            make_event_at(EventName::StopWork, "2026-08-12T10:00:00+00:00", "b"),
+            holiday,
+            sick,
+            sick_again,
+        ];
+        let stats = compute_stats_from_events(filter_month_events("2026-08", events));
+
+        assert_eq!(stats.total_work_days, 1);
+        assert_eq!(stats.work_stats_by_week.len(), 1);
+        let leave: Vec<(&str, &EventName)> = stats
+            .leave_days
+            .iter()
+            .map(|leave_day| (leave_day.day.as_str(), &leave_day.event))
+            .collect();
+        assert_eq!(
+            leave,
+            vec![
+                ("2026-08-13", &EventName::Sick),
+                ("2026-08-14", &EventName::Holiday)
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_month_stats_adds_up_days_of_all_vaults() {
+        let personal = compute_stats_from_events(vec![
+            make_event_at(EventName::StartWork, "2026-08-05T09:00:00+00:00", "a"),
+            make_event_at(EventName::StopWork, "2026-08-05T10:00:00+00:00", "b"),
+        ]);
+        let acme = compute_stats_from_events(vec![
+            make_event_at(EventName::StartWork, "2026-08-05T14:00:00+00:00", "c"),
+            make_event_at(EventName::StopWork, "2026-08-05T16:00:00+00:00", "d"),
+            make_event_at(EventName::StartWork, "2026-08-12T09:00:00+00:00", "e"),
+            make_event_at(EventName::StopWork, "2026-08-12T10:00:00+00:00", "f"),
+        ]);
+
+        let stats = merge_month_stats(vec![personal, acme]);
+
+        assert_eq!(stats.total_duration_in_minutes, 240);
+        assert_eq!(stats.total_work_days, 2);
+        assert_eq!(stats.work_stats_by_week.len(), 2);
+    }
 }
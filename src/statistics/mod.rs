@@ -1,3 +1,5 @@
 pub mod compute;
 pub mod models;
 pub mod print;
+pub mod status;
+pub mod targets;
@@ -1,3 +1,6 @@
+use serde::Serialize;
+
+use crate::events::models::EventName;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -12,11 +15,20 @@ pub struct WeekStats {
     pub work_stats: Vec<Stats>,
 }
 
+/// A day of leave, from a DAY_OFF, HOLIDAY, SICK or HALF_DAY event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaveDay {
+    pub day: String, // in format "YYYY-MM-DD"
+    pub event: EventName,
+}
+
 #[derive(Debug, Clone)]
 pub struct MonthStats {
     pub total_duration_in_minutes: i32,
     pub total_work_days: i32,
     pub work_stats_by_week: HashMap<WeekId, WeekStats>,
+    // sorted by day, one per day
+    pub leave_days: Vec<LeaveDay>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -24,3 +36,46 @@ pub struct WeekId {
     pub year: i32,
     pub week: u32,
 }
+
+/// The running session and the totals of the periods containing a given moment.
+/// The running session counts in the totals up to that moment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WorkStatus {
+    pub running: bool,
+    // datetime of the START_WORK event of the running session
+    pub since: Option<String>,
+    pub elapsed_minutes: i32,
+    pub today_minutes: i32,
+    pub week_minutes: i32,
+    pub month_minutes: i32,
+    pub month_target_minutes: Option<i32>,
+    pub today_notes: Vec<String>,
+}
+
+/// How the work days of a vault are paid, from its `work.salary`, `work.currency` and
+/// `work.paid_leave` settings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pay {
+    // daily rate, see `get_work_salary` when missing
+    pub salary: Option<f32>,
+    // see `get_work_currency` when missing
+    pub currency: Option<String>,
+    pub paid_leave: Vec<EventName>,
+}
+
+/// The minutes expected to be worked in a month and in each of its weeks, see `statistics::targets`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonthExpectation {
+    pub expected_minutes: i32,
+    pub expected_by_week: HashMap<WeekId, i32>,
+}
+
+/// A line of `nost balance`: the balance carries the deltas of the previous months.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MonthBalance {
+    pub month: String, // in format "YYYY-MM"
+    pub expected_minutes: i32,
+    pub actual_minutes: i32,
+    pub delta_minutes: i32,
+    pub balance_minutes: i32,
+}
@@ -1,24 +1,141 @@
-use crate::statistics::models::{MonthStats, WeekId, WeekStats};
+use crate::configurations::get::get_work_salary;
+use crate::events::models::EventName;
+use crate::locales::models::{Locale, StatsLabels};
+use crate::statistics::models::{LeaveDay, MonthExpectation, MonthStats, Pay, WeekId, WeekStats};
 use chrono::Datelike;
 use chrono::NaiveDate;
-use std::env;
+use std::collections::BTreeMap;
 
-pub fn print_stats(stats: MonthStats) -> String {
+/// Format a duration the short way, i.e. 133 minutes -> "2h13"
+pub fn format_duration(minutes: i32) -> String {
+    let sign = if minutes < 0 { "-" } else { "" };
+    let minutes = minutes.abs();
+    format!("{}{}h{:02}", sign, minutes / 60, minutes % 60)
+}
+
+fn hours(minutes: i32) -> f32 {
+    minutes as f32 / 60.0
+}
+
+// The kind of a leave day, i.e. "holiday" for HOLIDAY
+pub fn leave_label(labels: &StatsLabels, event: &EventName) -> &'static str {
+    match event {
+        EventName::Holiday => labels.holiday,
+        EventName::Sick => labels.sick,
+        EventName::HalfDay => labels.half_day,
+        _ => labels.day_off,
+    }
+}
+
+fn week_of_leave_day(leave_day: &LeaveDay) -> Option<WeekId> {
+    let date = NaiveDate::parse_from_str(&leave_day.day, "%Y-%m-%d").ok()?;
+    Some(WeekId {
+        year: date.iso_week().year(),
+        week: date.iso_week().week(),
+    })
+}
+
+/**
+ * Count the days of paid leave of a month for the salary: a leave day on which some work was
+ * recorded is already paid as a work day.
+ */
+fn count_paid_leave_days(stats: &MonthStats, paid_leave: &[EventName]) -> f32 {
+    stats
+        .leave_days
+        .iter()
+        .filter(|leave_day| paid_leave.contains(&leave_day.event))
+        .filter(|leave_day| {
+            !stats.work_stats_by_week.values().any(|week_stats| {
+                week_stats
+                    .work_stats
+                    .iter()
+                    .any(|stat| stat.day == leave_day.day)
+            })
+        })
+        .filter_map(|leave_day| leave_day.event.leave_fraction())
+        .sum()
+}
+
+/// Compute the salary of the month of a vault, its paid leave included.
+pub fn compute_salary(stats: &MonthStats, pay: &Pay) -> f32 {
+    let paid_days = stats.total_work_days as f32 + count_paid_leave_days(stats, &pay.paid_leave);
+
+    if paid_days > 0.0 {
+        get_work_salary(pay) * paid_days
+    } else {
+        0.0
+    }
+}
+
+/**
+ * Render the stats of a month as a table per week, with its leave days. With an expectation,
+ * the expected hours, the worked hours and the delta are added under each week and in the
+ * summary. The salary is given per currency, see `compute_salary`.
+ */
+pub fn print_stats(
+    stats: MonthStats,
+    locale: &Locale,
+    expectation: Option<&MonthExpectation>,
+    salaries: &BTreeMap<String, f32>,
+) -> String {
     // would be better to return a Result<String, Error> but for now we just return a String
-    let header =
-        "\n| Day | Date       | Hours | Acc |\n|-----|------------|-------|-----|\n".to_string();
+    let labels = &locale.stats;
+    let header = format!(
+        "\n| {} | {:<10} | {} | {} |\n|{}|{}|{}|{}|\n",
+        labels.day,
+        labels.date,
+        labels.hours,
+        labels.acc,
+        "-".repeat(labels.day.chars().count() + 2),
+        "-".repeat(labels.date.chars().count().max(10) + 2),
+        "-".repeat(labels.hours.chars().count() + 2),
+        "-".repeat(labels.acc.chars().count() + 2),
+    );
+    // summary labels are aligned on the longest one
+    let mut summary_labels = vec![labels.work_days, labels.total, labels.salary];
+    if expectation.is_some() {
+        summary_labels.extend([labels.expected, labels.delta]);
+    }
+    if !stats.leave_days.is_empty() {
+        summary_labels.push(labels.leave);
+    }
+    let width = summary_labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or_default();
     let mut stats_content: String = String::new();
 
-    // collect and sort weeks by date (year, then week)
-    let mut sorted_weeks: Vec<(&WeekId, &WeekStats)> = stats.work_stats_by_week.iter().collect();
-    sorted_weeks
-        .sort_by(|(a_id, _), (b_id, _)| a_id.year.cmp(&b_id.year).then(a_id.week.cmp(&b_id.week)));
+    // collect and sort weeks by date (year, then week), with the expected weeks without work
+    let mut sorted_weeks: Vec<WeekId> = stats.work_stats_by_week.keys().copied().collect();
+    if let Some(expectation) = expectation {
+        for (week_id, expected_minutes) in &expectation.expected_by_week {
+            if *expected_minutes > 0 && !sorted_weeks.contains(week_id) {
+                sorted_weeks.push(*week_id);
+            }
+        }
+    }
+    for week_id in stats.leave_days.iter().filter_map(week_of_leave_day) {
+        if !sorted_weeks.contains(&week_id) {
+            sorted_weeks.push(week_id);
+        }
+    }
+    sorted_weeks.sort_by(|a_id, b_id| a_id.year.cmp(&b_id.year).then(a_id.week.cmp(&b_id.week)));
 
-    // for each week in work_stats_by_week add an header and then the stats
-    for (_week_id, week_stats) in sorted_weeks {
+    // for each week add an header and then the stats
+    for week_id in sorted_weeks {
         // Add week header
         stats_content.push_str(&header);
 
+        let week_stats = stats
+            .work_stats_by_week
+            .get(&week_id)
+            .cloned()
+            .unwrap_or(WeekStats {
+                total_duration_in_minutes: 0,
+                work_stats: Vec::new(),
+            });
+
         // sort the days by day (ascending)
         let mut sorted_work_stats = week_stats.work_stats.clone();
         sorted_work_stats.sort_by(|a, b| a.day.cmp(&b.day));
@@ -26,7 +143,7 @@ pub fn print_stats(stats: MonthStats) -> String {
         let mut cumulative_week_hours = 0.0;
         for work_stat in sorted_work_stats.iter() {
             let date = NaiveDate::parse_from_str(&work_stat.day, "%Y-%m-%d").unwrap();
-            let weekday = date.weekday();
+            let weekday = locale.weekdays_short[date.weekday().num_days_from_monday() as usize];
             let hours = work_stat.length_in_minutes as f32 / 60.0;
             cumulative_week_hours += hours;
 
@@ -35,55 +152,114 @@ pub fn print_stats(stats: MonthStats) -> String {
                 weekday, work_stat.day, hours, cumulative_week_hours
             ));
         }
+
+        let week_leave: Vec<String> = stats
+            .leave_days
+            .iter()
+            .filter(|leave_day| week_of_leave_day(leave_day) == Some(week_id))
+            .map(|leave_day| {
+                let weekday = NaiveDate::parse_from_str(&leave_day.day, "%Y-%m-%d")
+                    .map(|date| {
+                        locale.weekdays_short[date.weekday().num_days_from_monday() as usize]
+                    })
+                    .unwrap_or_default();
+                format!(
+                    "{} {} ({})",
+                    weekday,
+                    leave_day.day,
+                    leave_label(labels, &leave_day.event)
+                )
+            })
+            .collect();
+        if !week_leave.is_empty() {
+            stats_content.push_str(&format!("\n{}: {}\n", labels.leave, week_leave.join(", ")));
+        }
+
+        if let Some(expectation) = expectation {
+            let expected_minutes = expectation
+                .expected_by_week
+                .get(&week_id)
+                .copied()
+                .unwrap_or(0);
+            stats_content.push_str(&format!(
+                "\n{}: {:.2} | {}: {:.2} | {}: {:+.2}\n",
+                labels.expected,
+                hours(expected_minutes),
+                labels.total,
+                hours(week_stats.total_duration_in_minutes),
+                labels.delta,
+                hours(week_stats.total_duration_in_minutes - expected_minutes)
+            ));
+        }
     }
 
     stats_content.push_str(&format!(
-        "\n| Work Days | {}     |\n",
-        stats.total_work_days
+        "\n| {:<width$} | {}     |\n",
+        labels.work_days, stats.total_work_days
     ));
     stats_content.push_str(&format!(
-        "| Total     | {:.2} |\n",
+        "| {:<width$} | {:.2} |\n",
+        labels.total,
         stats.total_duration_in_minutes as f32 / 60.0
     ));
+    if let Some(expectation) = expectation {
+        stats_content.push_str(&format!(
+            "| {:<width$} | {:.2} |\n",
+            labels.expected,
+            hours(expectation.expected_minutes)
+        ));
+        stats_content.push_str(&format!(
+            "| {:<width$} | {:+.2} |\n",
+            labels.delta,
+            hours(stats.total_duration_in_minutes - expectation.expected_minutes)
+        ));
+    }
 
-    // replace env, use config
-    let daily_rate: f32 = env::var("NOST_WORK_SALARY")
-        .unwrap_or_else(|_| {
-            eprintln!("NOST_WORK_SALARY environment variable not set.");
-            "0".to_string()
-        })
-        .parse()
-        .unwrap_or(0.0);
-
-    // replace env, use config
-    let currency = env::var("NOST_WORK_CURRENCY").unwrap_or_else(|_| {
-        eprintln!("NOST_WORK_CURRENCY environment variable not set.");
-        "EUR".to_string()
-    });
-
-    let salary = if stats.total_work_days > 0 {
-        daily_rate * stats.total_work_days as f32
-    } else {
-        0.0
-    };
+    if !stats.leave_days.is_empty() {
+        let leave_days: f32 = stats
+            .leave_days
+            .iter()
+            .filter_map(|leave_day| leave_day.event.leave_fraction())
+            .sum();
+        stats_content.push_str(&format!("| {:<width$} | {} |\n", labels.leave, leave_days));
+    }
 
-    stats_content.push_str(&format!("| Salary    | {:.2} {} |\n", salary, currency));
+    // the salaries in different currencies are not added up
+    let salary = salaries
+        .iter()
+        .map(|(currency, salary)| format!("{:.2} {}", salary, currency))
+        .collect::<Vec<String>>()
+        .join(" + ");
+    stats_content.push_str(&format!("| {:<width$} | {} |\n", labels.salary, salary));
 
     stats_content
 }
 
 #[cfg(test)]
 mod tests {
-    use super::print_stats;
-    use crate::statistics::models::{MonthStats, Stats, WeekId, WeekStats};
-    use std::{collections::HashMap, env};
+    use super::{compute_salary, count_paid_leave_days, format_duration, print_stats};
+    use crate::events::models::EventName;
+    use crate::locales::get::find_locale;
+    use crate::statistics::models::{
+        LeaveDay, MonthExpectation, MonthStats, Pay, Stats, WeekId, WeekStats,
+    };
+    use std::collections::{BTreeMap, HashMap};
+
+    fn salaries(stats: &MonthStats, pay: Pay) -> BTreeMap<String, f32> {
+        let currency = pay.currency.clone().unwrap_or_default();
+        BTreeMap::from([(currency, compute_salary(stats, &pay))])
+    }
+
+    fn pay(salary: f32, currency: &str) -> Pay {
+        Pay {
+            salary: Some(salary),
+            currency: Some(currency.to_string()),
+            paid_leave: Vec::new(),
+        }
+    }
 
     #[test]
-    #[serial_test::serial]
     fn print_stats_renders_totals_and_salary() {
-        env::set_var("NOST_WORK_SALARY", "120");
-        env::set_var("NOST_WORK_CURRENCY", "EUR");
-
         let mut work_stats_by_week = HashMap::new();
         work_stats_by_week.insert(
             WeekId {
@@ -109,9 +285,11 @@ mod tests {
             total_duration_in_minutes: 180,
             total_work_days: 2,
             work_stats_by_week,
+            leave_days: Vec::new(),
         };
 
-        let rendered = print_stats(stats);
+        let salaries = salaries(&stats, pay(120.0, "EUR"));
+        let rendered = print_stats(stats, find_locale("en"), None, &salaries);
 
         assert!(rendered.contains("| Work Days | 2"));
         assert!(rendered.contains("| Total     | 3.00 |"));
@@ -119,11 +297,7 @@ mod tests {
     }
 
     #[test]
-    #[serial_test::serial]
     fn print_stats_sorts_weeks_and_days() {
-        env::set_var("NOST_WORK_SALARY", "100");
-        env::set_var("NOST_WORK_CURRENCY", "EUR");
-
         let mut work_stats_by_week = HashMap::new();
         // Insert week 33 first on purpose (to verify sorting by week id)
         work_stats_by_week.insert(
@@ -165,9 +339,11 @@ mod tests {
             total_duration_in_minutes: 180,
             total_work_days: 3,
             work_stats_by_week,
+            leave_days: Vec::new(),
         };
 
-        let rendered = print_stats(stats);
+        let salaries = salaries(&stats, pay(100.0, "EUR"));
+        let rendered = print_stats(stats, find_locale("en"), None, &salaries);
 
         let idx_0805 = rendered.find("2026-08-05").unwrap();
         let idx_0806 = rendered.find("2026-08-06").unwrap();
@@ -178,21 +354,168 @@ mod tests {
     }
 
     #[test]
-    #[serial_test::serial]
     fn print_stats_zero_work_days_has_zero_salary() {
-        env::set_var("NOST_WORK_SALARY", "500");
-        env::set_var("NOST_WORK_CURRENCY", "USD");
-
         let stats = MonthStats {
             total_duration_in_minutes: 0,
             total_work_days: 0,
             work_stats_by_week: HashMap::new(),
+            leave_days: Vec::new(),
         };
 
-        let rendered = print_stats(stats);
+        let salaries = salaries(&stats, pay(500.0, "USD"));
+        let rendered = print_stats(stats, find_locale("en"), None, &salaries);
 
         assert!(rendered.contains("| Work Days | 0"));
         assert!(rendered.contains("| Total     | 0.00 |"));
         assert!(rendered.contains("| Salary    | 0.00 USD |"));
     }
+
+    #[test]
+    fn print_stats_uses_locale_labels() {
+        let mut work_stats_by_week = HashMap::new();
+        work_stats_by_week.insert(
+            WeekId {
+                year: 2026,
+                week: 32,
+            },
+            WeekStats {
+                total_duration_in_minutes: 60,
+                work_stats: vec![Stats {
+                    day: "2026-08-05".to_string(),
+                    length_in_minutes: 60,
+                }],
+            },
+        );
+
+        let stats = MonthStats {
+            total_duration_in_minutes: 60,
+            total_work_days: 1,
+            work_stats_by_week,
+            leave_days: Vec::new(),
+        };
+
+        let salaries = salaries(&stats, pay(100.0, "EUR"));
+        let rendered = print_stats(stats, find_locale("de"), None, &salaries);
+
+        assert!(rendered.contains("| Tag | Datum      | Stunden | Summe |"));
+        assert!(rendered.contains("| Mi | 2026-08-05 | 1.00 | 1.00 |"));
+        assert!(rendered.contains("| Arbeitstage | 1"));
+        assert!(rendered.contains("| Gehalt      | 100.00 EUR |"));
+    }
+
+    #[test]
+    fn print_stats_shows_expected_hours_and_delta_per_week() {
+        let week_32 = WeekId {
+            year: 2026,
+            week: 32,
+        };
+        let week_33 = WeekId {
+            year: 2026,
+            week: 33,
+        };
+        let mut work_stats_by_week = HashMap::new();
+        work_stats_by_week.insert(
+            week_32,
+            WeekStats {
+                total_duration_in_minutes: 1500,
+                work_stats: vec![Stats {
+                    day: "2026-08-05".to_string(),
+                    length_in_minutes: 1500,
+                }],
+            },
+        );
+        let stats = MonthStats {
+            total_duration_in_minutes: 1500,
+            total_work_days: 1,
+            work_stats_by_week,
+            leave_days: Vec::new(),
+        };
+        let expectation = MonthExpectation {
+            expected_minutes: 2400,
+            expected_by_week: HashMap::from([(week_32, 1200), (week_33, 1200)]),
+        };
+
+        let salaries = salaries(&stats, pay(100.0, "EUR"));
+        let rendered = print_stats(stats, find_locale("en"), Some(&expectation), &salaries);
+
+        assert!(rendered.contains("Expected: 20.00 | Total: 25.00 | Delta: +5.00"));
+        // a week without work is still listed
+        assert!(rendered.contains("Expected: 20.00 | Total: 0.00 | Delta: -20.00"));
+        assert!(rendered.contains("| Expected  | 40.00 |"));
+        assert!(rendered.contains("| Delta     | -15.00 |"));
+    }
+
+    #[test]
+    fn print_stats_lists_leave_days_per_week() {
+        let mut work_stats_by_week = HashMap::new();
+        work_stats_by_week.insert(
+            WeekId {
+                year: 2026,
+                week: 32,
+            },
+            WeekStats {
+                total_duration_in_minutes: 60,
+                work_stats: vec![Stats {
+                    day: "2026-08-05".to_string(),
+                    length_in_minutes: 60,
+                }],
+            },
+        );
+        let stats = MonthStats {
+            total_duration_in_minutes: 60,
+            total_work_days: 1,
+            work_stats_by_week,
+            leave_days: vec![
+                LeaveDay {
+                    day: "2026-08-05".to_string(),
+                    event: EventName::HalfDay,
+                },
+                LeaveDay {
+                    day: "2026-08-14".to_string(),
+                    event: EventName::Holiday,
+                },
+            ],
+        };
+
+        // the half day is already paid as a work day
+        assert_eq!(
+            count_paid_leave_days(&stats, &[EventName::Holiday, EventName::HalfDay]),
+            1.0
+        );
+
+        let holiday_pay = Pay {
+            paid_leave: vec![EventName::Holiday],
+            ..pay(100.0, "EUR")
+        };
+        let salaries = salaries(&stats, holiday_pay);
+        let rendered = print_stats(stats, find_locale("en"), None, &salaries);
+
+        assert!(rendered.contains("Leave: Wed 2026-08-05 (half day)"));
+        // a week with leave only is still listed
+        assert!(rendered.contains("Leave: Fri 2026-08-14 (holiday)"));
+        assert!(rendered.contains("| Leave     | 1.5 |"));
+        assert!(rendered.contains("| Salary    | 200.00 EUR |"));
+    }
+
+    #[test]
+    fn print_stats_does_not_add_up_currencies() {
+        let stats = MonthStats {
+            total_duration_in_minutes: 0,
+            total_work_days: 0,
+            work_stats_by_week: HashMap::new(),
+            leave_days: Vec::new(),
+        };
+        let salaries = BTreeMap::from([("EUR".to_string(), 240.0), ("USD".to_string(), 50.0)]);
+
+        let rendered = print_stats(stats, find_locale("en"), None, &salaries);
+
+        assert!(rendered.contains("| Salary    | 240.00 EUR + 50.00 USD |"));
+    }
+
+    #[test]
+    fn format_duration_pads_the_minutes() {
+        assert_eq!(format_duration(133), "2h13");
+        assert_eq!(format_duration(5), "0h05");
+        assert_eq!(format_duration(-90), "-1h30");
+    }
 }
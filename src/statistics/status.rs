@@ -0,0 +1,124 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate};
+use std::collections::HashMap;
+
+use crate::{
+    events::models::{Event, EventName},
+    statistics::{compute::compute_workday_duration, models::WorkStatus},
+};
+
+/**
+ * Compute the running session and the totals of the day, ISO week and month of `now` from the
+ * events of a journal. A running session counts for the day it started, like in the stats.
+ */
+pub fn compute_work_status(
+    events: &[Event],
+    now: DateTime<FixedOffset>,
+    month_target_minutes: Option<i32>,
+) -> WorkStatus {
+    let work_events: Vec<&Event> = events
+        .iter()
+        .filter(|event| {
+            event.event == EventName::StartWork.to_string()
+                || event.event == EventName::StopWork.to_string()
+        })
+        .collect();
+
+    // the running session is opened by the last work event of the journal
+    let running_start = work_events
+        .last()
+        .filter(|event| event.event == EventName::StartWork.to_string())
+        .and_then(|event| {
+            let start = DateTime::parse_from_rfc3339(&event.datetime).ok()?;
+            Some((event, start))
+        });
+
+    let mut minutes_by_day: HashMap<NaiveDate, i32> = HashMap::new();
+    let mut events_by_day: HashMap<&str, Vec<Event>> = HashMap::new();
+    for event in &work_events {
+        events_by_day
+            .entry(event.day.as_str())
+            .or_default()
+            .push((*event).clone());
+    }
+    for (day, day_events) in events_by_day {
+        if let Ok(date) = NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+            *minutes_by_day.entry(date).or_default() += compute_workday_duration(&day_events);
+        }
+    }
+
+    let mut status = WorkStatus {
+        month_target_minutes,
+        ..WorkStatus::default()
+    };
+
+    if let Some((event, start)) = running_start {
+        status.running = true;
+        status.since = Some(event.datetime.clone());
+        status.elapsed_minutes = (now - start).num_minutes().max(0) as i32;
+        if let Ok(date) = NaiveDate::parse_from_str(&event.day, "%Y-%m-%d") {
+            *minutes_by_day.entry(date).or_default() += status.elapsed_minutes;
+        }
+    }
+
+    let today = now.date_naive();
+    for (date, minutes) in minutes_by_day {
+        if date == today {
+            status.today_minutes += minutes;
+        }
+        if date.iso_week() == today.iso_week() {
+            status.week_minutes += minutes;
+        }
+        if date.year() == today.year() && date.month() == today.month() {
+            status.month_minutes += minutes;
+        }
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{at, event_at};
+
+    #[test]
+    fn running_session_counts_in_the_totals() {
+        let events = vec![
+            // previous week, same month
+            event_at(EventName::StartWork, "2026-08-03T09:00:00+02:00"),
+            event_at(EventName::StopWork, "2026-08-03T17:00:00+02:00"),
+            // same week
+            event_at(EventName::StartWork, "2026-08-10T09:00:00+02:00"),
+            event_at(EventName::StopWork, "2026-08-10T12:00:00+02:00"),
+            // today
+            event_at(EventName::StartWork, "2026-08-12T08:00:00+02:00"),
+            event_at(EventName::StopWork, "2026-08-12T09:00:00+02:00"),
+            event_at(EventName::StartWork, "2026-08-12T10:00:00+02:00"),
+        ];
+
+        let status = compute_work_status(&events, at("2026-08-12T12:13:00+02:00"), Some(140 * 60));
+
+        assert!(status.running);
+        assert_eq!(status.since.as_deref(), Some("2026-08-12T10:00:00+02:00"));
+        assert_eq!(status.elapsed_minutes, 133);
+        assert_eq!(status.today_minutes, 60 + 133);
+        assert_eq!(status.week_minutes, 180 + 60 + 133);
+        assert_eq!(status.month_minutes, 480 + 180 + 60 + 133);
+        assert_eq!(status.month_target_minutes, Some(140 * 60));
+    }
+
+    #[test]
+    fn no_session_is_running_after_a_stop() {
+        let events = vec![
+            event_at(EventName::StartWork, "2026-08-12T08:00:00+02:00"),
+            event_at(EventName::StopWork, "2026-08-12T09:30:00+02:00"),
+        ];
+
+        let status = compute_work_status(&events, at("2026-08-12T12:00:00+02:00"), None);
+
+        assert!(!status.running);
+        assert_eq!(status.since, None);
+        assert_eq!(status.today_minutes, 90);
+        assert_eq!(status.month_target_minutes, None);
+    }
+}
@@ -0,0 +1,293 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::HashMap;
+
+use crate::{
+    configurations::models::WorkTargets,
+    dates::get::get_days_of_month,
+    events::models::Event,
+    statistics::{
+        compute::{compute_stats_from_events, filter_month_events},
+        models::{LeaveDay, MonthBalance, MonthExpectation, WeekId},
+    },
+};
+
+fn is_workweek_day(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+fn hours_to_minutes(hours: f32) -> i32 {
+    (hours * 60.0).round() as i32
+}
+
+/**
+ * Get the minutes expected on a day, from the most precise target configured: the target of
+ * its weekday, else the week target spread over Monday to Friday, else the month target spread
+ * over the Mondays to Fridays of the month.
+ * i.e. with `week = 40`, a Wednesday is expected to last 8 hours and a Saturday 0
+ */
+pub fn expected_minutes_for_day(targets: &WorkTargets, date: NaiveDate) -> i32 {
+    if targets.has_weekdays() {
+        return targets
+            .weekday(date.weekday())
+            .map(hours_to_minutes)
+            .unwrap_or(0);
+    }
+
+    if !is_workweek_day(date) {
+        return 0;
+    }
+
+    if let Some(week) = targets.week {
+        return hours_to_minutes(week / 5.0);
+    }
+
+    if let Some(month) = targets.month {
+        let workweek_days = get_days_of_month(date)
+            .into_iter()
+            .filter(|day| is_workweek_day(*day))
+            .count();
+        return hours_to_minutes(month / workweek_days as f32);
+    }
+
+    0
+}
+
+// Scale the minutes of each week in proportion so that they add up to `total`, rounding the
+// cumulated minutes so that no minute is lost
+fn scale_weeks(expected_by_week: &mut HashMap<WeekId, i32>, total: i32) {
+    let current_total: i32 = expected_by_week.values().sum();
+    if current_total == 0 {
+        return;
+    }
+
+    let mut week_ids: Vec<WeekId> = expected_by_week.keys().copied().collect();
+    week_ids.sort_by_key(|week_id| (week_id.year, week_id.week));
+
+    let mut cumulated = 0;
+    let mut scaled_cumulated = 0;
+    for week_id in week_ids {
+        let Some(minutes) = expected_by_week.get_mut(&week_id) else {
+            continue;
+        };
+        cumulated += *minutes;
+        let scaled = (cumulated as f64 * total as f64 / current_total as f64).round() as i32;
+        *minutes = scaled - scaled_cumulated;
+        scaled_cumulated = scaled;
+    }
+}
+
+/**
+ * Compute the minutes expected in the month of a date, by ISO week, counting the days up to
+ * `until` included so that an ongoing month is not expected in full. Leave days are not
+ * expected, half days only by half. A complete month with a month target is expected to last
+ * that target, less its leave, its weeks being scaled to add up to it.
+ */
+pub fn compute_month_expectation(
+    targets: &WorkTargets,
+    month: NaiveDate,
+    until: NaiveDate,
+    leave_days: &[LeaveDay],
+) -> MonthExpectation {
+    let days = get_days_of_month(month);
+    let month_is_complete = days.last().is_some_and(|last| *last <= until);
+
+    let mut expectation = MonthExpectation::default();
+    let mut leave_minutes = 0;
+    for day in days.into_iter().filter(|day| *day <= until) {
+        let day_string = day.format("%Y-%m-%d").to_string();
+        let leave_fraction = leave_days
+            .iter()
+            .find(|leave_day| leave_day.day == day_string)
+            .and_then(|leave_day| leave_day.event.leave_fraction())
+            .unwrap_or(0.0);
+
+        let full_day_minutes = expected_minutes_for_day(targets, day);
+        let minutes = (full_day_minutes as f32 * (1.0 - leave_fraction)).round() as i32;
+        leave_minutes += full_day_minutes - minutes;
+        let week_id = WeekId {
+            year: day.iso_week().year(),
+            week: day.iso_week().week(),
+        };
+
+        *expectation.expected_by_week.entry(week_id).or_default() += minutes;
+        expectation.expected_minutes += minutes;
+    }
+
+    if let (true, Some(month_target)) = (month_is_complete, targets.month) {
+        expectation.expected_minutes = hours_to_minutes(month_target) - leave_minutes;
+        scale_weeks(
+            &mut expectation.expected_by_week,
+            expectation.expected_minutes,
+        );
+    }
+
+    expectation
+}
+
+/**
+ * Compute the expected and actual minutes of each month from `from` to `to` (any day of the
+ * months), with the balance carried from month to month. Days after `today` are not expected.
+ */
+pub fn compute_balance(
+    targets: &WorkTargets,
+    events: &[Event],
+    from: NaiveDate,
+    to: NaiveDate,
+    today: NaiveDate,
+) -> Vec<MonthBalance> {
+    let mut balances = Vec::new();
+    let mut balance_minutes = 0;
+
+    let Some(mut month) = from.with_day(1) else {
+        return balances;
+    };
+    while month <= to {
+        let month_string = month.format("%Y-%m").to_string();
+        let stats = compute_stats_from_events(filter_month_events(&month_string, events.to_vec()));
+        let actual_minutes = stats.total_duration_in_minutes;
+        let expected_minutes =
+            compute_month_expectation(targets, month, today, &stats.leave_days).expected_minutes;
+        let delta_minutes = actual_minutes - expected_minutes;
+        balance_minutes += delta_minutes;
+
+        balances.push(MonthBalance {
+            month: month_string,
+            expected_minutes,
+            actual_minutes,
+            delta_minutes,
+            balance_minutes,
+        });
+
+        month = match month.checked_add_months(chrono::Months::new(1)) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+
+    balances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::models::EventName;
+    use crate::test_helpers::{date, event_at};
+
+    #[test]
+    fn weekday_targets_win_over_week_and_month() {
+        let targets = WorkTargets {
+            monday: Some(7.5),
+            friday: Some(4.0),
+            week: Some(40.0),
+            month: Some(140.0),
+            ..WorkTargets::default()
+        };
+
+        // 2026-08-03 is a Monday
+        assert_eq!(expected_minutes_for_day(&targets, date(2026, 8, 3)), 450);
+        assert_eq!(expected_minutes_for_day(&targets, date(2026, 8, 4)), 0);
+        assert_eq!(expected_minutes_for_day(&targets, date(2026, 8, 7)), 240);
+    }
+
+    #[test]
+    fn week_and_month_targets_are_spread_over_workweek_days() {
+        let week = WorkTargets {
+            week: Some(40.0),
+            ..WorkTargets::default()
+        };
+        assert_eq!(expected_minutes_for_day(&week, date(2026, 8, 5)), 480);
+        assert_eq!(expected_minutes_for_day(&week, date(2026, 8, 8)), 0);
+
+        // August 2026 has 21 days from Monday to Friday
+        let month = WorkTargets {
+            month: Some(168.0),
+            ..WorkTargets::default()
+        };
+        assert_eq!(expected_minutes_for_day(&month, date(2026, 8, 5)), 480);
+    }
+
+    #[test]
+    fn ongoing_month_is_expected_up_to_today() {
+        let targets = WorkTargets {
+            week: Some(40.0),
+            month: Some(150.0),
+            ..WorkTargets::default()
+        };
+
+        // Saturday 1st and Sunday 2nd, then Monday 3rd to Wednesday 5th
+        let ongoing = compute_month_expectation(&targets, date(2026, 8, 1), date(2026, 8, 5), &[]);
+        assert_eq!(ongoing.expected_minutes, 3 * 480);
+        assert_eq!(
+            ongoing.expected_by_week.get(&WeekId {
+                year: 2026,
+                week: 32
+            }),
+            Some(&(3 * 480))
+        );
+
+        let complete = compute_month_expectation(&targets, date(2026, 8, 1), date(2026, 9, 1), &[]);
+        assert_eq!(complete.expected_minutes, 150 * 60);
+        assert_eq!(complete.expected_by_week.len(), 6);
+        // the weeks are scaled to the month target
+        assert_eq!(complete.expected_by_week.values().sum::<i32>(), 150 * 60);
+    }
+
+    #[test]
+    fn leave_days_reduce_the_expected_hours() {
+        let targets = WorkTargets {
+            week: Some(40.0),
+            month: Some(150.0),
+            ..WorkTargets::default()
+        };
+        let leave_days = vec![
+            LeaveDay {
+                day: "2026-08-04".to_string(),
+                event: EventName::Holiday,
+            },
+            LeaveDay {
+                day: "2026-08-05".to_string(),
+                event: EventName::HalfDay,
+            },
+        ];
+
+        let ongoing =
+            compute_month_expectation(&targets, date(2026, 8, 1), date(2026, 8, 5), &leave_days);
+        assert_eq!(ongoing.expected_minutes, 480 + 240);
+
+        let complete =
+            compute_month_expectation(&targets, date(2026, 8, 1), date(2026, 9, 1), &leave_days);
+        assert_eq!(complete.expected_minutes, 150 * 60 - 480 - 240);
+        assert_eq!(
+            complete.expected_by_week.values().sum::<i32>(),
+            complete.expected_minutes
+        );
+    }
+
+    #[test]
+    fn balance_is_carried_across_months() {
+        let targets = WorkTargets {
+            month: Some(2.0),
+            ..WorkTargets::default()
+        };
+        let events = vec![
+            event_at(EventName::StartWork, "2026-07-06T09:00:00+02:00"),
+            event_at(EventName::StopWork, "2026-07-06T12:00:00+02:00"),
+            event_at(EventName::StartWork, "2026-08-05T09:00:00+02:00"),
+            event_at(EventName::StopWork, "2026-08-05T10:30:00+02:00"),
+        ];
+
+        let balances = compute_balance(
+            &targets,
+            &events,
+            date(2026, 7, 1),
+            date(2026, 8, 1),
+            date(2026, 9, 15),
+        );
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].month, "2026-07");
+        assert_eq!(balances[0].delta_minutes, 60);
+        assert_eq!(balances[1].delta_minutes, -30);
+        assert_eq!(balances[1].balance_minutes, 30);
+    }
+}
@@ -0,0 +1,27 @@
+//! Factories shared by the unit tests.
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+use crate::events::models::Event;
+
+pub fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// An RFC 3339 datetime, i.e. "2026-08-05T09:00:00+02:00".
+pub fn at(datetime: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(datetime).unwrap()
+}
+
+/// A work event at an RFC 3339 datetime, i.e. "2026-08-05T09:00:00+02:00", with the datetime
+/// as uid.
+pub fn event_at(event_name: impl ToString, datetime: &str) -> Event {
+    Event {
+        datetime: datetime.to_string(),
+        event: event_name.to_string(),
+        day: datetime[..10].to_string(),
+        not_type: "work".to_string(),
+        uid: datetime.to_string(),
+        marker: None,
+    }
+}
@@ -0,0 +1,405 @@
+use chrono::{DateTime, FixedOffset, Local};
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs::{metadata, read_to_string},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    errors::models::NostResult,
+    events::models::{Event, EventName, PAUSED},
+    export::render::strip_metadata,
+    git::commit::{autocommit, commit_message},
+    plugins::{models::StatsContext, registry::PLUGINS},
+    projects::initialize::initialize_project,
+    statistics::{models::WorkStatus, status::compute_work_status},
+    vaults::vault::Vault,
+};
+
+/// The work session, as told by the last work event of the journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerState {
+    Stopped,
+    Running,
+    // the last event is a STOP_WORK marked PAUSED
+    Paused,
+}
+
+/// A key of the session timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerKey {
+    Start,
+    Stop,
+    Pause,
+}
+
+pub fn timer_state(last_work_event: Option<&Event>) -> TimerState {
+    match last_work_event {
+        Some(event) if event.event == EventName::StartWork.to_string() => TimerState::Running,
+        Some(event) if event.marker.as_deref() == Some(PAUSED) => TimerState::Paused,
+        _ => TimerState::Stopped,
+    }
+}
+
+/**
+ * The event recorded by a key of the timer, None when the key does nothing in this state.
+ * A pause is a STOP_WORK marked PAUSED: start or pause resume the session, stop ends it.
+ */
+pub fn timer_event(key: TimerKey, state: TimerState) -> Option<Event> {
+    let work = || "work".to_string();
+    match (key, state) {
+        (TimerKey::Start, TimerState::Stopped | TimerState::Paused)
+        | (TimerKey::Pause, TimerState::Paused) => Some(Event::now(EventName::StartWork, work())),
+        (TimerKey::Stop, TimerState::Running | TimerState::Paused) => {
+            Some(Event::now(EventName::StopWork, work()))
+        }
+        (TimerKey::Pause, TimerState::Running) => Some(Event {
+            marker: Some(PAUSED.to_string()),
+            ..Event::now(EventName::StopWork, work())
+        }),
+        _ => None,
+    }
+}
+
+/// A line of the vault tree, its path is relative to the vault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeRow {
+    pub path: PathBuf,
+    pub depth: usize,
+    pub is_dir: bool,
+    pub expanded: bool,
+}
+
+fn push_tree_rows(
+    entries: &BTreeSet<PathBuf>,
+    dirs: &HashSet<PathBuf>,
+    expanded: &HashSet<PathBuf>,
+    parent: &Path,
+    depth: usize,
+    rows: &mut Vec<TreeRow>,
+) {
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.parent() == Some(parent))
+    {
+        let is_dir = dirs.contains(entry);
+        let is_expanded = is_dir && expanded.contains(entry);
+        rows.push(TreeRow {
+            path: entry.clone(),
+            depth,
+            is_dir,
+            expanded: is_expanded,
+        });
+        if is_expanded {
+            push_tree_rows(entries, dirs, expanded, entry, depth + 1, rows);
+        }
+    }
+}
+
+/**
+ * The visible lines of the tree of the notes, sorted by name: the folders holding notes, and
+ * the content of the expanded ones.
+ * i.e. "2026", then "2026/08" and its weeks once "2026" is expanded
+ */
+pub fn tree_rows(notes: &[PathBuf], expanded: &HashSet<PathBuf>) -> Vec<TreeRow> {
+    let mut entries = BTreeSet::new();
+    let mut dirs = HashSet::new();
+    for note in notes {
+        entries.insert(note.clone());
+        for ancestor in note.ancestors().skip(1) {
+            if ancestor.as_os_str().is_empty() {
+                break;
+            }
+            entries.insert(ancestor.to_path_buf());
+            dirs.insert(ancestor.to_path_buf());
+        }
+    }
+
+    let mut rows = Vec::new();
+    push_tree_rows(&entries, &dirs, expanded, Path::new(""), 0, &mut rows);
+    rows
+}
+
+/// What `nost tui` shows, read from the vault and refreshed when its journal changes.
+pub struct App {
+    pub vault: Vault,
+    pub now: DateTime<FixedOffset>,
+    events: Vec<Event>,
+    pub timer: TimerState,
+    pub status: WorkStatus,
+    pub month_stats: Vec<String>,
+    // the note shown instead of today's notes, relative to the vault
+    pub previewed: Option<PathBuf>,
+    pub preview: Vec<String>,
+    // the notes of the vault, relative to the vault
+    notes: Vec<PathBuf>,
+    expanded: HashSet<PathBuf>,
+    pub tree: Vec<TreeRow>,
+    pub selected: usize,
+    journal_modified: Option<SystemTime>,
+    // the result of the last key, i.e. "Work session paused"
+    pub message: Option<String>,
+}
+
+impl App {
+    /// Read the vault, with the folders of today's notes expanded in the tree.
+    pub fn new(vault: Vault) -> NostResult<Self> {
+        let mut app = Self {
+            vault,
+            now: Local::now().fixed_offset(),
+            events: Vec::new(),
+            timer: TimerState::Stopped,
+            status: WorkStatus::default(),
+            month_stats: Vec::new(),
+            previewed: None,
+            preview: Vec::new(),
+            notes: Vec::new(),
+            expanded: HashSet::new(),
+            tree: Vec::new(),
+            selected: 0,
+            journal_modified: None,
+            message: None,
+        };
+        app.reload()?;
+
+        for note in app.today_notes() {
+            app.expanded
+                .extend(note.ancestors().skip(1).map(Path::to_path_buf));
+        }
+        app.tree = tree_rows(&app.notes, &app.expanded);
+        if let Some(today) = app.today_notes().first() {
+            app.selected = app
+                .tree
+                .iter()
+                .position(|row| &row.path == today)
+                .unwrap_or(0);
+        }
+
+        Ok(app)
+    }
+
+    // Today's notes, relative to the vault
+    fn today_notes(&self) -> Vec<PathBuf> {
+        self.vault
+            .notes_of_day(self.now.date_naive())
+            .iter()
+            .map(|path| path.strip_prefix(self.vault.path()).unwrap_or(path).into())
+            .collect()
+    }
+
+    fn journal_modified(&self) -> Option<SystemTime> {
+        metadata(self.vault.journal_path())
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Read the journal, the notes and the month stats again.
+    pub fn reload(&mut self) -> NostResult<()> {
+        self.journal_modified = self.journal_modified();
+        self.events = self.vault.events()?;
+        self.timer = timer_state(self.events.iter().rev().find(|event| {
+            event.event == EventName::StartWork.to_string()
+                || event.event == EventName::StopWork.to_string()
+        }));
+        self.tick(Local::now().fixed_offset());
+
+        self.notes = self
+            .vault
+            .notes()?
+            .iter()
+            .map(|path| path.strip_prefix(self.vault.path()).unwrap_or(path).into())
+            .collect();
+        self.tree = tree_rows(&self.notes, &self.expanded);
+        self.selected = self.selected.min(self.tree.len().saturating_sub(1));
+        self.load_preview();
+
+        let context = StatsContext {
+            vaults: std::slice::from_ref(&self.vault),
+            month: None,
+            locale: self.vault.locale(),
+        };
+        self.month_stats = Vec::new();
+        for plugin in PLUGINS.iter() {
+            match plugin.stats(&context) {
+                Ok(Some(section)) => self.month_stats.extend(section.lines().map(str::to_string)),
+                Ok(None) => {}
+                Err(e) => self.month_stats.push(e.to_string()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reload when `journal.json` changed since the last reload, i.e. after `nost work` in
+    /// another terminal. Returns true if it did.
+    pub fn reload_if_journal_changed(&mut self) -> NostResult<bool> {
+        if self.journal_modified() == self.journal_modified {
+            return Ok(false);
+        }
+        // the message is about the previous state
+        self.message = None;
+        self.reload()?;
+        Ok(true)
+    }
+
+    /// Move the timer and the totals to a new moment.
+    pub fn tick(&mut self, now: DateTime<FixedOffset>) {
+        self.now = now;
+        self.status = compute_work_status(&self.events, now, None);
+    }
+
+    fn load_preview(&mut self) {
+        let notes = match &self.previewed {
+            Some(note) => vec![note.clone()],
+            None => self.today_notes(),
+        };
+        if notes.is_empty() {
+            self.preview = vec![self.vault.locale().tui.no_note_today.to_string()];
+            return;
+        }
+
+        self.preview = Vec::new();
+        for note in notes {
+            if !self.preview.is_empty() {
+                self.preview.push(String::new());
+            }
+            self.preview.push(format!("── {}", note.display()));
+            match read_to_string(self.vault.path().join(&note)) {
+                Ok(content) => self.preview.extend(
+                    strip_metadata(&content)
+                        .lines()
+                        .map(|line| line.replace('\t', "    ")),
+                ),
+                Err(e) => self.preview.push(e.to_string()),
+            }
+        }
+    }
+
+    /// Record the event of a timer key, with today's work note created on start like
+    /// `nost work`.
+    pub fn press_timer(&mut self, key: TimerKey) -> NostResult<()> {
+        let Some(event) = timer_event(key, self.timer) else {
+            let labels = &self.vault.locale().tui;
+            self.message = Some(match self.timer {
+                TimerState::Running => labels.session_already_running.to_string(),
+                _ => labels.no_session_running.to_string(),
+            });
+            return Ok(());
+        };
+
+        initialize_project()?;
+        let mut paths = Vec::new();
+        if event.event == EventName::StartWork.to_string() {
+            paths.push(self.vault.create_note(self.now.date_naive(), "work")?.path);
+        }
+        self.vault.record_event(event.clone())?;
+        autocommit(
+            &self.vault,
+            &commit_message("tui", &event.event, &event.datetime),
+            &paths,
+        );
+
+        let locale = self.vault.locale();
+        self.message = Some(match (key, self.timer) {
+            (TimerKey::Pause, TimerState::Running) => locale.tui.session_paused.to_string(),
+            (_, TimerState::Paused) if key != TimerKey::Stop => {
+                locale.tui.session_resumed.to_string()
+            }
+            (TimerKey::Start, _) => locale.messages.work_started.to_string(),
+            _ => locale.messages.work_closed.to_string(),
+        });
+        self.reload()
+    }
+
+    /// Move the selection of the tree, i.e. -1 for the previous line.
+    pub fn move_selection(&mut self, offset: isize) {
+        let last = self.tree.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(offset).min(last);
+    }
+
+    /// Expand or collapse the selected folder, or preview the selected note.
+    pub fn open_selected(&mut self) {
+        let Some(row) = self.tree.get(self.selected).cloned() else {
+            return;
+        };
+        if row.is_dir {
+            if !self.expanded.remove(&row.path) {
+                self.expanded.insert(row.path);
+            }
+            self.tree = tree_rows(&self.notes, &self.expanded);
+        } else {
+            self.previewed = Some(row.path);
+            self.load_preview();
+        }
+    }
+
+    /// Preview today's notes again.
+    pub fn show_today(&mut self) {
+        self.previewed = None;
+        self.load_preview();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::event_at;
+
+    #[test]
+    fn pause_stops_the_session_until_it_is_resumed() {
+        let stopped = event_at(EventName::StopWork, "2026-08-05T12:00:00+02:00");
+        let paused = Event {
+            marker: Some(PAUSED.to_string()),
+            ..stopped.clone()
+        };
+        assert_eq!(timer_state(None), TimerState::Stopped);
+        assert_eq!(timer_state(Some(&paused)), TimerState::Paused);
+        assert_eq!(timer_state(Some(&stopped)), TimerState::Stopped);
+
+        let pause = timer_event(TimerKey::Pause, TimerState::Running).unwrap();
+        assert_eq!(pause.event, "STOP_WORK");
+        assert_eq!(pause.marker.as_deref(), Some(PAUSED));
+        let resume = timer_event(TimerKey::Pause, TimerState::Paused).unwrap();
+        assert_eq!(resume.event, "START_WORK");
+        let stop = timer_event(TimerKey::Stop, TimerState::Paused).unwrap();
+        assert_eq!((stop.event.as_str(), stop.marker), ("STOP_WORK", None));
+
+        assert!(timer_event(TimerKey::Start, TimerState::Running).is_none());
+        assert!(timer_event(TimerKey::Pause, TimerState::Stopped).is_none());
+        assert!(timer_event(TimerKey::Stop, TimerState::Stopped).is_none());
+    }
+
+    #[test]
+    fn tree_shows_the_content_of_expanded_folders() {
+        let notes = vec![
+            PathBuf::from("2026/08/2/5/05.work.md"),
+            PathBuf::from("2026/08/2/5/05.default.md"),
+            PathBuf::from("2026/07/1/1/01.work.md"),
+            PathBuf::from("topics/acme.md"),
+        ];
+        let expanded: HashSet<PathBuf> = ["2026", "2026/08", "2026/08/2", "2026/08/2/5"]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let rows: Vec<(String, usize, bool)> = tree_rows(&notes, &expanded)
+            .into_iter()
+            .map(|row| (row.path.display().to_string(), row.depth, row.is_dir))
+            .collect();
+
+        assert_eq!(
+            rows,
+            vec![
+                ("2026".to_string(), 0, true),
+                ("2026/07".to_string(), 1, true),
+                ("2026/08".to_string(), 1, true),
+                ("2026/08/2".to_string(), 2, true),
+                ("2026/08/2/5".to_string(), 3, true),
+                ("2026/08/2/5/05.default.md".to_string(), 4, false),
+                ("2026/08/2/5/05.work.md".to_string(), 4, false),
+                ("topics".to_string(), 0, true),
+            ]
+        );
+    }
+}
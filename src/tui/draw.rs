@@ -0,0 +1,401 @@
+use chrono::{DateTime, Local};
+
+use crate::{
+    locales::get::fill,
+    statistics::print::format_duration,
+    tui::app::{App, TimerState},
+};
+
+// The dashboard is not drawn below this size
+const MIN_WIDTH: usize = 40;
+const MIN_HEIGHT: usize = 12;
+// Lines of the session pane, borders included
+const SESSION_HEIGHT: usize = 8;
+
+pub const KEYS_HELP: &str =
+    "s start · p pause · x stop · ↑↓ browse · enter open · t today · r refresh · q quit";
+
+// The cell covered by the right half of a wide character
+const WIDE_FILLER: char = '\0';
+
+/**
+ * The columns a character takes in a terminal: 2 for the CJK characters and most emoji, 0 for
+ * the combining marks and the joiners, 1 otherwise. An approximation of the East Asian Width of
+ * Unicode, enough for notes.
+ */
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F | 0x200B..=0x200F | 0x20D0..=0x20FF | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F
+        | 0x231A..=0x231B
+        | 0x23E9..=0x23EC
+        | 0x23F0
+        | 0x23F3
+        | 0x25FD..=0x25FE
+        | 0x2614..=0x2615
+        | 0x2648..=0x2653
+        | 0x267F
+        | 0x2693
+        | 0x26A1
+        | 0x26AA..=0x26AB
+        | 0x26BD..=0x26BE
+        | 0x26C4..=0x26C5
+        | 0x26CE
+        | 0x26D4
+        | 0x26EA
+        | 0x26F2..=0x26F3
+        | 0x26F5
+        | 0x26FA
+        | 0x26FD
+        | 0x2705
+        | 0x270A..=0x270B
+        | 0x2728
+        | 0x274C
+        | 0x274E
+        | 0x2753..=0x2755
+        | 0x2757
+        | 0x2795..=0x2797
+        | 0x27B0
+        | 0x27BF
+        | 0x2B1B..=0x2B1C
+        | 0x2B50
+        | 0x2B55
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xA960..=0xA97F
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE10..=0xFE19
+        | 0xFE30..=0xFE6F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F004
+        | 0x1F0CF
+        | 0x1F18E
+        | 0x1F191..=0x1F19A
+        | 0x1F1E6..=0x1F1FF
+        | 0x1F200..=0x1F64F
+        | 0x1F680..=0x1F6FF
+        | 0x1F7E0..=0x1F7EB
+        | 0x1F900..=0x1F9FF
+        | 0x1FA70..=0x1FAFF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+// Write a character in a cell, a wide character partly overwritten is cleared
+fn put(line: &mut [char], position: usize, c: char) {
+    let Some(&previous) = line.get(position) else {
+        return;
+    };
+    if previous == WIDE_FILLER && position > 0 {
+        line[position - 1] = ' ';
+    }
+    if previous != WIDE_FILLER && line.get(position + 1) == Some(&WIDE_FILLER) && c != WIDE_FILLER {
+        line[position + 1] = ' ';
+    }
+    line[position] = c;
+}
+
+/// A screen of characters, drawn line by line in the terminal.
+struct Canvas {
+    cells: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            cells: vec![vec![' '; width]; height],
+        }
+    }
+
+    // Write a text from (x, y), cut after `max_width` columns. A wide character takes two
+    // cells, the second one being `WIDE_FILLER`.
+    fn text(&mut self, x: usize, y: usize, text: &str, max_width: usize) {
+        let Some(line) = self.cells.get_mut(y) else {
+            return;
+        };
+        let mut column = 0;
+        for c in text.chars() {
+            let width = char_width(c);
+            if width == 0 {
+                continue;
+            }
+            if column + width > max_width {
+                break;
+            }
+            let position = x + column;
+            column += width;
+            if width == 2 && position + 1 >= line.len() {
+                put(line, position, ' ');
+                continue;
+            }
+            put(line, position, if c.is_control() { ' ' } else { c });
+            if width == 2 {
+                put(line, position + 1, WIDE_FILLER);
+            }
+        }
+    }
+
+    // Draw the borders of a pane, with its title in the top one
+    fn frame(&mut self, x: usize, y: usize, width: usize, height: usize, title: &str) {
+        if width < 2 || height < 2 {
+            return;
+        }
+        let horizontal = "─".repeat(width - 2);
+        self.text(x, y, &format!("┌{}┐", horizontal), width);
+        self.text(x, y + height - 1, &format!("└{}┘", horizontal), width);
+        for row in y + 1..y + height - 1 {
+            self.text(x, row, "│", 1);
+            self.text(x + width - 1, row, "│", 1);
+        }
+        self.text(x + 2, y, &format!(" {} ", title), width.saturating_sub(4));
+    }
+
+    // Write lines inside a pane, the ones that do not fit are left out
+    fn lines<'a>(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        lines: impl Iterator<Item = &'a str>,
+    ) {
+        for (row, line) in lines.take(height.saturating_sub(2)).enumerate() {
+            self.text(x + 2, y + 1 + row, line, width.saturating_sub(4));
+        }
+    }
+
+    fn into_lines(self) -> Vec<String> {
+        self.cells
+            .into_iter()
+            .map(|line| line.into_iter().filter(|c| *c != WIDE_FILLER).collect())
+            .collect()
+    }
+}
+
+// The lines of the session pane: the timer and the totals
+fn session_lines(app: &App) -> Vec<String> {
+    let labels = &app.vault.locale().tui;
+    let since = app
+        .status
+        .since
+        .as_deref()
+        .and_then(|since| DateTime::parse_from_rfc3339(since).ok());
+    let timer = match (app.timer, since) {
+        (TimerState::Running, Some(since)) => {
+            let seconds = (app.now - since).num_seconds().max(0);
+            format!(
+                "{}   {:02}:{:02}:{:02}",
+                fill(
+                    labels.running_since,
+                    &[&since.with_timezone(&Local).format("%H:%M").to_string()]
+                ),
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            )
+        }
+        (TimerState::Paused, _) => labels.paused.to_string(),
+        _ => labels.stopped.to_string(),
+    };
+
+    let totals = [
+        (labels.today, app.status.today_minutes),
+        (labels.this_week, app.status.week_minutes),
+        (labels.this_month, app.status.month_minutes),
+    ];
+    // the durations are aligned after the longest label
+    let label_width = totals
+        .iter()
+        .map(|(label, _)| text_width(label))
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![timer, String::new()];
+    lines.extend(totals.iter().map(|(label, minutes)| {
+        format!(
+            "{}:{} {}",
+            label,
+            " ".repeat(label_width - text_width(label)),
+            format_duration(*minutes)
+        )
+    }));
+    lines.push(app.message.clone().unwrap_or_default());
+    lines
+}
+
+// The lines of the tree pane, scrolled to keep the selection visible
+fn tree_lines(app: &App, height: usize) -> Vec<String> {
+    let visible = height.saturating_sub(2).max(1);
+    let first = (app.selected + 1).saturating_sub(visible);
+
+    app.tree
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(index, row)| {
+            let name = row
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let marker = match (row.is_dir, row.expanded) {
+                (true, true) => "▾ ",
+                (true, false) => "▸ ",
+                (false, _) => "  ",
+            };
+            format!(
+                "{}{}{}{}",
+                if index == app.selected { "> " } else { "  " },
+                "  ".repeat(row.depth),
+                marker,
+                name
+            )
+        })
+        .collect()
+}
+
+/**
+ * Draw the dashboard on a screen of `width` x `height` characters: today's notes and the
+ * vault tree on the left, the session and the month stats on the right, the keys at the
+ * bottom. Every line is `width` columns wide.
+ */
+pub fn render(app: &App, width: usize, height: usize) -> Vec<String> {
+    let mut canvas = Canvas::new(width, height);
+    if width < MIN_WIDTH || height < MIN_HEIGHT {
+        canvas.text(0, 0, "The terminal is too small for nost tui.", width);
+        return canvas.into_lines();
+    }
+
+    let labels = &app.vault.locale().tui;
+    let body = height - 1;
+    let left = width / 2;
+    let right = width - left;
+    let preview_height = body * 3 / 5;
+    let tree_height = body - preview_height;
+    let stats_height = body - SESSION_HEIGHT;
+
+    let preview_title = match &app.previewed {
+        Some(note) => note.display().to_string(),
+        None => fill(
+            labels.today_title,
+            &[&app.now.format("%Y-%m-%d").to_string()],
+        ),
+    };
+    canvas.frame(0, 0, left, preview_height, &preview_title);
+    canvas.lines(
+        0,
+        0,
+        left,
+        preview_height,
+        app.preview.iter().map(String::as_str),
+    );
+
+    let tree = tree_lines(app, tree_height);
+    canvas.frame(0, preview_height, left, tree_height, labels.vault);
+    canvas.lines(
+        0,
+        preview_height,
+        left,
+        tree_height,
+        tree.iter().map(String::as_str),
+    );
+
+    let session = session_lines(app);
+    canvas.frame(left, 0, right, SESSION_HEIGHT, labels.session);
+    canvas.lines(
+        left,
+        0,
+        right,
+        SESSION_HEIGHT,
+        session.iter().map(String::as_str),
+    );
+
+    // the end of the stats is kept when they do not fit, with the totals of the month
+    let hidden_stats = app
+        .month_stats
+        .len()
+        .saturating_sub(stats_height.saturating_sub(2));
+    canvas.frame(left, SESSION_HEIGHT, right, stats_height, labels.month);
+    canvas.lines(
+        left,
+        SESSION_HEIGHT,
+        right,
+        stats_height,
+        app.month_stats[hidden_stats..].iter().map(String::as_str),
+    );
+
+    canvas.text(0, body, KEYS_HELP, width);
+
+    canvas.into_lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        events::models::{Event, EventName},
+        vaults::vault::Vault,
+    };
+    use chrono::Local;
+    use tempfile::tempdir;
+
+    #[test]
+    fn dashboard_shows_the_running_session_and_today_note() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path());
+        let today = Local::now().date_naive();
+        vault.create_note(today, "work").unwrap();
+        let start = Local::now().fixed_offset() - chrono::Duration::minutes(75);
+        vault
+            .record_event(Event::at(EventName::StartWork, "work".to_string(), start))
+            .unwrap();
+
+        let mut app = App::new(vault).unwrap();
+        app.tick(start + chrono::Duration::seconds(75 * 60 + 12));
+        let screen = render(&app, 100, 30);
+
+        assert_eq!(screen.len(), 30);
+        assert!(screen.iter().all(|line| line.chars().count() == 100));
+        assert!(screen[0].starts_with("┌─ Today · "));
+        assert!(screen[0].contains("┌─ Session ─"));
+        assert!(screen[1].contains("● Running since"));
+        assert!(screen[1].contains("01:15:12"));
+        assert!(screen.iter().any(|line| line.contains("── ")
+            && line.contains(&format!("{}.work.md", today.format("%d")))));
+        // the tree is opened on today's note
+        assert!(screen
+            .iter()
+            .any(|line| line.contains("> ")
+                && line.contains(&format!("{}.work.md", today.format("%d")))));
+        assert_eq!(screen[29].trim_end(), KEYS_HELP);
+    }
+
+    #[test]
+    fn wide_characters_take_two_columns() {
+        let mut canvas = Canvas::new(12, 3);
+        canvas.frame(0, 0, 12, 3, "✅ 日本");
+        canvas.lines(0, 0, 12, 3, ["会議のメモ🎉"].into_iter());
+        // a wide character that would overflow by one column is left out
+        canvas.text(9, 2, "ab日", 3);
+
+        let lines = canvas.into_lines();
+        assert_eq!(lines, vec!["┌─ ✅ 日本─┐", "│ 会議のメ │", "└────────ab┘"]);
+        assert!(lines.iter().all(|line| text_width(line) == 12));
+
+        let mut canvas = Canvas::new(4, 1);
+        canvas.text(0, 0, "日本", 4);
+        canvas.text(1, 0, "x", 1);
+        assert_eq!(canvas.into_lines(), vec![" x本"]);
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::Local;
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{poll, read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{
+        disable_raw_mode, enable_raw_mode, size, EnterAlternateScreen, LeaveAlternateScreen,
+    },
+};
+use std::{
+    io::{stdout, Stdout, Write},
+    time::Duration,
+};
+
+use crate::{
+    errors::models::{NostError, NostResult},
+    tui::{
+        app::{App, TimerKey},
+        draw::render,
+    },
+    vaults::vault::Vault,
+};
+
+// How long to wait for a key before moving the timer and looking at the journal
+const TICK: Duration = Duration::from_millis(500);
+
+// Restore the terminal when the dashboard ends, even on an error
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+    }
+}
+
+fn terminal_error(e: std::io::Error) -> NostError {
+    NostError::io("Cannot draw the dashboard", e)
+}
+
+fn draw(out: &mut Stdout, app: &App) -> NostResult<()> {
+    let (width, height) = size().map_err(terminal_error)?;
+    for (y, line) in render(app, width as usize, height as usize)
+        .iter()
+        .enumerate()
+    {
+        queue!(out, MoveTo(0, y as u16), Print(line)).map_err(terminal_error)?;
+    }
+    out.flush().map_err(terminal_error)
+}
+
+// Apply a key, returns false to quit
+fn handle_key(app: &mut App, key: KeyEvent) -> bool {
+    let result = match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return false,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+        KeyCode::Char('s') => app.press_timer(TimerKey::Start),
+        KeyCode::Char('x') => app.press_timer(TimerKey::Stop),
+        KeyCode::Char('p') => app.press_timer(TimerKey::Pause),
+        KeyCode::Char('r') => app.reload(),
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.move_selection(-1);
+            Ok(())
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.move_selection(1);
+            Ok(())
+        }
+        KeyCode::Enter | KeyCode::Right | KeyCode::Char(' ') => {
+            app.open_selected();
+            Ok(())
+        }
+        KeyCode::Char('t') => {
+            app.show_today();
+            Ok(())
+        }
+        _ => Ok(()),
+    };
+    // the dashboard stays open, the error is shown in the session pane
+    if let Err(e) = result {
+        app.message = Some(format!("🛑 {}", e));
+    }
+
+    true
+}
+
+/**
+ * Run the dashboard of a vault until q is pressed. The timer moves every tick, and everything
+ * is read again when `journal.json` changes, i.e. after `nost work` in another terminal.
+ */
+pub fn run_dashboard(vault: Vault) -> NostResult<()> {
+    let mut app = App::new(vault)?;
+
+    let mut out = stdout();
+    enable_raw_mode().map_err(terminal_error)?;
+    let _guard = TerminalGuard;
+    execute!(out, EnterAlternateScreen, Hide).map_err(terminal_error)?;
+
+    loop {
+        app.tick(Local::now().fixed_offset());
+        if let Err(e) = app.reload_if_journal_changed() {
+            app.message = Some(format!("🛑 {}", e));
+        }
+        draw(&mut out, &app)?;
+
+        if !poll(TICK).map_err(terminal_error)? {
+            continue;
+        }
+        match read().map_err(terminal_error)? {
+            Event::Key(key) if key.kind == KeyEventKind::Press && !handle_key(&mut app, key) => {
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+}
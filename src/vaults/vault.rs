@@ -0,0 +1,468 @@
+use chrono::{Datelike, NaiveDate};
+use std::{
+    collections::HashMap,
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    annotations::{extract::extract_annotations_from_files, models::Annotation},
+    configurations::{
+        get::{
+            get_hooks, get_metadata_style, get_not_path, get_raw_config, get_value_from_config,
+            get_vault_override, get_work_pay, get_work_pay_of, get_work_targets,
+            get_work_targets_of,
+        },
+        models::{MetadataStyle, WorkTargets},
+    },
+    errors::models::{NostError, NostResult},
+    events::{
+        find::{find_all_events, find_last_work_event_in, get_journal_file_path},
+        models::{Event, SessionState},
+        record::record_event_in,
+        state::read_state_in,
+    },
+    files::{
+        build_paths::build_folder_path_with_layout,
+        create::create_note_in,
+        find::find_all_not_files_with_layouts,
+        layout::{get_layout, validate_layout, DEFAULT_LAYOUT},
+        models::{DatedNote, NoteFile},
+        reorganize::{infer_dated_note, parse_note_file_name},
+    },
+    hooks::models::Hooks,
+    links::{graph::build_link_graph, models::LinkGraph},
+    locales::{
+        catalog::EN,
+        get::{find_locale, get_current_locale},
+        models::Locale,
+    },
+    projects::initialize::get_project_config_path_for,
+    statistics::{
+        compute::{compute_minutes_by_day_in, compute_month_stats_in},
+        models::{MonthStats, Pay},
+    },
+};
+
+/**
+ * A notes folder, with its journal in `<path>/.nost/journal.json`.
+ * Nothing is written to the disk until a note is created or an event is recorded.
+ */
+#[derive(Debug, Clone)]
+pub struct Vault {
+    // name of the vault in the configuration, if any
+    name: Option<String>,
+    path: PathBuf,
+    layout: String,
+    locale: &'static Locale,
+    // run when notes are created and events recorded
+    hooks: Hooks,
+    // commit the touched files after each command, see git::commit::autocommit
+    autocommit: bool,
+    // metadata written in new notes, none by default
+    metadata: Option<MetadataStyle>,
+    // daily rate, currency and paid leave of the work days
+    pay: Pay,
+    // hours expected to be worked, none by default
+    targets: WorkTargets,
+}
+
+impl Vault {
+    /// Open the notes folder at `path`, with the default layout, English dates, no hooks and
+    /// no automatic commits.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self {
+            name: None,
+            path: path.into(),
+            layout: DEFAULT_LAYOUT.to_string(),
+            locale: &EN,
+            hooks: Hooks::default(),
+            autocommit: false,
+            metadata: None,
+            pay: Pay::default(),
+            targets: WorkTargets::default(),
+        }
+    }
+
+    /// Open the notes folder of the configuration, with its layout, language, hooks, git and
+    /// metadata settings.
+    /// The vault is named after the --vault option, if given.
+    pub fn from_config() -> NostResult<Self> {
+        let not_path = get_not_path()?;
+        Ok(Self {
+            name: get_vault_override().map(str::to_string),
+            path: PathBuf::from(not_path),
+            layout: get_layout(),
+            locale: get_current_locale(),
+            hooks: get_hooks(),
+            autocommit: get_value_from_config("git.autocommit").is_ok_and(|value| value == "true"),
+            metadata: get_metadata_style(),
+            pay: get_work_pay(),
+            targets: get_work_targets(),
+        })
+    }
+
+    /// Open every vault of the configuration, with its own language, layout, pay and targets.
+    pub fn all_from_config() -> NostResult<Vec<Self>> {
+        let config = get_raw_config()?;
+        if config.vaults.is_empty() {
+            return Err(NostError::InvalidConfig(
+                "No vault configured, add [vaults.<name>] sections to config.toml".to_string(),
+            ));
+        }
+
+        config
+            .vaults
+            .keys()
+            .map(|name| {
+                let vault_config = config.for_vault(Some(name))?;
+                Vault::open(&vault_config.not_path)
+                    .with_name(name)
+                    .with_language(&vault_config.language)
+                    .with_pay(get_work_pay_of(Some(&vault_config)))
+                    .with_targets(get_work_targets_of(Some(&vault_config)))
+                    .with_layout(&vault_config.get_value("layout").unwrap_or_default())
+            })
+            .collect()
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Use the locale of a language code for the date headers, i.e. "fr" or "pt-BR".
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.locale = find_locale(language);
+        self
+    }
+
+    /// Use another folder layout, i.e. "{year}/W{iso_week}".
+    pub fn with_layout(mut self, layout: &str) -> NostResult<Self> {
+        validate_layout(layout).map_err(NostError::InvalidConfig)?;
+        self.layout = layout.to_string();
+        Ok(self)
+    }
+
+    /// Run these hooks when notes are created and events recorded.
+    pub fn with_hooks(mut self, hooks: Hooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Commit the files touched by the commands in the git repository of the notes folder.
+    pub fn with_autocommit(mut self, autocommit: bool) -> Self {
+        self.autocommit = autocommit;
+        self
+    }
+
+    /// Write the uid, the creation date and the type of new notes as annotations or as front
+    /// matter.
+    pub fn with_metadata(mut self, metadata: Option<MetadataStyle>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Pay the work days at this daily rate and currency, with these kinds of paid leave.
+    /// The missing rate and currency are read from the environment when the salary is computed.
+    pub fn with_pay(mut self, pay: Pay) -> Self {
+        self.pay = pay;
+        self
+    }
+
+    /// Expect these hours to be worked, see `statistics::targets`.
+    pub fn with_targets(mut self, targets: WorkTargets) -> Self {
+        self.targets = targets;
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn layout(&self) -> &str {
+        &self.layout
+    }
+
+    pub fn locale(&self) -> &'static Locale {
+        self.locale
+    }
+
+    pub fn hooks(&self) -> &Hooks {
+        &self.hooks
+    }
+
+    pub fn autocommit(&self) -> bool {
+        self.autocommit
+    }
+
+    pub fn metadata(&self) -> Option<MetadataStyle> {
+        self.metadata
+    }
+
+    pub fn pay(&self) -> &Pay {
+        &self.pay
+    }
+
+    pub fn targets(&self) -> &WorkTargets {
+        &self.targets
+    }
+
+    // i.e. "<path>/.nost/"
+    pub fn config_path(&self) -> String {
+        get_project_config_path_for(&self.path.to_string_lossy())
+    }
+
+    pub fn journal_path(&self) -> PathBuf {
+        PathBuf::from(get_journal_file_path(&self.config_path()))
+    }
+
+    /// Create the note of a date, unless it already exists, and record a CREATE_NOT event.
+    pub fn create_note(&self, date: NaiveDate, note_type: &str) -> NostResult<NoteFile> {
+        create_note_in(
+            &self.path.to_string_lossy(),
+            &self.layout,
+            self.locale,
+            &self.hooks,
+            self.metadata,
+            date,
+            note_type,
+        )
+    }
+
+    /// Append an event to the journal, running its hooks.
+    pub fn record_event(&self, event: Event) -> NostResult<String> {
+        record_event_in(&self.config_path(), event, &self.hooks)
+    }
+
+    /// Every event of the journal, in the order they were recorded.
+    pub fn events(&self) -> NostResult<Vec<Event>> {
+        find_all_events(&self.config_path())
+    }
+
+    /// The most recent START_WORK or STOP_WORK event.
+    pub fn last_work_event(&self) -> NostResult<Option<Event>> {
+        find_last_work_event_in(&self.config_path())
+    }
+
+    /// The running session and the last event, read from `.nost/state.json` instead of the
+    /// whole journal.
+    pub fn session_state(&self) -> NostResult<SessionState> {
+        read_state_in(&self.config_path())
+    }
+
+    /// The note files, found with the vault layout and the default one.
+    pub fn notes(&self) -> NostResult<Vec<PathBuf>> {
+        let mut layouts = vec![self.layout.clone()];
+        if self.layout != DEFAULT_LAYOUT {
+            layouts.push(DEFAULT_LAYOUT.to_string());
+        }
+
+        find_all_not_files_with_layouts(self.path.clone(), &layouts).map_err(|e| {
+            NostError::io(
+                format!("Cannot read the notes in {}", self.path.display()),
+                e,
+            )
+        })
+    }
+
+    /// The note files with their date and type, sorted by date. Files whose date cannot be
+    /// inferred are left out.
+    pub fn dated_notes(&self) -> NostResult<Vec<DatedNote>> {
+        let mut layouts = vec![self.layout.clone()];
+        if self.layout != DEFAULT_LAYOUT {
+            layouts.push(DEFAULT_LAYOUT.to_string());
+        }
+
+        let mut notes: Vec<DatedNote> = self
+            .notes()?
+            .iter()
+            .filter_map(|path| infer_dated_note(&self.path, path, &layouts))
+            .collect();
+        notes.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.path.cmp(&b.path)));
+
+        Ok(notes)
+    }
+
+    /// The notes of a day, whatever their type, i.e. "05.md" and "05.work.md".
+    pub fn notes_of_day(&self, date: NaiveDate) -> Vec<PathBuf> {
+        let mut layouts = vec![self.layout.as_str()];
+        if self.layout != DEFAULT_LAYOUT {
+            layouts.push(DEFAULT_LAYOUT);
+        }
+
+        let mut notes: Vec<PathBuf> = layouts
+            .into_iter()
+            .filter_map(|layout| {
+                read_dir(build_folder_path_with_layout(
+                    &self.path.to_string_lossy(),
+                    layout,
+                    date,
+                ))
+                .ok()
+            })
+            .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .and_then(parse_note_file_name)
+                        .is_some_and(|(day, _)| day == date.day())
+            })
+            .collect();
+        notes.sort();
+        notes.dedup();
+
+        notes
+    }
+
+    /// The annotations of every note.
+    pub fn annotations(&self) -> NostResult<Vec<Annotation>> {
+        extract_annotations_from_files(self.notes()?)
+    }
+
+    /// The wiki links between the markdown files of the vault.
+    pub fn links(&self) -> NostResult<LinkGraph> {
+        build_link_graph(self)
+    }
+
+    /// The work stats of a month ("YYYY-MM"), the current month if None.
+    pub fn month_stats(&self, month: Option<&str>) -> NostResult<MonthStats> {
+        compute_month_stats_in(&self.config_path(), month)
+    }
+
+    /// The worked minutes of each day of a period, i.e. "2026" or "2026-08".
+    pub fn minutes_by_day(&self, period: &str) -> NostResult<HashMap<String, i32>> {
+        compute_minutes_by_day_in(&self.config_path(), period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::models::EventName;
+    use crate::test_helpers::date;
+    use std::fs::read_to_string;
+    use tempfile::tempdir;
+
+    #[test]
+    fn create_note_uses_layout_and_records_event() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path()).with_language("fr");
+
+        let note = vault.create_note(date(2026, 8, 5), "work").unwrap();
+
+        assert!(note.created);
+        assert!(note.path.ends_with("2026/08/2/5/05.work.md"));
+        assert!(read_to_string(&note.path)
+            .unwrap()
+            .starts_with("# Mercredi 5 août 2026"));
+
+        let events = vault.events().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event, EventName::CreateNot.to_string());
+        assert_eq!(events[0].not_type, "work");
+
+        // an existing note is neither overwritten nor recorded again
+        let again = vault.create_note(date(2026, 8, 5), "work").unwrap();
+        assert!(!again.created);
+        assert_eq!(vault.events().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn note_metadata_is_written_in_the_configured_style() {
+        let dir = tempdir().unwrap();
+
+        for metadata in [MetadataStyle::FrontMatter, MetadataStyle::Annotations] {
+            let vault =
+                Vault::open(dir.path().join(metadata.as_str())).with_metadata(Some(metadata));
+            let note = vault.create_note(date(2026, 8, 5), "work").unwrap();
+            let event = vault.events().unwrap().remove(0);
+
+            let content = read_to_string(&note.path).unwrap();
+            assert_eq!(
+                content.starts_with("---\n"),
+                metadata == MetadataStyle::FrontMatter
+            );
+            assert!(content.contains("\n# Wednesday") || content.starts_with("# Wednesday"));
+
+            // both styles are read back as the same annotation
+            let annotations = vault.annotations().unwrap();
+            assert_eq!(annotations.len(), 1);
+            assert_eq!(annotations[0].event, EventName::CreateNot);
+            assert_eq!(annotations[0]._uid.to_string(), event.uid);
+            assert_eq!(annotations[0].field("type"), Some("work"));
+        }
+    }
+
+    #[test]
+    fn notes_are_found_with_custom_layout() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path())
+            .with_layout("{iso_year}/W{iso_week}")
+            .unwrap();
+
+        vault.create_note(date(2027, 1, 1), "default").unwrap();
+
+        let notes = vault.notes().unwrap();
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].ends_with("2026/W53/01.default.md"));
+        assert_eq!(vault.notes_of_day(date(2027, 1, 1)), notes);
+        assert!(vault.notes_of_day(date(2027, 1, 2)).is_empty());
+    }
+
+    #[test]
+    fn invalid_layout_is_rejected() {
+        assert!(Vault::open("/tmp").with_layout("{year}/{quarter}").is_err());
+    }
+
+    #[test]
+    fn work_events_are_queried_from_the_journal() {
+        let dir = tempdir().unwrap();
+        let vault = Vault::open(dir.path());
+
+        assert!(vault.last_work_event().unwrap().is_none());
+        vault
+            .record_event(Event::now(EventName::StartWork, "work".to_string()))
+            .unwrap();
+
+        let last = vault.last_work_event().unwrap().unwrap();
+        assert_eq!(last.event, EventName::StartWork.to_string());
+        assert!(vault.journal_path().is_file());
+        assert_eq!(
+            vault.month_stats(None).unwrap().total_duration_in_minutes,
+            0
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failing_pre_hook_cancels_the_note() {
+        use std::collections::BTreeMap;
+
+        let dir = tempdir().unwrap();
+        let hooks = Hooks::new(BTreeMap::from([
+            ("pre_new".to_string(), "exit 1".to_string()),
+            ("post_work_start".to_string(), "exit 1".to_string()),
+        ]));
+        let vault = Vault::open(dir.path()).with_hooks(hooks);
+
+        assert!(matches!(
+            vault.create_note(date(2026, 8, 5), "work"),
+            Err(NostError::HookFailed { .. })
+        ));
+        assert!(vault.notes().unwrap().is_empty());
+        assert!(vault.events().unwrap().is_empty());
+
+        // a failing post hook does not undo the recorded event
+        vault
+            .record_event(Event::now(EventName::StartWork, "work".to_string()))
+            .unwrap();
+        assert_eq!(vault.events().unwrap().len(), 1);
+    }
+}